@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+
+use ge_man_lib::tag::TagKind;
+
+/// An application which makes use of a GE version through its own configuration.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Application {
+    Steam,
+    Lutris,
+    Bottles,
+}
+
+impl Application {
+    /// The application a version of the given kind is applied to when no other target is selected.
+    pub fn for_kind(kind: &TagKind) -> Self {
+        match kind {
+            TagKind::Proton => Application::Steam,
+            TagKind::Wine { .. } => Application::Lutris,
+        }
+    }
+
+    /// The application a version of the given kind is applied to with the selected target. Bottles is only used when
+    /// it was selected explicitly.
+    pub fn for_target(kind: &TagKind, bottles: bool) -> Self {
+        if bottles {
+            Application::Bottles
+        } else {
+            Application::for_kind(kind)
+        }
+    }
+
+    /// Whether the application is able to use versions of the given kind.
+    pub fn uses(&self, kind: &TagKind) -> bool {
+        match self {
+            Application::Steam => kind.eq(&TagKind::Proton),
+            Application::Lutris | Application::Bottles => matches!(kind, TagKind::Wine { .. }),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Application::Steam => "Steam",
+            Application::Lutris => "Lutris",
+            Application::Bottles => "Bottles",
+        }
+    }
+}
+
+impl Display for Application {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(TagKind::Proton => Application::Steam; "Proton versions are used by Steam")]
+    #[test_case(TagKind::wine() => Application::Lutris; "Wine versions are used by Lutris")]
+    #[test_case(TagKind::lol() => Application::Lutris; "Wine LoL versions are used by Lutris")]
+    fn for_kind(kind: TagKind) -> Application {
+        Application::for_kind(&kind)
+    }
+
+    #[test_case(TagKind::Proton, false => Application::Steam; "Proton versions without target")]
+    #[test_case(TagKind::wine(), false => Application::Lutris; "Wine versions without target")]
+    #[test_case(TagKind::wine(), true => Application::Bottles; "Wine versions with Bottles target")]
+    #[test_case(TagKind::lol(), true => Application::Bottles; "Wine LoL versions with Bottles target")]
+    fn for_target(kind: TagKind, bottles: bool) -> Application {
+        Application::for_target(&kind, bottles)
+    }
+
+    #[test_case(Application::Steam, TagKind::Proton => true; "Steam uses Proton versions")]
+    #[test_case(Application::Steam, TagKind::wine() => false; "Steam does not use Wine versions")]
+    #[test_case(Application::Lutris, TagKind::lol() => true; "Lutris uses Wine LoL versions")]
+    #[test_case(Application::Bottles, TagKind::wine() => true; "Bottles uses Wine versions")]
+    #[test_case(Application::Bottles, TagKind::Proton => false; "Bottles does not use Proton versions")]
+    fn uses(application: Application, kind: TagKind) -> bool {
+        application.uses(&kind)
+    }
+
+    #[test_case(Application::Steam => "Steam"; "Steam name")]
+    #[test_case(Application::Lutris => "Lutris"; "Lutris name")]
+    #[test_case(Application::Bottles => "Bottles"; "Bottles name")]
+    fn name(application: Application) -> String {
+        application.to_string()
+    }
+}
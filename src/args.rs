@@ -1,22 +1,33 @@
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use clap::ArgMatches;
+use clap_complete::Shell;
 
 use ge_man_lib::tag::{Tag, TagKind};
 
+use crate::checksum::ChecksumAlgorithm;
 use crate::clap::{arg_group_names, arg_names, commands};
+use crate::config::GeManConfig;
+use crate::progress::{parse_rate, ProgressFormat};
 use crate::version::Version;
 
 #[derive(Debug)]
 pub struct TagArg {
     pub tag: Option<Tag>,
     pub kind: TagKind,
+    /// Whether the kind was taken from the "default_kind" config instead of a kind flag.
+    pub kind_from_default: bool,
 }
 
 impl TagArg {
     pub fn new(tag: Option<Tag>, kind: TagKind) -> Self {
         let tag = tag.map(Into::into);
-        TagArg { tag, kind }
+        TagArg {
+            tag,
+            kind,
+            kind_from_default: false,
+        }
     }
 
     pub fn value(&self) -> Option<&String> {
@@ -57,14 +68,111 @@ impl TryFrom<&ArgMatches> for TagArg {
     }
 }
 
+impl TagArg {
+    /// Create the tag argument of a command with a positional tag. Without a kind flag the given default kind is used.
+    /// If neither exists, the same error clap reports for a missing required argument is returned. A tag given to the
+    /// kind flag and as positional argument at the same time is rejected, instead of dropping one of them.
+    pub fn try_from_or_default(matches: &ArgMatches, default_kind: Option<TagKind>) -> Result<Self, clap::Error> {
+        let positional_tag = matches.value_of(arg_names::TAG_ARG).map(Tag::from);
+        match (TagArg::try_from(matches), default_kind) {
+            (Ok(tag_arg), _) if tag_arg.tag.is_some() && positional_tag.is_some() => Err(clap::Error::raw(
+                clap::ErrorKind::ArgumentConflict,
+                "A tag can not be given to a kind flag and as positional argument at the same time\n",
+            )),
+            (Ok(tag_arg), _) if tag_arg.tag.is_some() => Ok(tag_arg),
+            (Ok(tag_arg), _) => Ok(TagArg::new(positional_tag, tag_arg.kind)),
+            (Err(()), Some(kind)) => {
+                let mut tag_arg = TagArg::new(positional_tag, kind);
+                tag_arg.kind_from_default = true;
+                Ok(tag_arg)
+            }
+            (Err(()), None) => Err(clap::Error::raw(
+                clap::ErrorKind::MissingRequiredArgument,
+                "One of --proton, --wine or --lol is required when no \"default_kind\" is configured\n",
+            )),
+        }
+    }
+}
+
+fn flag_or_default(matches: &ArgMatches, enable_arg: &str, disable_arg: &str, default: bool) -> bool {
+    if matches.is_present(enable_arg) {
+        true
+    } else if matches.is_present(disable_arg) {
+        false
+    } else {
+        default
+    }
+}
+
+/// Order of the versions of a tag kind in `list`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ListSort {
+    /// Newest version first.
+    Semver,
+    /// Alphabetical by tag.
+    Name,
+    /// In the order the versions were added.
+    Added,
+}
+
+impl ListSort {
+    pub fn values() -> [&'static str; 3] {
+        ["semver", "name", "added"]
+    }
+}
+
+impl Default for ListSort {
+    fn default() -> Self {
+        ListSort::Semver
+    }
+}
+
+impl FromStr for ListSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "semver" => Ok(ListSort::Semver),
+            "name" => Ok(ListSort::Name),
+            "added" => Ok(ListSort::Added),
+            _ => Err(format!("Unsupported sort order: {}", s)),
+        }
+    }
+}
+
 pub struct ListArgs {
     pub kind: Option<TagKind>,
     pub newest: bool,
+    pub limit: Option<usize>,
+    pub page: usize,
+    pub merged: bool,
+    pub json: bool,
+    pub size: bool,
+    pub filter: Option<String>,
+    pub all: bool,
+    pub sort: ListSort,
+    pub show_added: bool,
+    pub include_lol: bool,
+    pub manifest: Option<PathBuf>,
 }
 
 impl ListArgs {
     pub fn new(kind: Option<TagKind>, newest: bool) -> Self {
-        ListArgs { kind, newest }
+        ListArgs {
+            kind,
+            newest,
+            limit: None,
+            page: 1,
+            merged: false,
+            json: false,
+            size: false,
+            filter: None,
+            all: false,
+            sort: ListSort::default(),
+            show_added: false,
+            include_lol: false,
+            manifest: None,
+        }
     }
 }
 
@@ -74,15 +182,123 @@ impl From<ArgMatches> for ListArgs {
         let newest = matches.is_present(arg_names::NEWEST_ARG);
         let kind = TagArg::try_from(matches).ok().map(|tag| tag.kind);
 
-        ListArgs::new(kind, newest)
+        let mut args = ListArgs::new(kind, newest);
+        args.limit = matches
+            .value_of(arg_names::LIMIT_ARG)
+            .map(|limit| limit.parse().unwrap());
+        if let Some(page) = matches.value_of(arg_names::PAGE_ARG) {
+            args.page = page.parse().unwrap();
+        }
+        args.merged = matches.is_present(arg_names::MERGED_ARG);
+        args.json = matches.is_present(arg_names::JSON_ARG);
+        args.size = matches.is_present(arg_names::SIZE_ARG);
+        args.filter = matches.value_of(arg_names::FILTER_ARG).map(String::from);
+        args.all = matches.is_present(arg_names::ALL_ARG);
+        if let Some(sort) = matches.value_of(arg_names::SORT_ARG) {
+            args.sort = sort.parse().unwrap();
+        }
+        args.show_added = matches.is_present(arg_names::SHOW_ADDED_ARG);
+        args.include_lol = matches.is_present(arg_names::INCLUDE_LOL_ARG);
+        args.manifest = matches.value_of(arg_names::MANIFEST_ARG).map(PathBuf::from);
+
+        args
     }
 }
 
 #[derive(Debug)]
+/// Arguments which can be provided to every command.
+pub struct GlobalArgs {
+    pub retry_all: Option<usize>,
+    pub no_progress: bool,
+    pub progress_format: Option<ProgressFormat>,
+    pub flatpak_steam: bool,
+    pub flatpak_lutris: bool,
+    pub assume_yes: bool,
+    pub verbose: u64,
+    pub log_file: Option<PathBuf>,
+    pub limit_rate: Option<u64>,
+    pub offline: bool,
+    pub quiet: bool,
+    pub config: Option<PathBuf>,
+}
+
+impl GlobalArgs {
+    pub fn new(retry_all: Option<usize>, no_progress: bool) -> Self {
+        GlobalArgs {
+            retry_all,
+            no_progress,
+            progress_format: None,
+            flatpak_steam: false,
+            flatpak_lutris: false,
+            assume_yes: false,
+            verbose: 0,
+            log_file: None,
+            limit_rate: None,
+            offline: false,
+            quiet: false,
+            config: None,
+        }
+    }
+
+    /// Progress bars are drawn to stdout, so `--quiet` hides them as well as `--no-progress`.
+    pub fn show_progress(&self) -> bool {
+        !self.no_progress && !self.quiet
+    }
+}
+
+impl From<&ArgMatches> for GlobalArgs {
+    fn from(matches: &ArgMatches) -> Self {
+        // Global arguments that are provided after the command name are only present in the command matches.
+        let global_matches = |arg: &str| match matches.subcommand() {
+            Some((_, sub_matches)) if sub_matches.is_present(arg) => sub_matches,
+            _ => matches,
+        };
+        let retry_all = global_matches(arg_names::RETRY_ALL_ARG)
+            .value_of(arg_names::RETRY_ALL_ARG)
+            .map(|retries| retries.parse().unwrap());
+        let no_progress = global_matches(arg_names::NO_PROGRESS_ARG).is_present(arg_names::NO_PROGRESS_ARG);
+
+        let mut args = GlobalArgs::new(retry_all, no_progress);
+        args.progress_format = global_matches(arg_names::PROGRESS_FORMAT_ARG)
+            .value_of(arg_names::PROGRESS_FORMAT_ARG)
+            .map(|format| format.parse().unwrap());
+        args.flatpak_steam = global_matches(arg_names::FLATPAK_STEAM_ARG).is_present(arg_names::FLATPAK_STEAM_ARG);
+        args.flatpak_lutris = global_matches(arg_names::FLATPAK_LUTRIS_ARG).is_present(arg_names::FLATPAK_LUTRIS_ARG);
+        args.assume_yes = global_matches(arg_names::ASSUME_YES_ARG).is_present(arg_names::ASSUME_YES_ARG);
+        args.verbose = global_matches(arg_names::VERBOSE_ARG).occurrences_of(arg_names::VERBOSE_ARG);
+        args.log_file = global_matches(arg_names::LOG_FILE_ARG)
+            .value_of(arg_names::LOG_FILE_ARG)
+            .map(PathBuf::from);
+        args.limit_rate = global_matches(arg_names::LIMIT_RATE_ARG)
+            .value_of(arg_names::LIMIT_RATE_ARG)
+            .map(|rate| parse_rate(rate).unwrap());
+        args.offline = global_matches(arg_names::OFFLINE_ARG).is_present(arg_names::OFFLINE_ARG);
+        args.quiet = global_matches(arg_names::QUIET_ARG).is_present(arg_names::QUIET_ARG);
+        args.config = global_matches(arg_names::CONFIG_ARG)
+            .value_of(arg_names::CONFIG_ARG)
+            .map(PathBuf::from);
+
+        args
+    }
+}
+
 pub struct AddArgs {
     pub tag_arg: TagArg,
     pub skip_checksum: bool,
     pub apply: bool,
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    pub skip_existing: bool,
+    pub keep_archive_cache: bool,
+    pub archive: Option<PathBuf>,
+    pub checksum_file: Option<PathBuf>,
+    pub label: Option<String>,
+    pub dry_run: bool,
+    pub refresh: bool,
+    pub force: bool,
+    pub verify_only: bool,
+    pub keep_archive: bool,
+    pub keep_archive_dir: Option<PathBuf>,
+    pub steam_root: Option<PathBuf>,
 }
 
 impl AddArgs {
@@ -91,28 +307,85 @@ impl AddArgs {
             tag_arg: tag,
             skip_checksum,
             apply,
+            checksum_algorithm: None,
+            skip_existing: false,
+            keep_archive_cache: false,
+            archive: None,
+            checksum_file: None,
+            label: None,
+            dry_run: false,
+            refresh: false,
+            force: false,
+            verify_only: false,
+            keep_archive: false,
+            keep_archive_dir: None,
+            steam_root: None,
         }
     }
+
+    /// Create the add arguments from the command line matches. Flags that are not provided on the command line fall
+    /// back to the defaults configured for the tag kind in the GE-Man config.
+    pub fn create_from(matches: ArgMatches, config: &GeManConfig) -> Self {
+        let matches = matches.subcommand_matches(commands::ADD).unwrap();
+        let tag = TagArg::try_from_or_default(matches, config.default_tag_kind()).unwrap_or_else(|err| err.exit());
+        let defaults = config.defaults_for(&tag.kind);
+
+        let skip_checksum = flag_or_default(
+            matches,
+            arg_names::SKIP_CHECKSUM_ARG,
+            arg_names::CHECKSUM_ARG,
+            defaults.skip_checksum,
+        );
+        // The default Steam installation does not read the compatibility tools of another Steam root, so applying
+        // after add is only done for the default Steam root.
+        let steam_root = matches.value_of(arg_names::STEAM_ROOT_ARG).map(PathBuf::from);
+        let apply = flag_or_default(
+            matches,
+            arg_names::APPLY_ARG,
+            arg_names::NO_APPLY_ARG,
+            defaults.apply_after_add && steam_root.is_none(),
+        );
+
+        let mut args = AddArgs::new(tag, skip_checksum, apply);
+        args.steam_root = steam_root;
+        args.checksum_algorithm = matches
+            .value_of(arg_names::CHECKSUM_ALGORITHM_ARG)
+            .map(|algorithm| algorithm.parse().unwrap());
+        args.skip_existing = matches.is_present(arg_names::SKIP_EXISTING_ARG);
+        args.keep_archive_cache = matches.is_present(arg_names::KEEP_ARCHIVE_CACHE_ARG);
+        args.archive = matches.value_of(arg_names::ARCHIVE_ARG).map(PathBuf::from);
+        args.checksum_file = matches.value_of(arg_names::CHECKSUM_FILE_ARG).map(PathBuf::from);
+        args.label = matches.value_of(arg_names::LABEL_ARG).map(String::from);
+        args.dry_run = matches.is_present(arg_names::DRY_RUN_ARG);
+        args.refresh = matches.is_present(arg_names::REFRESH_ARG);
+        args.force = matches.is_present(arg_names::FORCE_ARG);
+        args.verify_only = matches.is_present(arg_names::VERIFY_ONLY_ARG);
+        args.keep_archive = matches.is_present(arg_names::KEEP_ARCHIVE_ARG);
+        args.keep_archive_dir = matches.value_of(arg_names::KEEP_ARCHIVE_ARG).map(PathBuf::from);
+
+        args
+    }
 }
 
 impl From<ArgMatches> for AddArgs {
     fn from(matches: ArgMatches) -> Self {
-        let matches = matches.subcommand_matches(commands::ADD).unwrap();
-        let tag = TagArg::try_from(matches).expect("Could not create tag information from provided argument");
-        let skip_checksum = matches.is_present(arg_names::SKIP_CHECKSUM_ARG);
-        let apply = matches.is_present(arg_names::APPLY_ARG);
-
-        AddArgs::new(tag, skip_checksum, apply)
+        AddArgs::create_from(matches, &GeManConfig::default())
     }
 }
 
 pub struct RemoveArgs {
-    pub tag_arg: TagArg,
+    pub versions: Vec<Version>,
+    pub force: bool,
+    pub yes: bool,
 }
 
 impl RemoveArgs {
     pub fn new(tag_arg: TagArg) -> Self {
-        RemoveArgs { tag_arg }
+        RemoveArgs {
+            versions: vec![tag_arg.version()],
+            force: false,
+            yes: false,
+        }
     }
 }
 
@@ -124,30 +397,55 @@ impl From<ArgMatches> for RemoveArgs {
             panic!("No version provided!")
         }
 
-        RemoveArgs::new(tag_arg)
+        let arg_name = match tag_arg.kind {
+            TagKind::Proton => arg_names::PROTON_ARG,
+            TagKind::Wine { .. } if tag_arg.kind.eq(&TagKind::lol()) => arg_names::LOL_ARG,
+            TagKind::Wine { .. } => arg_names::WINE_ARG,
+        };
+        let versions = matches
+            .values_of(arg_name)
+            .unwrap()
+            .map(|tag| Version::new(tag, tag_arg.kind))
+            .collect();
+
+        RemoveArgs {
+            versions,
+            force: matches.is_present(arg_names::FORCE_ARG),
+            yes: matches.is_present(arg_names::YES_ARG),
+        }
     }
 }
 
 pub struct CheckArgs {
     pub kind: Option<TagKind>,
+    pub notify: bool,
+    pub refresh: bool,
 }
 
 impl CheckArgs {
     pub fn new(kind: Option<TagKind>) -> Self {
-        CheckArgs { kind }
+        CheckArgs {
+            kind,
+            notify: false,
+            refresh: false,
+        }
     }
 }
 
 impl From<ArgMatches> for CheckArgs {
     fn from(matches: ArgMatches) -> Self {
         let matches = matches.subcommand_matches(commands::CHECK).unwrap();
-        if matches.is_present(arg_group_names::TAG) {
+        let mut args = if matches.is_present(arg_group_names::TAG) {
             let tag_arg = TagArg::try_from(matches).expect("Could not create tag information from provided argument");
             let kind = tag_arg.kind;
             CheckArgs::new(Some(kind))
         } else {
             CheckArgs::new(None)
-        }
+        };
+        args.notify = matches.is_present(arg_names::NOTIFY_ARG);
+        args.refresh = matches.is_present(arg_names::REFRESH_ARG);
+
+        args
     }
 }
 
@@ -160,12 +458,19 @@ impl Default for CheckArgs {
 pub struct MigrationArgs {
     pub tag_arg: TagArg,
     pub source_path: PathBuf,
+    pub dry_run: bool,
+    pub dir_name: Option<String>,
 }
 
 impl MigrationArgs {
     pub fn new<P: Into<PathBuf>>(tag_arg: TagArg, source_path: P) -> Self {
         let source_path = source_path.into();
-        MigrationArgs { tag_arg, source_path }
+        MigrationArgs {
+            tag_arg,
+            source_path,
+            dry_run: false,
+            dir_name: None,
+        }
     }
 }
 
@@ -175,38 +480,133 @@ impl From<ArgMatches> for MigrationArgs {
         let tag_arg = TagArg::try_from(matches).expect("Could not create tag information from provided argument");
         let source_path = matches.value_of(arg_names::SOURCE_ARG).unwrap();
 
-        MigrationArgs::new(tag_arg, PathBuf::from(source_path))
+        let mut args = MigrationArgs::new(tag_arg, PathBuf::from(source_path));
+        args.dry_run = matches.is_present(arg_names::DRY_RUN_ARG);
+        args.dir_name = matches.value_of(arg_names::DIR_NAME_ARG).map(String::from);
+        args
+    }
+}
+
+/// Arguments of `migrate --source-dir`, which migrates every directory in the source directory.
+pub struct MigrateDirArgs {
+    pub kind: Option<TagKind>,
+    pub source_dir: PathBuf,
+    pub dry_run: bool,
+}
+
+impl MigrateDirArgs {
+    pub fn new<P: Into<PathBuf>>(kind: Option<TagKind>, source_dir: P) -> Self {
+        MigrateDirArgs {
+            kind,
+            source_dir: source_dir.into(),
+            dry_run: false,
+        }
+    }
+
+    /// Whether the migrate command was called with `--source-dir`.
+    pub fn is_present(matches: &ArgMatches) -> bool {
+        matches
+            .subcommand_matches(commands::MIGRATE)
+            .map_or(false, |matches| matches.is_present(arg_names::SOURCE_DIR_ARG))
+    }
+}
+
+impl From<ArgMatches> for MigrateDirArgs {
+    fn from(matches: ArgMatches) -> Self {
+        let matches = matches.subcommand_matches(commands::MIGRATE).unwrap();
+        let kind = TagArg::try_from(matches).ok().map(|tag_arg| tag_arg.kind);
+        let source_dir = matches.value_of(arg_names::SOURCE_DIR_ARG).unwrap();
+
+        let mut args = MigrateDirArgs::new(kind, PathBuf::from(source_dir));
+        args.dry_run = matches.is_present(arg_names::DRY_RUN_ARG);
+        args
     }
 }
 
+/// Direction in which `apply` steps from the currently applied version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ApplyStep {
+    Next,
+    Prev,
+}
+
 pub struct ApplyArgs {
     pub tag_arg: TagArg,
+    pub step: Option<ApplyStep>,
+    pub reset: bool,
+    pub symlink: Option<String>,
+    pub bottles: bool,
+    pub no_backup: bool,
+    /// Which backup `--reset` restores, 1 is the newest one.
+    pub backup: usize,
 }
 
 impl ApplyArgs {
     pub fn new(tag_arg: TagArg) -> Self {
-        ApplyArgs { tag_arg }
+        ApplyArgs {
+            tag_arg,
+            step: None,
+            reset: false,
+            symlink: None,
+            bottles: false,
+            no_backup: false,
+            backup: 1,
+        }
+    }
+}
+
+impl ApplyArgs {
+    pub fn create_from(matches: ArgMatches, config: &GeManConfig) -> Self {
+        let matches = matches.subcommand_matches(commands::APPLY).unwrap();
+        let tag_arg =
+            TagArg::try_from_or_default(matches, config.default_tag_kind()).unwrap_or_else(|err| err.exit());
+
+        let mut args = ApplyArgs::new(tag_arg);
+        if matches.is_present(arg_names::NEXT_ARG) {
+            args.step = Some(ApplyStep::Next);
+        } else if matches.is_present(arg_names::PREV_ARG) {
+            args.step = Some(ApplyStep::Prev);
+        }
+        args.reset = matches.is_present(arg_names::RESET_ARG);
+        args.symlink = matches.value_of(arg_names::SYMLINK_ARG).map(String::from);
+        args.bottles = matches.is_present(arg_names::BOTTLES_ARG);
+        args.no_backup = matches.is_present(arg_names::NO_BACKUP_ARG);
+        if let Some(backup) = matches.value_of(arg_names::BACKUP_ARG) {
+            args.backup = backup.parse().unwrap();
+        }
+        args
     }
 }
 
 impl From<ArgMatches> for ApplyArgs {
     fn from(matches: ArgMatches) -> Self {
-        let matches = matches.subcommand_matches(commands::APPLY).unwrap();
-        let tag_arg = TagArg::try_from(matches).expect("Could not create tag information from provided argument");
-        ApplyArgs::new(tag_arg)
+        ApplyArgs::create_from(matches, &GeManConfig::default())
     }
 }
 
 pub struct CopyUserSettingsArgs {
-    pub src_tag: Tag,
+    pub src_tag: Option<Tag>,
+    pub src_file: Option<PathBuf>,
     pub dst_tag: Tag,
 }
 
 impl CopyUserSettingsArgs {
     pub fn new<T: Into<Tag>>(src_tag: T, dst_tag: T) -> Self {
-        let src_tag = src_tag.into();
+        let src_tag = Some(src_tag.into());
         let dst_tag = dst_tag.into();
-        CopyUserSettingsArgs { src_tag, dst_tag }
+        CopyUserSettingsArgs {
+            src_tag,
+            src_file: None,
+            dst_tag,
+        }
+    }
+
+    pub fn from_file<P: Into<PathBuf>, T: Into<Tag>>(src_file: P, dst_tag: T) -> Self {
+        CopyUserSettingsArgs {
+            src_tag: None,
+            src_file: Some(src_file.into()),
+            dst_tag: dst_tag.into(),
+        }
     }
 }
 
@@ -220,10 +620,36 @@ impl From<ArgMatches> for CopyUserSettingsArgs {
     fn from(matches: ArgMatches) -> Self {
         let matches = matches.subcommand_matches(commands::PROTON_USER_SETTINGS).unwrap();
         let matches = matches.subcommand_matches(commands::USER_SETTINGS_COPY).unwrap();
+        let dst_tag = matches.value_of(arg_names::DESTINATION_ARG).unwrap();
+
+        match matches.value_of(arg_names::FROM_FILE_ARG) {
+            Some(src_file) => CopyUserSettingsArgs::from_file(src_file, dst_tag),
+            None => CopyUserSettingsArgs::new(matches.value_of(arg_names::SOURCE_ARG).unwrap(), dst_tag),
+        }
+    }
+}
+
+pub struct DiffUserSettingsArgs {
+    pub src_tag: Tag,
+    pub dst_tag: Tag,
+}
+
+impl DiffUserSettingsArgs {
+    pub fn new<T: Into<Tag>>(src_tag: T, dst_tag: T) -> Self {
+        let src_tag = src_tag.into();
+        let dst_tag = dst_tag.into();
+        DiffUserSettingsArgs { src_tag, dst_tag }
+    }
+}
+
+impl From<ArgMatches> for DiffUserSettingsArgs {
+    fn from(matches: ArgMatches) -> Self {
+        let matches = matches.subcommand_matches(commands::PROTON_USER_SETTINGS).unwrap();
+        let matches = matches.subcommand_matches(commands::USER_SETTINGS_DIFF).unwrap();
         let src_tag = matches.value_of(arg_names::SOURCE_ARG).unwrap();
         let dst_tag = matches.value_of(arg_names::DESTINATION_ARG).unwrap();
 
-        CopyUserSettingsArgs::new(src_tag, dst_tag)
+        DiffUserSettingsArgs::new(src_tag, dst_tag)
     }
 }
 
@@ -246,12 +672,139 @@ impl From<ArgMatches> for ForgetArgs {
     }
 }
 
+pub struct InfoArgs {
+    pub tag_arg: TagArg,
+}
+
+impl InfoArgs {
+    pub fn new(tag_arg: TagArg) -> Self {
+        InfoArgs { tag_arg }
+    }
+}
+
+impl From<ArgMatches> for InfoArgs {
+    fn from(matches: ArgMatches) -> Self {
+        let matches = matches.subcommand_matches(commands::INFO).unwrap();
+        let tag_arg = TagArg::try_from(matches).expect("Could not create tag information from provided argument");
+
+        InfoArgs::new(tag_arg)
+    }
+}
+
+pub struct PinArgs {
+    pub tag_arg: TagArg,
+    pub pinned: bool,
+}
+
+impl PinArgs {
+    pub fn new(tag_arg: TagArg, pinned: bool) -> Self {
+        PinArgs { tag_arg, pinned }
+    }
+}
+
+impl From<ArgMatches> for PinArgs {
+    fn from(matches: ArgMatches) -> Self {
+        let (command, matches) = matches.subcommand().unwrap();
+        let tag_arg = TagArg::try_from(matches).expect("Could not create tag information from provided argument");
+
+        PinArgs::new(tag_arg, command.eq(commands::PIN))
+    }
+}
+
+pub struct VerifyArgs {
+    pub version: Option<Version>,
+}
+
+impl VerifyArgs {
+    pub fn new(version: Option<Version>) -> Self {
+        VerifyArgs { version }
+    }
+}
+
+impl From<ArgMatches> for VerifyArgs {
+    fn from(matches: ArgMatches) -> Self {
+        let matches = matches.subcommand_matches(commands::VERIFY).unwrap();
+        let version = TagArg::try_from(matches).ok().map(|tag_arg| tag_arg.version());
+
+        VerifyArgs::new(version)
+    }
+}
+
+pub struct CompletionsArgs {
+    pub shell: Shell,
+}
+
+impl CompletionsArgs {
+    pub fn new(shell: Shell) -> Self {
+        CompletionsArgs { shell }
+    }
+}
+
+impl From<ArgMatches> for CompletionsArgs {
+    fn from(matches: ArgMatches) -> Self {
+        let matches = matches.subcommand_matches(commands::COMPLETIONS).unwrap();
+        let shell = matches.value_of(arg_names::SHELL_ARG).unwrap().parse().unwrap();
+
+        CompletionsArgs::new(shell)
+    }
+}
+
+pub struct ExportArgs {
+    pub script_path: Option<PathBuf>,
+    pub manifest_path: Option<PathBuf>,
+}
+
+impl ExportArgs {
+    pub fn new(script_path: Option<PathBuf>, manifest_path: Option<PathBuf>) -> Self {
+        ExportArgs {
+            script_path,
+            manifest_path,
+        }
+    }
+}
+
+impl From<ArgMatches> for ExportArgs {
+    fn from(matches: ArgMatches) -> Self {
+        let matches = matches.subcommand_matches(commands::EXPORT).unwrap();
+        let script_path = matches.value_of(arg_names::SCRIPT_ARG).map(PathBuf::from);
+        let manifest_path = matches.value_of(arg_names::MANIFEST_ARG).map(PathBuf::from);
+
+        ExportArgs::new(script_path, manifest_path)
+    }
+}
+
+pub struct ImportArgs {
+    pub manifest_path: PathBuf,
+    pub download: bool,
+}
+
+impl ImportArgs {
+    pub fn new<P: Into<PathBuf>>(manifest_path: P, download: bool) -> Self {
+        let manifest_path = manifest_path.into();
+        ImportArgs {
+            manifest_path,
+            download,
+        }
+    }
+}
+
+impl From<ArgMatches> for ImportArgs {
+    fn from(matches: ArgMatches) -> Self {
+        let matches = matches.subcommand_matches(commands::IMPORT).unwrap();
+        let manifest_path = matches.value_of(arg_names::MANIFEST_ARG).unwrap();
+        let download = matches.is_present(arg_names::DOWNLOAD_ARG);
+
+        ImportArgs::new(manifest_path, download)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use clap::ErrorKind;
     use test_case::test_case;
 
     use crate::clap::setup_clap;
+    use crate::config::DefaultKind;
 
     use super::*;
 
@@ -276,13 +829,43 @@ mod tests {
         assert_tag_arg(args.tag_arg, expected.tag_arg);
         assert_eq!(args.skip_checksum, expected.skip_checksum);
         assert_eq!(args.apply, expected.apply);
+        assert_eq!(args.checksum_algorithm, expected.checksum_algorithm);
+        assert_eq!(args.skip_existing, expected.skip_existing);
+        assert_eq!(args.keep_archive_cache, expected.keep_archive_cache);
+        assert_eq!(args.archive, expected.archive);
+        assert_eq!(args.checksum_file, expected.checksum_file);
+        assert_eq!(args.label, expected.label);
+        assert_eq!(args.dry_run, expected.dry_run);
+        assert_eq!(args.refresh, expected.refresh);
+        assert_eq!(args.force, expected.force);
+        assert_eq!(args.verify_only, expected.verify_only);
+        assert_eq!(args.keep_archive, expected.keep_archive);
+        assert_eq!(args.keep_archive_dir, expected.keep_archive_dir);
+        assert_eq!(args.steam_root, expected.steam_root);
     }
 
     fn remove_test_template(args: Vec<&str>, expected: RemoveArgs) {
         let matches = setup_clap().try_get_matches_from(args).unwrap();
         let args = RemoveArgs::from(matches);
 
+        assert_eq!(args.versions, expected.versions);
+        assert_eq!(args.force, expected.force);
+        assert_eq!(args.yes, expected.yes);
+    }
+
+    fn verify_test_template(args: Vec<&str>, expected: VerifyArgs) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = VerifyArgs::from(matches);
+
+        assert_eq!(args.version, expected.version);
+    }
+
+    fn pin_test_template(args: Vec<&str>, expected: PinArgs) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = PinArgs::from(matches);
+
         assert_tag_arg(args.tag_arg, expected.tag_arg);
+        assert_eq!(args.pinned, expected.pinned);
     }
 
     fn check_test_template(args: Vec<&str>, expected: CheckArgs) {
@@ -290,6 +873,8 @@ mod tests {
         let args = CheckArgs::from(matches);
 
         assert_eq!(args.kind, expected.kind);
+        assert_eq!(args.notify, expected.notify);
+        assert_eq!(args.refresh, expected.refresh);
     }
 
     fn migration_test_template(args: Vec<&str>, expected: MigrationArgs) {
@@ -298,6 +883,18 @@ mod tests {
 
         assert_tag_arg(args.tag_arg, expected.tag_arg);
         assert_eq!(args.source_path, expected.source_path);
+        assert_eq!(args.dry_run, expected.dry_run);
+        assert_eq!(args.dir_name, expected.dir_name);
+    }
+
+    fn migrate_dir_test_template(args: Vec<&str>, expected: MigrateDirArgs) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        assert!(MigrateDirArgs::is_present(&matches));
+        let args = MigrateDirArgs::from(matches);
+
+        assert_eq!(args.kind, expected.kind);
+        assert_eq!(args.source_dir, expected.source_dir);
+        assert_eq!(args.dry_run, expected.dry_run);
     }
 
     fn apply_test_template(args: Vec<&str>, expected: ApplyArgs) {
@@ -305,6 +902,12 @@ mod tests {
         let args = ApplyArgs::from(matches);
 
         assert_tag_arg(args.tag_arg, expected.tag_arg);
+        assert_eq!(args.step, expected.step);
+        assert_eq!(args.reset, expected.reset);
+        assert_eq!(args.symlink, expected.symlink);
+        assert_eq!(args.bottles, expected.bottles);
+        assert_eq!(args.no_backup, expected.no_backup);
+        assert_eq!(args.backup, expected.backup);
     }
 
     fn copy_user_settings_test_template(args: Vec<&str>, expected: CopyUserSettingsArgs) {
@@ -312,6 +915,7 @@ mod tests {
         let args = CopyUserSettingsArgs::from(matches);
 
         assert_eq!(args.src_tag, expected.src_tag);
+        assert_eq!(args.src_file, expected.src_file);
         assert_eq!(args.dst_tag, expected.dst_tag);
     }
 
@@ -322,12 +926,38 @@ mod tests {
         assert_tag_arg(args.tag_arg, expected.tag_arg);
     }
 
+    fn info_test_template(args: Vec<&str>, expected: InfoArgs) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = InfoArgs::from(matches);
+
+        assert_tag_arg(args.tag_arg, expected.tag_arg);
+    }
+
+    fn export_test_template(args: Vec<&str>, expected: ExportArgs) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = ExportArgs::from(matches);
+
+        assert_eq!(args.script_path, expected.script_path);
+        assert_eq!(args.manifest_path, expected.manifest_path);
+    }
+
     fn list_test_template(args: Vec<&str>, expected: ListArgs) {
         let matches = setup_clap().try_get_matches_from(args).unwrap();
         let args = ListArgs::from(matches);
 
         assert_eq!(args.kind, expected.kind);
         assert_eq!(args.newest, expected.newest);
+        assert_eq!(args.limit, expected.limit);
+        assert_eq!(args.page, expected.page);
+        assert_eq!(args.merged, expected.merged);
+        assert_eq!(args.json, expected.json);
+        assert_eq!(args.size, expected.size);
+        assert_eq!(args.filter, expected.filter);
+        assert_eq!(args.all, expected.all);
+        assert_eq!(args.sort, expected.sort);
+        assert_eq!(args.show_added, expected.show_added);
+        assert_eq!(args.include_lol, expected.include_lol);
+        assert_eq!(args.manifest, expected.manifest);
     }
 
     #[test_case("-p"; "Add specific Proton GE version")]
@@ -376,37 +1006,38 @@ mod tests {
     }
 
     #[test]
-    fn add_should_require_one_tag_arg() {
-        let args = vec!["geman", "add"];
-        let result = setup_clap().try_get_matches_from(args);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    fn add_should_use_config_defaults_when_no_flags_are_provided() {
+        let mut config = GeManConfig::default();
+        config.defaults.proton.skip_checksum = true;
+        config.defaults.proton.apply_after_add = true;
+
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "add", "-p"]).unwrap();
+        let args = AddArgs::create_from(matches, &config);
+        assert!(args.skip_checksum);
+        assert!(args.apply);
+
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "add", "-w"]).unwrap();
+        let args = AddArgs::create_from(matches, &config);
+        assert!(!args.skip_checksum);
+        assert!(!args.apply);
     }
 
-    #[test_case("-p"; "Remove Proton GE version")]
-    #[test_case("-w"; "Remove Wine GE version")]
-    #[test_case("-l"; "Remove Wine GE LoL version")]
-    fn remove_specific_tag(kind: &str) {
-        let args = vec!["geman", "rm", kind, "6.20-GE-1"];
-        let expected = RemoveArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), kind_str_to_enum(kind)));
-        remove_test_template(args, expected);
-    }
+    #[test]
+    fn add_flags_should_override_config_defaults() {
+        let mut config = GeManConfig::default();
+        config.defaults.lol.skip_checksum = true;
+        config.defaults.lol.apply_after_add = true;
 
-    #[test_case("-p"; "Remove Proton GE version")]
-    #[test_case("-w"; "Remove Wine GE version")]
-    #[test_case("-l"; "Remove Wine GE LoL version")]
-    fn remove_should_require_a_tag_argument_with_a_value(kind: &str) {
-        let args = vec!["geman", "rm", kind];
-        let result = setup_clap().try_get_matches_from(args);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert_eq!(err.kind(), ErrorKind::EmptyValue);
+        let args = vec!["geman", "add", "-l", "--checksum", "--no-apply"];
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = AddArgs::create_from(matches, &config);
+        assert!(!args.skip_checksum);
+        assert!(!args.apply);
     }
 
     #[test]
-    fn remove_only_one_tag_arg_allowed() {
-        let args = vec!["geman", "rm", "-p", "6.20-GE-1", "-w", "6.20-GE-1"];
+    fn add_apply_and_no_apply_conflict() {
+        let args = vec!["geman", "add", "-p", "--apply", "--no-apply"];
         let result = setup_clap().try_get_matches_from(args);
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -414,43 +1045,358 @@ mod tests {
     }
 
     #[test]
-    fn remove_should_require_a_tag_argument() {
-        let args = vec!["geman", "rm"];
-        let result = setup_clap().try_get_matches_from(args);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    fn add_with_checksum_algorithm() {
+        let args = vec!["geman", "add", "-p", "--checksum-algorithm", "sha256"];
+        let mut expected = AddArgs::new(TagArg::new(None, TagKind::Proton), false, false);
+        expected.checksum_algorithm = Some(ChecksumAlgorithm::Sha256);
+        add_test_template(args, expected);
     }
 
     #[test]
-    fn check_without_args_should_not_throw_error() {
-        let args = vec!["geman", "check"];
-        check_test_template(args, CheckArgs::default());
+    fn add_with_skip_existing() {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--skip-existing"];
+        let mut expected = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+        expected.skip_existing = true;
+        add_test_template(args, expected);
     }
 
-    #[test_case("-p"; "Check for Proton GE")]
-    #[test_case("-w"; "Check for Wine GE")]
-    #[test_case("-l"; "Check for Wine GE LoL")]
-    fn check_with_tag_kind(kind: &str) {
-        let args = vec!["geman", "check", kind];
-        let expected = CheckArgs::new(Some(kind_str_to_enum(kind)));
-        check_test_template(args, expected);
+    #[test]
+    fn add_with_local_archive_and_checksum_file() {
+        let args = vec![
+            "geman",
+            "add",
+            "-p",
+            "6.20-GE-1",
+            "--archive",
+            "/tmp/Proton-6.20-GE-1.tar.gz",
+            "--checksum-file",
+            "/tmp/Proton-6.20-GE-1.sha512sum",
+        ];
+        let mut expected = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+        expected.archive = Some(PathBuf::from("/tmp/Proton-6.20-GE-1.tar.gz"));
+        expected.checksum_file = Some(PathBuf::from("/tmp/Proton-6.20-GE-1.sha512sum"));
+        add_test_template(args, expected);
     }
 
     #[test]
-    fn check_only_one_tag_arg_allowed() {
-        let args = vec!["geman", "check", "-p", "-w"];
+    fn add_checksum_file_requires_archive() {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--checksum-file", "/tmp/checksum"];
         let result = setup_clap().try_get_matches_from(args);
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
     }
 
     #[test]
-    fn migrate_tag_arg_is_required() {
-        let args = vec!["geman", "migrate", "-s", "/tmp"];
-        let result = setup_clap().try_get_matches_from(args);
-        assert!(result.is_err());
+    fn add_with_keep_archive_cache() {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--keep-archive-cache"];
+        let mut expected = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+        expected.keep_archive_cache = true;
+        add_test_template(args, expected);
+    }
+
+    #[test]
+    fn add_with_label() {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--label", "patched"];
+        let mut expected = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+        expected.label = Some(String::from("patched"));
+        add_test_template(args, expected);
+    }
+
+    #[test]
+    fn add_with_dry_run() {
+        let args = vec!["geman", "add", "-p", "--dry-run"];
+        let mut expected = AddArgs::new(TagArg::new(None, TagKind::Proton), false, false);
+        expected.dry_run = true;
+        add_test_template(args, expected);
+    }
+
+    #[test]
+    fn add_with_refresh() {
+        let args = vec!["geman", "add", "-w", "--refresh"];
+        let mut expected = AddArgs::new(TagArg::new(None, TagKind::wine()), false, false);
+        expected.refresh = true;
+        add_test_template(args, expected);
+    }
+
+    #[test_case("patched/../x"; "label with path separator")]
+    #[test_case(""; "empty label")]
+    fn add_with_invalid_label(label: &str) {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--label", label];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn add_checksum_algorithm_conflicts_with_skip_checksum() {
+        let args = vec![
+            "geman",
+            "add",
+            "-p",
+            "--checksum-algorithm",
+            "sha512",
+            "--skip-checksum",
+        ];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn add_with_force() {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--force"];
+        let mut expected = AddArgs::new(
+            TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton),
+            false,
+            false,
+        );
+        expected.force = true;
+        add_test_template(args, expected);
+    }
+
+    #[test]
+    fn add_force_conflicts_with_skip_existing() {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--force", "--skip-existing"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test_case("--verify-only"; "verify only")]
+    #[test_case("--checksum-only"; "checksum only alias")]
+    fn add_with_verify_only(flag: &str) {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", flag];
+        let mut expected = AddArgs::new(
+            TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton),
+            false,
+            false,
+        );
+        expected.verify_only = true;
+        add_test_template(args, expected);
+    }
+
+    #[test]
+    fn add_with_keep_archive() {
+        let args = vec!["geman", "add", "--keep-archive", "-p", "6.20-GE-1"];
+        let mut expected = AddArgs::new(
+            TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton),
+            false,
+            false,
+        );
+        expected.keep_archive = true;
+        add_test_template(args, expected);
+    }
+
+    #[test]
+    fn add_with_keep_archive_dir() {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--keep-archive=/tmp/archives"];
+        let mut expected = AddArgs::new(
+            TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton),
+            false,
+            false,
+        );
+        expected.keep_archive = true;
+        expected.keep_archive_dir = Some(PathBuf::from("/tmp/archives"));
+        add_test_template(args, expected);
+    }
+
+    #[test]
+    fn add_keep_archive_should_not_take_the_positional_tag_as_directory() {
+        let matches = setup_clap()
+            .try_get_matches_from(vec!["geman", "add", "--keep-archive", "GE-Proton7-8"])
+            .unwrap();
+        let matches = matches.subcommand_matches(commands::ADD).unwrap();
+        assert!(matches.is_present(arg_names::KEEP_ARCHIVE_ARG));
+        assert_eq!(matches.value_of(arg_names::KEEP_ARCHIVE_ARG), None);
+        assert_eq!(matches.value_of(arg_names::TAG_ARG), Some("GE-Proton7-8"));
+    }
+
+    #[test]
+    fn add_with_steam_root() {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--steam-root", "test_resources"];
+        let mut expected = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+        expected.steam_root = Some(PathBuf::from("test_resources"));
+        add_test_template(args, expected);
+    }
+
+    #[test]
+    fn add_with_steam_root_should_not_apply_after_add_from_config() {
+        let mut config = GeManConfig::default();
+        config.defaults.proton.apply_after_add = true;
+
+        let args = vec!["geman", "add", "-p", "--steam-root", "test_resources"];
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = AddArgs::create_from(matches, &config);
+        assert!(!args.apply);
+        assert_eq!(args.steam_root, Some(PathBuf::from("test_resources")));
+    }
+
+    #[test_case("does-not-exist"; "missing directory")]
+    #[test_case("Cargo.toml"; "file")]
+    fn add_with_invalid_steam_root(steam_root: &str) {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--steam-root", steam_root];
+        let result = setup_clap().try_get_matches_from(args);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test_case(vec!["-w"]; "wine")]
+    #[test_case(vec!["-l"]; "lol")]
+    #[test_case(vec!["-p", "--apply"]; "apply")]
+    fn add_steam_root_conflicts_with(flags: Vec<&str>) {
+        let mut args = vec!["geman", "add", "--steam-root", "test_resources"];
+        args.extend(flags);
+        let result = setup_clap().try_get_matches_from(args);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test_case("--skip-checksum"; "skip checksum")]
+    #[test_case("--apply"; "apply")]
+    #[test_case("--force"; "force")]
+    #[test_case("--dry-run"; "dry run")]
+    fn add_verify_only_conflicts_with(flag: &str) {
+        let args = vec!["geman", "add", "-p", "6.20-GE-1", "--verify-only", flag];
+        let result = setup_clap().try_get_matches_from(args);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn add_should_require_one_tag_arg() {
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "add"]).unwrap();
+        let result = TagArg::try_from_or_default(matches.subcommand_matches(commands::ADD).unwrap(), None);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test_case(None; "Without default kind")]
+    #[test_case(Some(TagKind::Proton); "With default kind")]
+    fn add_tag_for_kind_flag_and_positional_tag_should_conflict(default_kind: Option<TagKind>) {
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "add", "-p", "7-20", "extra"]).unwrap();
+        let result = TagArg::try_from_or_default(matches.subcommand_matches(commands::ADD).unwrap(), default_kind);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn add_without_kind_should_use_default_kind() {
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "add", "6.21-GE-1"]).unwrap();
+        let mut config = GeManConfig::default();
+        config.default_kind = Some(DefaultKind::Proton);
+        let args = AddArgs::create_from(matches, &config);
+
+        assert_tag_arg(args.tag_arg, TagArg::new(Some(Tag::from("6.21-GE-1")), TagKind::Proton));
+    }
+
+    #[test_case(vec!["geman", "add", "-w", "6.21-GE-1"], Some("6.21-GE-1"), TagKind::wine(); "Kind flag with tag")]
+    #[test_case(vec!["geman", "add", "-l", "6.16-GE-3-LoL"], Some("6.16-GE-3-LoL"), TagKind::lol(); "LoL flag")]
+    #[test_case(vec!["geman", "add", "6.21-GE-1", "-w"], Some("6.21-GE-1"), TagKind::wine(); "Positional tag")]
+    #[test_case(vec!["geman", "add"], None, TagKind::Proton; "Latest of default kind")]
+    fn tag_arg_with_default_kind(args: Vec<&str>, tag: Option<&str>, kind: TagKind) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let tag_arg =
+            TagArg::try_from_or_default(matches.subcommand_matches(commands::ADD).unwrap(), Some(TagKind::Proton))
+                .unwrap();
+
+        assert_tag_arg(tag_arg, TagArg::new(tag.map(Tag::from), kind));
+    }
+
+    #[test_case("-p"; "Remove Proton GE version")]
+    #[test_case("-w"; "Remove Wine GE version")]
+    #[test_case("-l"; "Remove Wine GE LoL version")]
+    fn remove_specific_tag(kind: &str) {
+        let args = vec!["geman", "rm", kind, "6.20-GE-1"];
+        let expected = RemoveArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), kind_str_to_enum(kind)));
+        remove_test_template(args, expected);
+    }
+
+    #[test_case("-p"; "Remove Proton GE versions")]
+    #[test_case("-w"; "Remove Wine GE versions")]
+    #[test_case("-l"; "Remove Wine GE LoL versions")]
+    fn remove_multiple_tags(kind: &str) {
+        let args = vec!["geman", "rm", kind, "6.20-GE-1", "6.19-GE-1"];
+        let mut expected = RemoveArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), kind_str_to_enum(kind)));
+        expected
+            .versions
+            .push(Version::new("6.19-GE-1", kind_str_to_enum(kind)));
+        remove_test_template(args, expected);
+    }
+
+    #[test_case("-p"; "Remove Proton GE version")]
+    #[test_case("-w"; "Remove Wine GE version")]
+    #[test_case("-l"; "Remove Wine GE LoL version")]
+    fn remove_should_require_a_tag_argument_with_a_value(kind: &str) {
+        let args = vec!["geman", "rm", kind];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::EmptyValue);
+    }
+
+    #[test]
+    fn remove_only_one_tag_arg_allowed() {
+        let args = vec!["geman", "rm", "-p", "6.20-GE-1", "-w", "6.20-GE-1"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn remove_should_require_a_tag_argument() {
+        let args = vec!["geman", "rm"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn check_without_args_should_not_throw_error() {
+        let args = vec!["geman", "check"];
+        check_test_template(args, CheckArgs::default());
+    }
+
+    #[test_case("-p"; "Check for Proton GE")]
+    #[test_case("-w"; "Check for Wine GE")]
+    #[test_case("-l"; "Check for Wine GE LoL")]
+    fn check_with_tag_kind(kind: &str) {
+        let args = vec!["geman", "check", kind];
+        let expected = CheckArgs::new(Some(kind_str_to_enum(kind)));
+        check_test_template(args, expected);
+    }
+
+    #[test]
+    fn check_with_notify() {
+        let args = vec!["geman", "check", "--notify"];
+        let mut expected = CheckArgs::default();
+        expected.notify = true;
+        check_test_template(args, expected);
+    }
+
+    #[test_case("--refresh"; "With refresh")]
+    #[test_case("--no-cache"; "With no-cache alias")]
+    fn check_with_refresh(flag: &str) {
+        let args = vec!["geman", "check", "-p", flag];
+        let mut expected = CheckArgs::new(Some(TagKind::Proton));
+        expected.refresh = true;
+        check_test_template(args, expected);
+    }
+
+    #[test]
+    fn check_only_one_tag_arg_allowed() {
+        let args = vec!["geman", "check", "-p", "-w"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn migrate_tag_arg_is_required() {
+        let args = vec!["geman", "migrate", "-s", "/tmp"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
         let err = result.unwrap_err();
         assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
     }
@@ -476,6 +1422,62 @@ mod tests {
         migration_test_template(args, expected);
     }
 
+    #[test]
+    fn migrate_with_dry_run() {
+        let args = vec!["geman", "migrate", "-p", "6.20-GE-1", "-s", "/tmp", "--dry-run"];
+        let mut expected = MigrationArgs::new(
+            TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton),
+            PathBuf::from("/tmp"),
+        );
+        expected.dry_run = true;
+        migration_test_template(args, expected);
+    }
+
+    #[test]
+    fn migrate_with_dir_name() {
+        let args = vec!["geman", "migrate", "-p", "6.20-GE-1", "-s", "/tmp", "--dir-name", "shared-proton-ge"];
+        let mut expected = MigrationArgs::new(
+            TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton),
+            PathBuf::from("/tmp"),
+        );
+        expected.dir_name = Some(String::from("shared-proton-ge"));
+        migration_test_template(args, expected);
+    }
+
+    #[test_case(".hidden"; "hidden directory")]
+    #[test_case("some/dir"; "nested directory")]
+    #[test_case(""; "empty name")]
+    fn migrate_with_invalid_dir_name(dir_name: &str) {
+        let args = vec!["geman", "migrate", "-p", "6.20-GE-1", "-s", "/tmp", "--dir-name", dir_name];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn migrate_with_source_dir_infers_kind() {
+        let args = vec!["geman", "migrate", "--source-dir", "/tmp"];
+        migrate_dir_test_template(args, MigrateDirArgs::new(None, "/tmp"));
+    }
+
+    #[test_case("-p"; "Migration of Proton GE directories")]
+    #[test_case("-w"; "Migration of Wine GE directories")]
+    #[test_case("-l"; "Migration of Wine GE LoL directories")]
+    fn migrate_with_source_dir_and_kind(kind: &str) {
+        let args = vec!["geman", "migrate", kind, "--source-dir", "/tmp", "--dry-run"];
+        let mut expected = MigrateDirArgs::new(Some(kind_str_to_enum(kind)), "/tmp");
+        expected.dry_run = true;
+        migrate_dir_test_template(args, expected);
+    }
+
+    #[test]
+    fn migrate_source_conflicts_with_source_dir() {
+        let args = vec!["geman", "migrate", "-p", "6.20-GE-1", "-s", "/tmp", "--source-dir", "/tmp"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn migrate_only_one_tag_arg_allowed() {
         let args = vec![
@@ -505,22 +1507,49 @@ mod tests {
 
     #[test]
     fn apply_missing_required_tag_arg() {
-        let args = vec!["geman", "apply"];
-        let result = setup_clap().try_get_matches_from(args);
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "apply"]).unwrap();
+        let result = TagArg::try_from_or_default(matches.subcommand_matches(commands::APPLY).unwrap(), None);
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
     }
 
+    #[test]
+    fn apply_tag_for_kind_flag_and_positional_tag_should_conflict() {
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "apply", "-w", "6.20-GE-1", "extra"]).unwrap();
+        let result = TagArg::try_from_or_default(matches.subcommand_matches(commands::APPLY).unwrap(), None);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn apply_without_kind_should_use_default_kind() {
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "apply", "GE-Proton7-20"]).unwrap();
+        let mut config = GeManConfig::default();
+        config.default_kind = Some(DefaultKind::Wine);
+        let args = ApplyArgs::create_from(matches, &config);
+
+        assert!(args.tag_arg.kind_from_default);
+        assert_tag_arg(args.tag_arg, TagArg::new(Some(Tag::from("GE-Proton7-20")), TagKind::wine()));
+    }
+
+    #[test]
+    fn apply_with_kind_flag_should_not_use_default_kind() {
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "apply", "-p", "GE-Proton7-20"]).unwrap();
+        let mut config = GeManConfig::default();
+        config.default_kind = Some(DefaultKind::Wine);
+        let args = ApplyArgs::create_from(matches, &config);
+
+        assert!(!args.tag_arg.kind_from_default);
+        assert_tag_arg(args.tag_arg, TagArg::new(Some(Tag::from("GE-Proton7-20")), TagKind::Proton));
+    }
+
     #[test_case("-p"; "Apply for Proton GE")]
     #[test_case("-w"; "Apply for Wine GE")]
     #[test_case("-l"; "Apply for Wine GE LoL")]
-    fn apply_missing_value_for_tag_arg(kind: &str) {
+    fn apply_without_tag_value_should_apply_newest_version(kind: &str) {
         let args = vec!["geman", "apply", kind];
-        let result = setup_clap().try_get_matches_from(args);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert_eq!(err.kind(), ErrorKind::EmptyValue);
+        let expected = ApplyArgs::new(TagArg::new(None, kind_str_to_enum(kind)));
+        apply_test_template(args, expected);
     }
 
     #[test]
@@ -535,11 +1564,45 @@ mod tests {
             "6.21-GE-1",
         ];
         let mut expected = CopyUserSettingsArgs::default();
-        expected.src_tag = Tag::from("6.20-GE-1");
+        expected.src_tag = Some(Tag::from("6.20-GE-1"));
         expected.dst_tag = Tag::from("6.21-GE-1");
         copy_user_settings_test_template(args, expected);
     }
 
+    #[test]
+    fn copy_user_settings_from_file() {
+        let args = vec![
+            "geman",
+            "user-settings",
+            "copy",
+            "--from-file",
+            "/tmp/user_settings.py",
+            "-d",
+            "6.21-GE-1",
+        ];
+        let expected = CopyUserSettingsArgs::from_file("/tmp/user_settings.py", "6.21-GE-1");
+        copy_user_settings_test_template(args, expected);
+    }
+
+    #[test]
+    fn copy_user_settings_source_conflicts_with_from_file() {
+        let args = vec![
+            "geman",
+            "user-settings",
+            "copy",
+            "-s",
+            "6.20-GE-1",
+            "--from-file",
+            "/tmp/user_settings.py",
+            "-d",
+            "6.21-GE-1",
+        ];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn copy_user_settings_should_fail_without_destination() {
         let args = vec!["geman", "user-settings", "copy", "-s", "6.20-GE-1"];
@@ -558,8 +1621,97 @@ mod tests {
         assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
     }
 
-    #[test_case("-p"; "Forget a Proton GE version")]
-    #[test_case("-w"; "Forget a Wine GE version")]
+    #[test]
+    fn diff_user_settings_with_all_required_args() {
+        let args = vec!["geman", "us", "diff", "-s", "6.20-GE-1", "-d", "6.21-GE-1"];
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = DiffUserSettingsArgs::from(matches);
+
+        assert_eq!(args.src_tag, Tag::from("6.20-GE-1"));
+        assert_eq!(args.dst_tag, Tag::from("6.21-GE-1"));
+    }
+
+    #[test_case(vec!["geman", "user-settings", "diff", "-s", "6.20-GE-1"]; "without destination")]
+    #[test_case(vec!["geman", "user-settings", "diff", "-d", "6.21-GE-1"]; "without source")]
+    fn diff_user_settings_should_fail_without_required_args(args: Vec<&str>) {
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn remove_with_force() {
+        let args = vec!["geman", "rm", "-p", "6.20-GE-1", "--force"];
+        let mut expected = RemoveArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        expected.force = true;
+        remove_test_template(args, expected);
+    }
+
+    #[test_case("--yes"; "Long yes flag")]
+    #[test_case("-y"; "Short yes flag")]
+    fn remove_with_yes(flag: &str) {
+        let args = vec!["geman", "rm", "-p", "6.20-GE-1", "6.19-GE-1", flag];
+        let mut expected = RemoveArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        expected.versions.push(Version::new("6.19-GE-1", TagKind::Proton));
+        expected.yes = true;
+        remove_test_template(args, expected);
+    }
+
+    #[test_case("pin", true; "Pin a version")]
+    #[test_case("unpin", false; "Unpin a version")]
+    fn pin_with_all_required_args(command: &str, pinned: bool) {
+        let args = vec!["geman", command, "-w", "6.20-GE-1"];
+        let expected = PinArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::wine()), pinned);
+        pin_test_template(args, expected);
+    }
+
+    #[test_case("pin"; "Pin without tag")]
+    #[test_case("unpin"; "Unpin without tag")]
+    fn pin_without_tag(command: &str) {
+        let args = vec!["geman", command, "-p"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::EmptyValue);
+    }
+
+    #[test_case("bash", Shell::Bash; "Bash completions")]
+    #[test_case("zsh", Shell::Zsh; "Zsh completions")]
+    #[test_case("fish", Shell::Fish; "Fish completions")]
+    #[test_case("powershell", Shell::PowerShell; "PowerShell completions")]
+    fn completions_for_shell(shell: &str, expected: Shell) {
+        let args = vec!["geman", "completions", shell];
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        assert_eq!(CompletionsArgs::from(matches).shell, expected);
+    }
+
+    #[test]
+    fn completions_for_unsupported_shell() {
+        let args = vec!["geman", "completions", "elvish"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn verify_without_version() {
+        let args = vec!["geman", "verify"];
+        verify_test_template(args, VerifyArgs::new(None));
+    }
+
+    #[test_case("-p"; "Verify a Proton GE version")]
+    #[test_case("-w"; "Verify a Wine GE version")]
+    #[test_case("-l"; "Verify a Wine GE LoL version")]
+    fn verify_specific_version(kind: &str) {
+        let args = vec!["geman", "verify", kind, "6.20-GE-1"];
+        let expected = VerifyArgs::new(Some(Version::new("6.20-GE-1", kind_str_to_enum(kind))));
+        verify_test_template(args, expected);
+    }
+
+    #[test_case("-p"; "Forget a Proton GE version")]
+    #[test_case("-w"; "Forget a Wine GE version")]
     #[test_case("-l"; "Forget a Wine GE LoL version")]
     fn forget_without_tag(kind: &str) {
         let args = vec!["geman", "forget", kind];
@@ -585,6 +1737,26 @@ mod tests {
         forget_test_template(args, expected);
     }
 
+    #[test_case("-p"; "Info of a Proton GE version")]
+    #[test_case("-w"; "Info of a Wine GE version")]
+    #[test_case("-l"; "Info of a Wine GE LoL version")]
+    fn info_without_tag(kind: &str) {
+        let args = vec!["geman", "info", kind];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::EmptyValue);
+    }
+
+    #[test_case("-p"; "Info of a Proton GE version")]
+    #[test_case("-w"; "Info of a Wine GE version")]
+    #[test_case("-l"; "Info of a Wine GE LoL version")]
+    fn info_with_all_required_args(kind: &str) {
+        let args = vec!["geman", "info", kind, "6.20-GE-1"];
+        let expected = InfoArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), kind_str_to_enum(kind)));
+        info_test_template(args, expected);
+    }
+
     #[test_case("-p"; "Forget a Proton GE version")]
     #[test_case("-w"; "Forget a Wine GE version")]
     #[test_case("-l"; "Forget a Wine GE LoL version")]
@@ -607,4 +1779,460 @@ mod tests {
         let expected = ListArgs::new(None, true);
         list_test_template(args, expected);
     }
+
+    #[test]
+    fn list_with_limit_and_page() {
+        let args = vec!["geman", "list", "--limit", "5", "--page", "2"];
+        let mut expected = ListArgs::new(None, false);
+        expected.limit = Some(5);
+        expected.page = 2;
+        list_test_template(args, expected);
+    }
+
+    #[test]
+    fn list_page_requires_limit() {
+        let args = vec!["geman", "list", "--page", "2"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn list_limit_must_be_greater_than_zero() {
+        let args = vec!["geman", "list", "--limit", "0"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn export_with_script() {
+        let args = vec!["geman", "export", "--script", "install.sh"];
+        let expected = ExportArgs::new(Some(PathBuf::from("install.sh")), None);
+        export_test_template(args, expected);
+    }
+
+    #[test]
+    fn export_with_manifest() {
+        let args = vec!["geman", "export", "versions.json"];
+        let expected = ExportArgs::new(None, Some(PathBuf::from("versions.json")));
+        export_test_template(args, expected);
+    }
+
+    #[test]
+    fn export_with_script_and_manifest() {
+        let args = vec!["geman", "export", "--script", "install.sh", "versions.json"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test_case(vec!["geman", "import", "versions.json"], false; "without download")]
+    #[test_case(vec!["geman", "import", "versions.json", "--download"], true; "with download")]
+    fn import(args: Vec<&str>, download: bool) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = ImportArgs::from(matches);
+
+        assert_eq!(args.manifest_path, PathBuf::from("versions.json"));
+        assert_eq!(args.download, download);
+    }
+
+    #[test]
+    fn import_without_manifest() {
+        let args = vec!["geman", "import"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn export_without_script() {
+        let args = vec!["geman", "export"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn list_merged() {
+        let args = vec!["geman", "list", "--merged"];
+        let mut expected = ListArgs::new(None, false);
+        expected.merged = true;
+        list_test_template(args, expected);
+    }
+
+    #[test]
+    fn list_json() {
+        let args = vec!["geman", "list", "-p", "--json"];
+        let mut expected = ListArgs::new(Some(TagKind::Proton), false);
+        expected.json = true;
+        list_test_template(args, expected);
+    }
+
+    #[test_case("semver", ListSort::Semver; "Semver")]
+    #[test_case("name", ListSort::Name; "Name")]
+    #[test_case("added", ListSort::Added; "Added")]
+    fn list_with_sort(sort: &str, expected_sort: ListSort) {
+        let args = vec!["geman", "list", "--sort", sort];
+        let mut expected = ListArgs::new(None, false);
+        expected.sort = expected_sort;
+        list_test_template(args, expected);
+    }
+
+    #[test]
+    fn list_show_added() {
+        let args = vec!["geman", "list", "-w", "--show-added"];
+        let mut expected = ListArgs::new(Some(TagKind::wine()), false);
+        expected.show_added = true;
+        list_test_template(args, expected);
+    }
+
+    #[test]
+    fn list_with_global_verbose_should_not_show_added() {
+        let args = vec!["geman", "list", "-w", "--verbose"];
+        let expected = ListArgs::new(Some(TagKind::wine()), false);
+        list_test_template(args, expected);
+    }
+
+    #[test]
+    fn list_wine_with_lol() {
+        let args = vec!["geman", "list", "-w", "--include-lol"];
+        let mut expected = ListArgs::new(Some(TagKind::wine()), false);
+        expected.include_lol = true;
+        list_test_template(args, expected);
+    }
+
+    #[test_case(vec!["geman", "list", "--include-lol"]; "Without kind")]
+    #[test_case(vec!["geman", "list", "-p", "--include-lol"]; "With Proton kind")]
+    fn list_include_lol_requires_wine(args: Vec<&str>) {
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn list_with_unknown_sort() {
+        let args = vec!["geman", "list", "--sort", "size"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn list_json_conflicts_with_merged() {
+        let args = vec!["geman", "list", "--json", "--merged"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn list_with_manifest() {
+        let args = vec!["geman", "list", "--manifest", "/tmp/managed_versions.json.bak"];
+        let mut expected = ListArgs::new(None, false);
+        expected.manifest = Some(PathBuf::from("/tmp/managed_versions.json.bak"));
+        list_test_template(args, expected);
+    }
+
+    #[test]
+    fn list_manifest_conflicts_with_merged() {
+        let args = vec!["geman", "list", "--manifest", "versions.json", "--merged"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn list_with_size() {
+        let args = vec!["geman", "list", "--merged", "--size"];
+        let mut expected = ListArgs::new(None, false);
+        expected.merged = true;
+        expected.size = true;
+        list_test_template(args, expected);
+    }
+
+    #[test]
+    fn list_with_filter() {
+        let args = vec!["geman", "list", "-p", "-n", "--filter", "6.21-*"];
+        let mut expected = ListArgs::new(Some(TagKind::Proton), true);
+        expected.filter = Some(String::from("6.21-*"));
+        list_test_template(args, expected);
+    }
+
+    #[test]
+    fn list_all() {
+        let args = vec!["geman", "list", "--all"];
+        let mut expected = ListArgs::new(None, false);
+        expected.all = true;
+        list_test_template(args, expected);
+    }
+
+    #[test_case("-p"; "Proton GE")]
+    #[test_case("-w"; "Wine GE")]
+    #[test_case("-l"; "Wine GE LoL")]
+    fn list_all_conflicts_with_kind(kind: &str) {
+        let args = vec!["geman", "list", "--all", kind];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn list_merged_conflicts_with_newest() {
+        let args = vec!["geman", "list", "--merged", "-n"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test_case("--next", ApplyStep::Next; "Apply next version")]
+    #[test_case("--prev", ApplyStep::Prev; "Apply previous version")]
+    fn apply_step(flag: &str, step: ApplyStep) {
+        let args = vec!["geman", "apply", "-p", flag];
+        let mut expected = ApplyArgs::new(TagArg::new(None, TagKind::Proton));
+        expected.step = Some(step);
+        apply_test_template(args, expected);
+    }
+
+    #[test]
+    fn apply_next_conflicts_with_prev() {
+        let args = vec!["geman", "apply", "-p", "--next", "--prev"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test_case("-p"; "Reset for Proton GE")]
+    #[test_case("-w"; "Reset for Wine GE")]
+    #[test_case("-l"; "Reset for Wine GE LoL")]
+    fn apply_reset(kind: &str) {
+        let args = vec!["geman", "apply", kind, "--reset"];
+        let mut expected = ApplyArgs::new(TagArg::new(None, kind_str_to_enum(kind)));
+        expected.reset = true;
+        apply_test_template(args, expected);
+    }
+
+    #[test]
+    fn apply_reset_without_kind_should_use_default_kind() {
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "apply", "--reset"]).unwrap();
+        let mut config = GeManConfig::default();
+        config.default_kind = Some(DefaultKind::Wine);
+        let args = ApplyArgs::create_from(matches, &config);
+
+        assert!(args.reset);
+        assert_tag_arg(args.tag_arg, TagArg::new(None, TagKind::wine()));
+    }
+
+    #[test]
+    fn apply_symlink() {
+        let args = vec!["geman", "apply", "-p", "6.20-GE-1", "--symlink", "GE-Proton-latest"];
+        let mut expected = ApplyArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        expected.symlink = Some(String::from("GE-Proton-latest"));
+        apply_test_template(args, expected);
+    }
+
+    #[test_case(vec!["geman", "apply", "-w", "--symlink", "latest"], ErrorKind::ArgumentConflict; "Wine GE version")]
+    #[test_case(vec!["geman", "apply", "-p", "--symlink", "a/b"], ErrorKind::ValueValidation; "Path as name")]
+    #[test_case(vec!["geman", "apply", "-p", "--symlink", ".."], ErrorKind::ValueValidation; "Parent directory")]
+    fn apply_symlink_with_invalid_args(args: Vec<&str>, kind: ErrorKind) {
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), kind);
+    }
+
+    #[test]
+    fn apply_bottles() {
+        let args = vec!["geman", "apply", "-w", "GE-Proton7-20", "--bottles"];
+        let mut expected = ApplyArgs::new(TagArg::new(Some(Tag::from("GE-Proton7-20")), TagKind::wine()));
+        expected.bottles = true;
+        apply_test_template(args, expected);
+    }
+
+    #[test_case(vec!["geman", "apply", "-p", "--bottles"]; "Proton GE version")]
+    #[test_case(vec!["geman", "apply", "-w", "--bottles", "--reset"]; "Reset")]
+    #[test_case(vec!["geman", "apply", "-w", "--bottles", "--next"]; "Next")]
+    fn apply_bottles_with_conflicting_args(args: Vec<&str>) {
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn apply_no_backup() {
+        let args = vec!["geman", "apply", "-p", "6.20-GE-1", "--no-backup"];
+        let mut expected = ApplyArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        expected.no_backup = true;
+        apply_test_template(args, expected);
+    }
+
+    #[test_case("-p"; "Restore backup for Proton GE")]
+    #[test_case("-w"; "Restore backup for Wine GE")]
+    #[test_case("-l"; "Restore backup for Wine GE LoL")]
+    fn apply_reset_with_backup(kind: &str) {
+        let args = vec!["geman", "apply", kind, "--reset", "--backup", "2"];
+        let mut expected = ApplyArgs::new(TagArg::new(None, kind_str_to_enum(kind)));
+        expected.reset = true;
+        expected.backup = 2;
+        apply_test_template(args, expected);
+    }
+
+    #[test_case(vec!["geman", "apply", "-p", "--backup", "2"], ErrorKind::MissingRequiredArgument; "Without reset")]
+    #[test_case(vec!["geman", "apply", "-p", "--reset", "--backup", "0"], ErrorKind::ValueValidation; "Zero")]
+    #[test_case(vec!["geman", "apply", "-p", "--reset", "--no-backup"], ErrorKind::ArgumentConflict; "No backup")]
+    fn apply_backup_with_invalid_args(args: Vec<&str>, kind: ErrorKind) {
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind(), kind);
+    }
+
+    #[test]
+    fn apply_reset_conflicts_with_next() {
+        let args = vec!["geman", "apply", "-p", "--reset", "--next"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
+    #[test_case(vec!["geman", "--retry-all", "3", "check"]; "Retry all before command")]
+    #[test_case(vec!["geman", "check", "--retry-all", "3"]; "Retry all after command")]
+    fn global_retry_all(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert_eq!(args.retry_all, Some(3));
+    }
+
+    #[test]
+    fn global_retry_all_is_optional() {
+        let matches = setup_clap().try_get_matches_from(vec!["geman", "check"]).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert_eq!(args.retry_all, None);
+        assert!(!args.no_progress);
+        assert_eq!(args.progress_format, None);
+    }
+
+    #[test_case(vec!["geman", "--no-progress", "add", "-p"]; "No progress before command")]
+    #[test_case(vec!["geman", "add", "-p", "--no-progress"]; "No progress after command")]
+    fn global_no_progress(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert!(args.no_progress);
+    }
+
+    #[test_case(vec!["geman", "--progress-format", "json", "add", "-p"]; "Progress format before command")]
+    #[test_case(vec!["geman", "add", "-p", "--progress-format", "json"]; "Progress format after command")]
+    fn global_progress_format(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert_eq!(args.progress_format, Some(ProgressFormat::Json));
+    }
+
+    #[test_case(vec!["geman", "--flatpak-steam", "list"]; "Flatpak Steam before command")]
+    #[test_case(vec!["geman", "list", "--flatpak-steam"]; "Flatpak Steam after command")]
+    fn global_flatpak_steam(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert!(args.flatpak_steam);
+    }
+
+    #[test_case(vec!["geman", "--flatpak-lutris", "list"]; "Flatpak Lutris before command")]
+    #[test_case(vec!["geman", "list", "--flatpak-lutris"]; "Flatpak Lutris after command")]
+    fn global_flatpak_lutris(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert!(args.flatpak_lutris);
+    }
+
+    #[test_case(vec!["geman", "--assume-yes", "apply", "-p"]; "Assume yes before command")]
+    #[test_case(vec!["geman", "apply", "-p", "--assume-yes"]; "Assume yes after command")]
+    fn global_assume_yes(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert!(args.assume_yes);
+    }
+
+    #[test_case(vec!["geman", "-v", "list"], 1; "Verbose before command")]
+    #[test_case(vec!["geman", "list", "--verbose"], 1; "Verbose after command")]
+    #[test_case(vec!["geman", "-vv", "add", "-p"], 2; "Debug verbosity before command")]
+    #[test_case(vec!["geman", "add", "-p", "-v", "-v"], 2; "Debug verbosity after command")]
+    #[test_case(vec!["geman", "check"], 0; "No verbosity")]
+    fn global_verbose(args: Vec<&str>, expected: u64) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert_eq!(args.verbose, expected);
+    }
+
+    #[test_case(vec!["geman", "--log-file", "/tmp/ge-man.log", "check"]; "Log file before command")]
+    #[test_case(vec!["geman", "check", "--log-file", "/tmp/ge-man.log"]; "Log file after command")]
+    fn global_log_file(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert_eq!(args.log_file, Some(PathBuf::from("/tmp/ge-man.log")));
+    }
+
+    #[test_case(vec!["geman", "--limit-rate", "500K", "add", "-p"]; "Limit rate before command")]
+    #[test_case(vec!["geman", "add", "-p", "--limit-rate", "500k"]; "Limit rate after command")]
+    fn global_limit_rate(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert_eq!(args.limit_rate, Some(500 * 1024));
+    }
+
+    #[test_case(vec!["geman", "--offline", "check"]; "Offline before command")]
+    #[test_case(vec!["geman", "check", "--offline"]; "Offline after command")]
+    fn global_offline(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert!(args.offline);
+    }
+
+    #[test_case(vec!["geman", "--quiet", "check"]; "Quiet before command")]
+    #[test_case(vec!["geman", "check", "-q"]; "Quiet after command")]
+    fn global_quiet(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert!(args.quiet);
+        assert!(!args.show_progress());
+    }
+
+    #[test_case(vec!["geman", "add", "-p"] => true; "Default")]
+    #[test_case(vec!["geman", "--no-progress", "add", "-p"] => false; "No progress")]
+    #[test_case(vec!["geman", "-q", "add", "-p"] => false; "Quiet")]
+    fn global_show_progress(args: Vec<&str>) -> bool {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        GlobalArgs::from(&matches).show_progress()
+    }
+
+    #[test_case(vec!["geman", "--config", "/tmp/proton.json", "add", "-p"]; "Config before command")]
+    #[test_case(vec!["geman", "add", "-p", "--config", "/tmp/proton.json"]; "Config after command")]
+    fn global_config(args: Vec<&str>) {
+        let matches = setup_clap().try_get_matches_from(args).unwrap();
+        let args = GlobalArgs::from(&matches);
+        assert_eq!(args.config, Some(PathBuf::from("/tmp/proton.json")));
+    }
+
+    #[test]
+    fn global_limit_rate_rejects_invalid_rate() {
+        let args = vec!["geman", "--limit-rate", "fast", "add", "-p"];
+        let err = setup_clap().try_get_matches_from(args).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn global_progress_format_rejects_unknown_format() {
+        let args = vec!["geman", "--progress-format", "xml", "add", "-p"];
+        let result = setup_clap().try_get_matches_from(args);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    }
 }
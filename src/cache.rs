@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use ge_man_lib::download::response::{DownloadedArchive, DownloadedAssets, DownloadedChecksum};
+use ge_man_lib::tag::TagKind;
+use serde::{Deserialize, Serialize};
+
+use crate::version::Versioned;
+
+const ARCHIVE_DIR: &str = "archive";
+const CHECKSUM_DIR: &str = "checksum";
+const LATEST_TAGS_FILE: &str = "latest_tags.json";
+
+/// Cache of downloaded release archives.
+///
+/// Every cached release is stored in `<dir>/<kind>/<tag>/` together with its checksum file. The cache is bounded by
+/// `max_size` bytes: when a new archive is stored, the least recently used releases are removed until the cache fits.
+pub struct ArchiveCache {
+    pub dir: PathBuf,
+    pub max_size: u64,
+}
+
+impl ArchiveCache {
+    pub fn new(dir: PathBuf, max_size: u64) -> Self {
+        ArchiveCache { dir, max_size }
+    }
+
+    fn entry_dir(&self, version: &dyn Versioned) -> PathBuf {
+        self.dir.join(kind_name(version.kind())).join(version.tag().to_string())
+    }
+
+    fn read_single_file(dir: &Path) -> Option<(String, Vec<u8>)> {
+        let entry = fs::read_dir(dir).ok()?.filter_map(Result::ok).next()?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let content = fs::read(entry.path()).ok()?;
+
+        Some((file_name, content))
+    }
+
+    /// Get the cached archive and checksum for the given version. Only entries with a checksum are ever cached, so an
+    /// entry without one is treated as missing. Accessing an entry marks it as recently used.
+    pub fn get(&self, version: &dyn Versioned) -> Option<DownloadedAssets> {
+        let entry_dir = self.entry_dir(version);
+        let (archive_name, archive_content) = ArchiveCache::read_single_file(&entry_dir.join(ARCHIVE_DIR))?;
+        let (checksum_name, checksum_content) = ArchiveCache::read_single_file(&entry_dir.join(CHECKSUM_DIR))?;
+
+        if let Ok(file) = File::open(&entry_dir) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+
+        Some(DownloadedAssets {
+            tag: version.tag().to_string(),
+            compressed_archive: DownloadedArchive {
+                compressed_content: archive_content,
+                file_name: archive_name,
+            },
+            checksum: Some(DownloadedChecksum {
+                checksum: String::from_utf8_lossy(&checksum_content).to_string(),
+                file_name: checksum_name,
+            }),
+        })
+    }
+
+    /// Store an archive and its checksum for the given version and evict the least recently used entries afterwards.
+    pub fn put(
+        &self,
+        version: &dyn Versioned,
+        archive: &DownloadedArchive,
+        checksum: &DownloadedChecksum,
+    ) -> anyhow::Result<()> {
+        self.remove(version)?;
+
+        let entry_dir = self.entry_dir(version);
+        let archive_dir = entry_dir.join(ARCHIVE_DIR);
+        let checksum_dir = entry_dir.join(CHECKSUM_DIR);
+        fs::create_dir_all(&archive_dir).context("Could not create archive cache directory")?;
+        fs::create_dir_all(&checksum_dir).context("Could not create archive cache directory")?;
+
+        fs::write(archive_dir.join(&archive.file_name), &archive.compressed_content)
+            .context("Could not write archive to cache")?;
+        fs::write(checksum_dir.join(&checksum.file_name), &checksum.checksum)
+            .context("Could not write checksum to cache")?;
+
+        self.evict()
+    }
+
+    /// Remove the cached entry of the given version if it exists.
+    pub fn remove(&self, version: &dyn Versioned) -> anyhow::Result<()> {
+        let entry_dir = self.entry_dir(version);
+        if entry_dir.exists() {
+            fs::remove_dir_all(&entry_dir).context(format!("Could not remove cached archive for {}", version.tag()))?;
+        }
+
+        Ok(())
+    }
+
+    fn entries(&self) -> Vec<(PathBuf, SystemTime, u64)> {
+        let kind_dirs = match fs::read_dir(&self.dir) {
+            Ok(dirs) => dirs,
+            Err(_) => return Vec::new(),
+        };
+
+        kind_dirs
+            .filter_map(Result::ok)
+            .filter_map(|kind_dir| fs::read_dir(kind_dir.path()).ok())
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|entry| {
+                let path = entry.path();
+                let modified = fs::metadata(&path)
+                    .and_then(|meta| meta.modified())
+                    .unwrap_or(UNIX_EPOCH);
+                let size = directory_size(&path);
+                (path, modified, size)
+            })
+            .collect()
+    }
+
+    fn evict(&self) -> anyhow::Result<()> {
+        let mut entries = self.entries();
+        entries.sort_by_key(|(_, modified, _)| *modified);
+
+        let mut total_size: u64 = entries.iter().map(|(_, _, size)| size).sum();
+        for (path, _, size) in entries {
+            if total_size <= self.max_size {
+                break;
+            }
+
+            fs::remove_dir_all(&path).context(format!("Could not evict {} from archive cache", path.display()))?;
+            total_size -= size;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LatestTag {
+    tag: String,
+    /// Seconds since the Unix epoch.
+    fetched_at: u64,
+}
+
+/// Cache of the latest release tag of every kind.
+///
+/// The tags are stored in `<dir>/latest_tags.json` together with the time they were fetched. A tag which was fetched
+/// longer than `ttl` ago is treated as missing. An unreadable cache file is treated as empty and replaced on the next
+/// write.
+pub struct LatestTagCache {
+    pub file: PathBuf,
+    pub ttl: Duration,
+}
+
+impl LatestTagCache {
+    pub fn new(dir: PathBuf, ttl: Duration) -> Self {
+        LatestTagCache {
+            file: dir.join(LATEST_TAGS_FILE),
+            ttl,
+        }
+    }
+
+    fn read(&self) -> HashMap<String, LatestTag> {
+        fs::read_to_string(&self.file)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Get the cached latest tag of the given kind if it has not expired yet.
+    pub fn get(&self, kind: &TagKind) -> Option<String> {
+        let entry = self.read().remove(kind_name(kind))?;
+        let fetched_at = UNIX_EPOCH + Duration::from_secs(entry.fetched_at);
+
+        match SystemTime::now().duration_since(fetched_at) {
+            Ok(age) if age < self.ttl => Some(entry.tag),
+            _ => None,
+        }
+    }
+
+    /// Store the latest tag of the given kind with the current time.
+    pub fn put(&self, kind: &TagKind, tag: &str) -> anyhow::Result<()> {
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("System time is before the Unix epoch")?
+            .as_secs();
+
+        let mut tags = self.read();
+        tags.insert(
+            kind_name(kind).to_string(),
+            LatestTag {
+                tag: tag.to_string(),
+                fetched_at,
+            },
+        );
+
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir).context("Could not create cache directory")?;
+        }
+        let json = serde_json::to_string_pretty(&tags).context("Could not serialize latest tags")?;
+        fs::write(&self.file, json).context("Could not write latest tag cache")
+    }
+}
+
+fn kind_name(kind: &TagKind) -> &'static str {
+    match kind {
+        TagKind::Proton => "proton",
+        TagKind::Wine { .. } if kind.eq(&TagKind::lol()) => "lol",
+        TagKind::Wine { .. } => "wine",
+    }
+}
+
+fn directory_size(path: &Path) -> u64 {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(_) => return 0,
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| match entry.metadata() {
+            Ok(meta) if meta.is_dir() => directory_size(&entry.path()),
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::TempDir;
+    use ge_man_lib::tag::Tag;
+
+    use crate::version::Version;
+
+    use super::*;
+
+    fn archive(content: &[u8]) -> DownloadedArchive {
+        DownloadedArchive {
+            compressed_content: content.to_vec(),
+            file_name: String::from("archive.tar.gz"),
+        }
+    }
+
+    fn checksum() -> DownloadedChecksum {
+        DownloadedChecksum {
+            checksum: String::from("abc  archive.tar.gz"),
+            file_name: String::from("archive.sha512sum"),
+        }
+    }
+
+    fn set_last_used(cache: &ArchiveCache, version: &Version, time: SystemTime) {
+        File::open(cache.entry_dir(version))
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    #[test]
+    fn get_returns_previously_stored_archive() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = ArchiveCache::new(tmp_dir.join("cache"), 1024);
+        let version = Version::new(Tag::from("6.20-GE-1"), TagKind::Proton);
+
+        cache.put(&version, &archive(b"content"), &checksum()).unwrap();
+        let assets = cache.get(&version).unwrap();
+
+        assert_eq!(assets.compressed_archive.compressed_content, b"content".to_vec());
+        assert_eq!(assets.compressed_archive.file_name, "archive.tar.gz");
+        let cached_checksum = assets.checksum.unwrap();
+        assert_eq!(cached_checksum.checksum, "abc  archive.tar.gz");
+        assert_eq!(cached_checksum.file_name, "archive.sha512sum");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn get_for_uncached_version_returns_none() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = ArchiveCache::new(tmp_dir.join("cache"), 1024);
+        let version = Version::new(Tag::from("6.20-GE-1"), TagKind::Proton);
+
+        cache.put(&version, &archive(b"content"), &checksum()).unwrap();
+
+        assert!(cache
+            .get(&Version::new(Tag::from("6.20-GE-1"), TagKind::wine()))
+            .is_none());
+        assert!(cache
+            .get(&Version::new(Tag::from("6.21-GE-1"), TagKind::Proton))
+            .is_none());
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn remove_deletes_cached_entry() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = ArchiveCache::new(tmp_dir.join("cache"), 1024);
+        let version = Version::new(Tag::from("6.20-GE-1"), TagKind::Proton);
+
+        cache.put(&version, &archive(b"content"), &checksum()).unwrap();
+        cache.remove(&version).unwrap();
+
+        assert!(cache.get(&version).is_none());
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn put_evicts_least_recently_used_entries() {
+        let tmp_dir = TempDir::new().unwrap();
+        let entry_size = (100 + checksum().checksum.len()) as u64;
+        let cache = ArchiveCache::new(tmp_dir.join("cache"), entry_size * 2);
+        let oldest = Version::new(Tag::from("6.19-GE-1"), TagKind::Proton);
+        let older = Version::new(Tag::from("6.20-GE-1"), TagKind::Proton);
+        let newest = Version::new(Tag::from("6.21-GE-1"), TagKind::Proton);
+
+        cache.put(&oldest, &archive(&[0; 100]), &checksum()).unwrap();
+        cache.put(&older, &archive(&[0; 100]), &checksum()).unwrap();
+        set_last_used(&cache, &oldest, SystemTime::now() - Duration::from_secs(60));
+        set_last_used(&cache, &older, SystemTime::now() - Duration::from_secs(120));
+
+        cache.put(&newest, &archive(&[0; 100]), &checksum()).unwrap();
+
+        assert!(cache.get(&oldest).is_some());
+        assert!(cache.get(&older).is_none());
+        assert!(cache.get(&newest).is_some());
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn latest_tag_cache_returns_stored_tag_of_kind() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+
+        cache.put(&TagKind::Proton, "GE-Proton7-20").unwrap();
+        cache.put(&TagKind::wine(), "lutris-ge-7.0-1-x86_64").unwrap();
+
+        assert_eq!(cache.get(&TagKind::Proton), Some(String::from("GE-Proton7-20")));
+        assert_eq!(
+            cache.get(&TagKind::wine()),
+            Some(String::from("lutris-ge-7.0-1-x86_64"))
+        );
+        assert_eq!(cache.get(&TagKind::lol()), None);
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn latest_tag_cache_put_replaces_previous_tag() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+
+        cache.put(&TagKind::Proton, "GE-Proton7-19").unwrap();
+        cache.put(&TagKind::Proton, "GE-Proton7-20").unwrap();
+
+        assert_eq!(cache.get(&TagKind::Proton), Some(String::from("GE-Proton7-20")));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn latest_tag_cache_ignores_expired_tags() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+        let fetched_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() - 3601;
+        fs::create_dir_all(tmp_dir.join("cache")).unwrap();
+        fs::write(
+            &cache.file,
+            format!(
+                r#"{{"proton": {{"tag": "GE-Proton7-20", "fetched_at": {}}}}}"#,
+                fetched_at
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(cache.get(&TagKind::Proton), None);
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn latest_tag_cache_with_zero_ttl_never_returns_tags() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::ZERO);
+
+        cache.put(&TagKind::Proton, "GE-Proton7-20").unwrap();
+
+        assert_eq!(cache.get(&TagKind::Proton), None);
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn latest_tag_cache_replaces_corrupt_cache_file() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+        fs::create_dir_all(tmp_dir.join("cache")).unwrap();
+        fs::write(&cache.file, "not json").unwrap();
+
+        assert_eq!(cache.get(&TagKind::Proton), None);
+        cache.put(&TagKind::Proton, "GE-Proton7-20").unwrap();
+        assert_eq!(cache.get(&TagKind::Proton), Some(String::from("GE-Proton7-20")));
+
+        tmp_dir.close().unwrap();
+    }
+}
@@ -0,0 +1,116 @@
+use std::str::FromStr;
+
+use anyhow::bail;
+use sha2::{Digest, Sha256, Sha512};
+
+/// Hash algorithm used to compare a downloaded archive against its checksum file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl ChecksumAlgorithm {
+    pub fn values() -> [&'static str; 2] {
+        ["sha256", "sha512"]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Sha256 => "sha256",
+            ChecksumAlgorithm::Sha512 => "sha512",
+        }
+    }
+
+    fn hex_len(&self) -> usize {
+        match self {
+            ChecksumAlgorithm::Sha256 => 64,
+            ChecksumAlgorithm::Sha512 => 128,
+        }
+    }
+
+    fn hex_digest(&self, content: &[u8]) -> String {
+        let digest = match self {
+            ChecksumAlgorithm::Sha256 => Sha256::digest(content).to_vec(),
+            ChecksumAlgorithm::Sha512 => Sha512::digest(content).to_vec(),
+        };
+        digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha256" => Ok(ChecksumAlgorithm::Sha256),
+            "sha512" => Ok(ChecksumAlgorithm::Sha512),
+            _ => Err(format!("Unsupported checksum algorithm: {}", s)),
+        }
+    }
+}
+
+/// Compare the content of an archive against the checksum found in a checksum file with the given algorithm.
+///
+/// The checksum file is expected to be in the format of the `sha*sum` tools. If the checksum in the file can not have
+/// been produced by the given algorithm an error is returned instead of reporting a mismatch.
+pub fn checksums_match(algorithm: ChecksumAlgorithm, content: &[u8], checksum_file: &str) -> anyhow::Result<bool> {
+    let expected = match checksum_file.split_whitespace().next() {
+        Some(checksum) => checksum,
+        None => bail!("The provided checksum file is empty"),
+    };
+
+    if expected.len() != algorithm.hex_len() || !expected.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!(
+            "The provided checksum file does not contain a {} checksum",
+            algorithm.name()
+        );
+    }
+
+    Ok(algorithm.hex_digest(content).eq_ignore_ascii_case(expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(ChecksumAlgorithm::Sha256, "test_resources/assets/Proton-6.20-GE-1.sha256sum"; "Matching sha256 checksum")]
+    #[test_case(ChecksumAlgorithm::Sha512, "test_resources/assets/Proton-6.20-GE-1.sha512sum"; "Matching sha512 checksum")]
+    fn checksums_match_for_algorithm(algorithm: ChecksumAlgorithm, checksum_path: &str) {
+        let archive = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
+        let checksum = fs::read_to_string(checksum_path).unwrap();
+
+        assert!(checksums_match(algorithm, &archive, &checksum).unwrap());
+    }
+
+    #[test]
+    fn checksums_do_not_match() {
+        let archive = fs::read("test_resources/assets/Proton-6.19-GE-1.tar.gz").unwrap();
+        let checksum = fs::read_to_string("test_resources/assets/Proton-6.20-GE-1.sha512sum").unwrap();
+
+        assert!(!checksums_match(ChecksumAlgorithm::Sha512, &archive, &checksum).unwrap());
+    }
+
+    #[test]
+    fn algorithm_does_not_fit_checksum_file() {
+        let archive = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
+        let checksum = fs::read_to_string("test_resources/assets/Proton-6.20-GE-1.sha512sum").unwrap();
+
+        let result = checksums_match(ChecksumAlgorithm::Sha256, &archive, &checksum);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The provided checksum file does not contain a sha256 checksum"
+        );
+    }
+
+    #[test]
+    fn empty_checksum_file() {
+        let result = checksums_match(ChecksumAlgorithm::Sha512, b"", "");
+        assert!(result.is_err());
+    }
+}
@@ -1,4 +1,12 @@
+use std::path::Path;
+
 use clap::{Arg, ArgGroup, command, Command, crate_version};
+use ge_man_lib::tag::TagKind;
+
+use crate::args::ListSort;
+use crate::checksum::ChecksumAlgorithm;
+use crate::progress::{parse_rate, ProgressFormat};
+use crate::version::is_valid_label;
 
 pub const APP_NAME: &str = "GE Helper";
 
@@ -11,25 +19,99 @@ pub mod commands {
     pub const APPLY: &str = "apply";
     pub const PROTON_USER_SETTINGS: &str = "user-settings";
     pub const USER_SETTINGS_COPY: &str = "copy";
+    pub const USER_SETTINGS_DIFF: &str = "diff";
     pub const FORGET: &str = "forget";
+    pub const EXPORT: &str = "export";
+    pub const IMPORT: &str = "import";
+    pub const REBUILD: &str = "rebuild";
+    pub const DIRS: &str = "dirs";
+    pub const STATUS: &str = "status";
+    pub const INFO: &str = "info";
+    pub const PIN: &str = "pin";
+    pub const UNPIN: &str = "unpin";
+    pub const VERIFY: &str = "verify";
+    pub const COMPLETIONS: &str = "completions";
 }
 
 pub mod arg_names {
     pub const LOL_ARG: &str = "lol";
+    pub const TAG_ARG: &str = "tag";
     pub const WINE_ARG: &str = "wine";
     pub const PROTON_ARG: &str = "proton";
     pub const NEWEST_ARG: &str = "newest";
     pub const SKIP_CHECKSUM_ARG: &str = "skip-checksum";
     pub const APPLY_ARG: &str = "apply";
+    pub const CHECKSUM_ARG: &str = "checksum";
+    pub const NO_APPLY_ARG: &str = "no-apply";
+    pub const CHECKSUM_ALGORITHM_ARG: &str = "checksum-algorithm";
+    pub const NOTIFY_ARG: &str = "notify";
+    pub const SKIP_EXISTING_ARG: &str = "skip-existing";
+    pub const KEEP_ARCHIVE_CACHE_ARG: &str = "keep-archive-cache";
+    pub const KEEP_ARCHIVE_ARG: &str = "keep-archive";
+    pub const ARCHIVE_ARG: &str = "archive";
+    pub const CHECKSUM_FILE_ARG: &str = "checksum-file";
+    pub const LABEL_ARG: &str = "label";
+    pub const STEAM_ROOT_ARG: &str = "steam-root";
+    pub const RETRY_ALL_ARG: &str = "retry-all";
+    pub const NO_PROGRESS_ARG: &str = "no-progress";
+    pub const PROGRESS_FORMAT_ARG: &str = "progress-format";
+    pub const FLATPAK_STEAM_ARG: &str = "flatpak-steam";
+    pub const FLATPAK_LUTRIS_ARG: &str = "flatpak-lutris";
+    pub const ASSUME_YES_ARG: &str = "assume-yes";
+    pub const LOG_FILE_ARG: &str = "log-file";
+    pub const LIMIT_RATE_ARG: &str = "limit-rate";
+    pub const OFFLINE_ARG: &str = "offline";
+    pub const QUIET_ARG: &str = "quiet";
+    pub const CONFIG_ARG: &str = "config";
+    pub const DRY_RUN_ARG: &str = "dry-run";
+    pub const REFRESH_ARG: &str = "refresh";
+    pub const SCRIPT_ARG: &str = "script";
+    pub const MANIFEST_ARG: &str = "manifest";
+    pub const DOWNLOAD_ARG: &str = "download";
+    pub const MERGED_ARG: &str = "merged";
+    pub const JSON_ARG: &str = "json";
+    pub const SIZE_ARG: &str = "size";
+    pub const FILTER_ARG: &str = "filter";
+    pub const ALL_ARG: &str = "all";
+    pub const SORT_ARG: &str = "sort";
+    pub const VERBOSE_ARG: &str = "verbose";
+    pub const SHOW_ADDED_ARG: &str = "show-added";
+    pub const INCLUDE_LOL_ARG: &str = "include-lol";
+    pub const FORCE_ARG: &str = "force";
+    pub const VERIFY_ONLY_ARG: &str = "verify-only";
+    pub const DIR_NAME_ARG: &str = "dir-name";
+    pub const YES_ARG: &str = "yes";
+    pub const SYMLINK_ARG: &str = "symlink";
+    pub const BOTTLES_ARG: &str = "bottles";
+    pub const RESET_ARG: &str = "reset";
+    pub const SHELL_ARG: &str = "shell";
+    pub const NEXT_ARG: &str = "next";
+    pub const PREV_ARG: &str = "prev";
+    pub const LIMIT_ARG: &str = "limit";
+    pub const PAGE_ARG: &str = "page";
     pub const SOURCE_ARG: &str = "source";
+    pub const SOURCE_DIR_ARG: &str = "source-dir";
     pub const DESTINATION_ARG: &str = "destination";
+    pub const FROM_FILE_ARG: &str = "from-file";
+    pub const NO_BACKUP_ARG: &str = "no-backup";
+    pub const BACKUP_ARG: &str = "backup";
 }
 
 pub mod arg_group_names {
     pub const TAG: &str = "tag-args";
+    pub const EXPORT_TARGET: &str = "export-target";
 }
 
 pub mod about_text {
+    pub const EXIT_CODES: &str = "EXIT CODES:
+    0    Success
+    1    General failure
+    2    Invalid arguments
+    3    Network failure, e.g. GitHub can not be reached or offline mode is enabled
+    4    Filesystem failure, e.g. a file can not be read or written
+    5    A given version, file or manifest does not exist
+    6    A version or directory already exists
+    7    A version is in use by Steam or Lutris";
     pub const ADD: &str =
         "Add a GE Proton version for Steam or a Wine GE version for Lutris. If no <TAG> value is provided, the \
          latest version is assumed.";
@@ -44,50 +126,249 @@ pub mod about_text {
     pub const USER_SETTINGS: &str =
         r#"Commands for managing user setting files for Proton versions. "user-settings" is aliased to "us""#;
     pub const USER_SETTINGS_COPY: &str = "Copy a user_settings.py from one Proton version to another.";
+    pub const USER_SETTINGS_DIFF: &str = "Show the differences between the user_settings.py of two Proton versions.";
     pub const FORGET: &str = "Forget a GE Proton or Wine GE version. This operation does not remove any files.";
+    pub const EXPORT: &str = "Export the managed versions to recreate them on another machine.";
+    pub const IMPORT: &str = "Import the managed versions of a manifest written by the export command.";
+    pub const REBUILD: &str = "Register every version directory of Steam and Lutris which follows the GE-Man naming \
+    scheme but is not managed, e.g. after the managed versions file was lost.";
+    pub const DIRS: &str = "Print the directories and files used by GE-Man in the current environment.";
+    pub const STATUS: &str = "Show which version is currently applied to Steam and Lutris.";
+    pub const INFO: &str = "Show the details of a managed GE Proton or Wine GE version.";
+    pub const PIN: &str = "Pin a GE Proton or Wine GE version to protect it from being removed.";
+    pub const UNPIN: &str = "Unpin a GE Proton or Wine GE version so it can be removed again.";
+    pub const COMPLETIONS: &str = "Print a shell completion script for GE-Man to stdout.";
+    pub const VERIFY: &str = "Verify that the files of managed versions are complete. Without a version every managed \
+    version is verified.";
 }
 
 mod help_text {
+    // Global
+    pub const RETRY_ALL: &str =
+        "Retry every failed network request of a command up to <NUMBER> times. Overrides the GE-Man config.";
+    pub const NO_PROGRESS: &str =
+        "Do not draw the download and extraction progress bars, e.g. in scripts. Overrides the GE-Man config.";
+    pub const PROGRESS_FORMAT: &str = "Report the download and extraction progress as progress bars or as one JSON \
+    object per update on stderr. Overrides the GE-Man config.";
+    pub const FLATPAK_STEAM: &str = "Use the Flatpak installation of Steam, even if a native installation exists.";
+    pub const FLATPAK_LUTRIS: &str = "Use the Flatpak installation of Lutris, even if a native installation exists.";
+    pub const ASSUME_YES: &str = "Answer every confirmation prompt with yes.";
+    pub const VERBOSE: &str = "Log what GE-Man is doing to stderr. Provide it twice (-vv) to also log debug messages.";
+    pub const LOG_FILE: &str = "Append the log messages to the file at <PATH> in addition to stderr.";
+    pub const LIMIT_RATE: &str = "Limit the download rate to <RATE> bytes per second. The rate may end with K, M or G, \
+    e.g. 500K or 2M. Overrides the GE-Man config.";
+    pub const OFFLINE: &str = "Fail every operation which needs the network instead of contacting GitHub. Can also be \
+    enabled with GEMAN_OFFLINE=1.";
+    pub const QUIET: &str = "Do not print anything to stdout, errors are still printed to stderr. Confirmation prompts \
+    are handled like in a non-interactive shell, use --assume-yes to confirm them.";
+    pub const CONFIG: &str = "Read the GE-Man config from <FILE> instead of the config.json in the GE-Man config \
+    directory.";
     // Add
     pub const ADD_PROTON_TAG: &str = "Download a GE Proton version";
     pub const ADD_WINE_TAG: &str = "Download a Wine GE version";
     pub const ADD_WINE_LOL_TAG: &str = "Download a Wine GE LoL version";
     pub const ADD_SKIP_CHECKSUM: &str = "Skip downloading the checksum and comparing it to the downloaded release.";
     pub const ADD_APPLY: &str = "Set the Steam or Lutris compatibility tool version after successfully adding it.";
+    pub const ADD_CHECKSUM: &str =
+        "Download the checksum and compare it to the downloaded release, even if the GE-Man config skips it.";
+    pub const ADD_CHECKSUM_ALGORITHM: &str =
+        "Force the algorithm used to compare the downloaded release against its checksum file.";
+    pub const ADD_NO_APPLY: &str = "Do not apply the version after adding it, even if the GE-Man config applies it.";
+    pub const ADD_SKIP_EXISTING: &str = "Silently skip the version if it is already managed.";
+    pub const ADD_FORCE: &str = "Download and install the version again if it is already managed, replacing its \
+    directory. A version which is in use by Steam or Lutris is not replaced.";
+    pub const ADD_VERIFY_ONLY: &str = "Download the version and its checksum and only compare them. Nothing is \
+    installed and the managed versions are not changed.";
+    pub const ADD_ARCHIVE: &str = "Add the version from a local archive instead of downloading it. Requires a tag.";
+    pub const ADD_CHECKSUM_FILE: &str = "Compare the local archive against the given sha*sum file. Without it the \
+    checksum comparison is skipped.";
+    pub const ADD_LABEL: &str = "Install the version under a label, so the same tag can be installed more than once. \
+    The label is appended to the directory name of the version.";
+    pub const ADD_KEEP_ARCHIVE_CACHE: &str = "Reuse a previously downloaded archive of the version if its checksum \
+    matches and keep the downloaded archive in the GE-Man cache for later use.";
+    pub const ADD_KEEP_ARCHIVE: &str = "Save the downloaded archive, e.g. for offline installations on other machines. \
+    The archive is saved in the archives directory of the GE-Man cache or in the directory given with \
+    --keep-archive=<DIR>.";
+    pub const ADD_REFRESH: &str =
+        "Fetch the latest tag from GitHub for a tagless add, even if a cached latest tag has not expired yet.";
+    pub const ADD_STEAM_ROOT: &str = "Install the GE Proton version into the compatibilitytools.d directory of the \
+    Steam root <DIR> instead of the default Steam root. GE-Man remembers <DIR> for the version. The default Steam \
+    installation does not read <DIR>, so the version is not applied after add.";
     // List
     pub const LIST_PROTON_TAG: &str = "List GE Proton versions";
-    pub const LIST_WINE_TAG: &str = "List Wine GE versions";
+    pub const LIST_WINE_TAG: &str = "List Wine GE versions. Wine GE LoL versions are only included with --include-lol";
     pub const LIST_WINE_LOL_TAG: &str = "List Wine GE LoL versions";
     pub const LIST_NEWEST: &str = "List the latest versions for each tag kind.";
+    pub const LIST_MERGED: &str =
+        "List every directory of the Steam and Lutris version directories with its managed and in use status.";
+    pub const LIST_JSON: &str = "Print the managed versions as a JSON array instead of a list.";
+    pub const LIST_SIZE: &str = "Show the disk usage of every listed version.";
+    pub const LIST_FILTER: &str = "Only list versions whose tag contains the pattern. A pattern with '*' or '?' \
+    wildcards has to match the whole tag, e.g. '6.21-*'.";
+    pub const LIST_ALL: &str = "List every tag kind, including kinds without any managed versions.";
+    pub const LIST_INCLUDE_LOL: &str = "Also list the Wine GE LoL versions when listing Wine GE versions.";
+    pub const LIST_SHOW_ADDED: &str = "Show when every listed version was added.";
+    pub const LIST_MANIFEST: &str = "List the versions of the manifest at <PATH> instead of the managed versions, e.g. \
+    an exported manifest or the backup of a corrupt managed versions file.";
+    pub const LIST_SORT: &str = "Order the versions of each tag kind by version (newest first), by tag name or in the \
+    order they were added.";
+    pub const LIST_LIMIT: &str = "Only list the newest <NUMBER> versions for each tag kind.";
+    pub const LIST_PAGE: &str = "Show the given page of versions when using --limit. The first page is 1.";
     // Remove
-    pub const REMOVE_PROTON_TAG: &str = "Remove a GE Proton version";
-    pub const REMOVE_WINE_TAG: &str = "Remove a Wine GE version";
-    pub const REMOVE_WINE_LOL_TAG: &str = "Remove a Wine GE LoL version";
+    pub const REMOVE_PROTON_TAG: &str = "Remove one or more GE Proton versions";
+    pub const REMOVE_WINE_TAG: &str = "Remove one or more Wine GE versions";
+    pub const REMOVE_WINE_LOL_TAG: &str = "Remove one or more Wine GE LoL versions";
+    pub const REMOVE_FORCE: &str = "Also remove pinned versions.";
+    pub const REMOVE_YES: &str = "Remove multiple versions without asking for confirmation.";
     // Check
     pub const CHECK_PROTON_TAG: &str = "Check for the latest GE Proton version";
     pub const CHECK_WINE_TAG: &str = "Check for the latest Wine GE version";
     pub const CHECK_WINE_LOL_TAG: &str = "Check for the latest Wine GE LoL version";
+    pub const CHECK_NOTIFY: &str =
+        "Show a desktop notification with notify-send when a latest release is not managed yet.";
+    pub const CHECK_REFRESH: &str = "Fetch the latest tags from GitHub, even if the cached tags have not expired yet.";
     // Migrate
     pub const MIGRATE_PROTON_TAG: &str = "Migrate a GE Proton version";
     pub const MIGRATE_WINE_TAG: &str = "Migrate a Wine GE version";
     pub const MIGRATE_WINE_LOL_TAG: &str = "Migrate a Wine GE LoL version";
-    pub const MIGRATE_SOURCE: &str = "Path to a directory containing a Wine GE or Proton GE version.";
+    pub const MIGRATE_SOURCE: &str =
+        "Path to a directory containing a Wine GE or Proton GE version. Requires a tag.";
+    pub const MIGRATE_SOURCE_DIR: &str = "Migrate every directory in the given directory. The tag and kind are \
+    inferred from the directory names. With a kind flag, directory names which can not be inferred are used as tag.";
+    pub const ADD_DRY_RUN: &str = "Show which version would be added, where it would be downloaded from and where it \
+    would be installed without downloading it or changing any files.";
+    pub const MIGRATE_DRY_RUN: &str = "Show what the migration would do without changing any files.";
+    pub const MIGRATE_DIR_NAME: &str = "Name of the migrated directory in the Steam or Lutris directory instead of the \
+    generated GEH_<KIND>_<TAG> name. A directory with this name must not exist yet.";
     // Apply
+    pub const ADD_TAG: &str = "Tag of the version to add. Without a kind flag, the \"default_kind\" of the config is \
+    used.";
     pub const APPLY_PROTON_TAG: &str = "Apply a GE Proton version for Steam";
     pub const APPLY_WINE_TAG: &str = "Apply a Wine GE version for Lutris";
     pub const APPLY_WINE_LOL_TAG: &str = "Apply a Wine GE LoL version for Lutris";
+    pub const APPLY_NEXT: &str = "Apply the managed version which is newer than the currently applied version.";
+    pub const APPLY_PREV: &str = "Apply the managed version which is older than the currently applied version.";
+    pub const APPLY_SYMLINK: &str = "Point a symlink with the given name in the Steam compatibility tools directory to \
+    the GE Proton version instead of modifying the Steam config.";
+    pub const APPLY_TAG: &str = "Tag of the version to apply. Without a tag, the newest managed version is applied. \
+    Without a kind flag, the \"default_kind\" of the config is used.";
+    pub const APPLY_RESET: &str = "Restore the Steam or Lutris config from the backup of the last apply.";
+    pub const APPLY_BOTTLES: &str = "Make the Wine GE version available as a runner in Bottles instead of modifying the \
+    Lutris config.";
+    pub const APPLY_NO_BACKUP: &str = "Modify the Steam or Lutris config without creating a backup of it first.";
+    pub const APPLY_BACKUP: &str = "Restore the <NUMBER>th newest backup instead of the newest one. How many backups \
+    are kept is set by the \"apply_backups\" option of the config.";
     // User settings copy
     pub const USER_SETTINGS_COPY_SOURCE: &str = "Source tag where to copy the user_settings.py from.";
     pub const USER_SETTINGS_COPY_DESTINATION: &str = "Destination tag where to copy the user_settings.py to.";
+    pub const USER_SETTINGS_COPY_FROM_FILE: &str =
+        "Copy the given file instead of the user_settings.py of a managed Proton version.";
+    // User settings diff
+    pub const USER_SETTINGS_DIFF_SOURCE: &str = "Tag of the user_settings.py to compare from.";
+    pub const USER_SETTINGS_DIFF_DESTINATION: &str = "Tag of the user_settings.py to compare to.";
     // Forget
     pub const FORGET_PROTON_TAG: &str = "Forget a GE Proton version";
     pub const FORGET_WINE_TAG: &str = "Forget a Wine GE version";
     pub const FORGET_WINE_LOL_TAG: &str = "Forget a Wine GE LoL version";
+    // Info
+    pub const INFO_PROTON_TAG: &str = "Show the details of a GE Proton version";
+    pub const INFO_WINE_TAG: &str = "Show the details of a Wine GE version";
+    pub const INFO_WINE_LOL_TAG: &str = "Show the details of a Wine GE LoL version";
+    // Pin
+    pub const PIN_PROTON_TAG: &str = "Pin a GE Proton version";
+    pub const PIN_WINE_TAG: &str = "Pin a Wine GE version";
+    pub const PIN_WINE_LOL_TAG: &str = "Pin a Wine GE LoL version";
+    pub const UNPIN_PROTON_TAG: &str = "Unpin a GE Proton version";
+    pub const UNPIN_WINE_TAG: &str = "Unpin a Wine GE version";
+    pub const UNPIN_WINE_LOL_TAG: &str = "Unpin a Wine GE LoL version";
+    // Completions
+    pub const COMPLETIONS_SHELL: &str = "Shell to generate the completion script for.";
+    // Verify
+    pub const VERIFY_PROTON_TAG: &str = "Verify a GE Proton version";
+    pub const VERIFY_WINE_TAG: &str = "Verify a Wine GE version";
+    pub const VERIFY_WINE_LOL_TAG: &str = "Verify a Wine GE LoL version";
+    // Export
+    pub const EXPORT_SCRIPT: &str = "Write a shell script of add commands which recreates the managed versions.";
+    pub const EXPORT_MANIFEST: &str = "Write the managed versions to a manifest file which can be imported with the \
+    import command. A file ending in .toml is written as TOML, any other file as JSON.";
+    pub const IMPORT_MANIFEST: &str = "Manifest file written by the export command.";
+    pub const IMPORT_DOWNLOAD: &str = "Download versions of the manifest which are not installed on this machine.";
 }
 
 pub mod value_name {
     pub const TAG: &str = "TAG";
     pub const PATH: &str = "PATH";
+    pub const NUMBER: &str = "NUMBER";
+    pub const ALGORITHM: &str = "ALGORITHM";
+    pub const SHELL: &str = "SHELL";
+    pub const LABEL: &str = "LABEL";
+    pub const PATTERN: &str = "PATTERN";
+    pub const FORMAT: &str = "FORMAT";
+    pub const NAME: &str = "NAME";
+    pub const ORDER: &str = "ORDER";
+    pub const DIR: &str = "DIR";
+    pub const FILE: &str = "FILE";
+    pub const RATE: &str = "RATE";
+}
+
+fn number(value: &str) -> Result<(), String> {
+    match value.parse::<usize>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(String::from("The value must be a number")),
+    }
+}
+
+fn positive_number(value: &str) -> Result<(), String> {
+    match value.parse::<usize>() {
+        Ok(number) if number > 0 => Ok(()),
+        _ => Err(String::from("The value must be a number greater than 0")),
+    }
+}
+
+fn rate(value: &str) -> Result<(), String> {
+    parse_rate(value).map(|_| ())
+}
+
+fn symlink_name(value: &str) -> Result<(), String> {
+    if value.is_empty() || value == "." || value == ".." || value.contains('/') {
+        Err(String::from("The value must be a file name without '/'"))
+    } else {
+        Ok(())
+    }
+}
+
+fn existing_directory(value: &str) -> Result<(), String> {
+    if Path::new(value).is_dir() {
+        Ok(())
+    } else {
+        Err(String::from("The value must be an existing directory"))
+    }
+}
+
+fn directory_name(value: &str) -> Result<(), String> {
+    if value.is_empty() || value.starts_with('.') || value.contains('/') {
+        Err(String::from("The value must be a directory name without '/' that does not start with '.'"))
+    } else {
+        Ok(())
+    }
+}
+
+fn label(value: &str) -> Result<(), String> {
+    if is_valid_label(value) {
+        Ok(())
+    } else {
+        Err(String::from("The label may only contain letters, digits, '-', '_' and '.'"))
+    }
+}
+
+/// The long command line flag which selects the given tag kind.
+pub fn tag_kind_flag(kind: &TagKind) -> String {
+    let arg = match kind {
+        TagKind::Proton => arg_names::PROTON_ARG,
+        TagKind::Wine { .. } if kind.eq(&TagKind::lol()) => arg_names::LOL_ARG,
+        TagKind::Wine { .. } => arg_names::WINE_ARG,
+    };
+    format!("--{}", arg)
 }
 
 fn proton_arg(help_text: &'static str, min_value: usize) -> Arg {
@@ -129,6 +410,10 @@ fn lol_arg(help_text: &'static str, min_value: usize) -> Arg {
         .multiple_values(false)
 }
 
+fn multiple_tags(tag_arg: Arg) -> Arg {
+    tag_arg.max_values(usize::MAX).multiple_values(true)
+}
+
 fn newest_arg(help_text: &'static str) -> Arg {
     Arg::new(arg_names::NEWEST_ARG)
         .short('n')
@@ -151,6 +436,68 @@ fn apply_arg(help_text: &'static str) -> Arg {
         .help(help_text)
 }
 
+fn refresh_arg(help_text: &'static str) -> Arg {
+    Arg::new(arg_names::REFRESH_ARG)
+        .long(arg_names::REFRESH_ARG)
+        .alias("no-cache")
+        .display_order(2)
+        .help(help_text)
+}
+
+fn checksum_arg(help_text: &'static str) -> Arg {
+    Arg::new(arg_names::CHECKSUM_ARG)
+        .long(arg_names::CHECKSUM_ARG)
+        .display_order(2)
+        .conflicts_with(arg_names::SKIP_CHECKSUM_ARG)
+        .help(help_text)
+}
+
+fn no_apply_arg(help_text: &'static str) -> Arg {
+    Arg::new(arg_names::NO_APPLY_ARG)
+        .long(arg_names::NO_APPLY_ARG)
+        .display_order(2)
+        .conflicts_with(arg_names::APPLY_ARG)
+        .help(help_text)
+}
+
+fn checksum_algorithm_arg(help_text: &'static str) -> Arg {
+    Arg::new(arg_names::CHECKSUM_ALGORITHM_ARG)
+        .long(arg_names::CHECKSUM_ALGORITHM_ARG)
+        .display_order(2)
+        .takes_value(true)
+        .value_name(value_name::ALGORITHM)
+        .possible_values(ChecksumAlgorithm::values())
+        .conflicts_with(arg_names::SKIP_CHECKSUM_ARG)
+        .help(help_text)
+}
+
+fn limit_arg(help_text: &'static str) -> Arg {
+    Arg::new(arg_names::LIMIT_ARG)
+        .long(arg_names::LIMIT_ARG)
+        .display_order(2)
+        .takes_value(true)
+        .value_name(value_name::NUMBER)
+        .validator(positive_number)
+        .help(help_text)
+}
+
+fn page_arg(help_text: &'static str) -> Arg {
+    Arg::new(arg_names::PAGE_ARG)
+        .long(arg_names::PAGE_ARG)
+        .display_order(2)
+        .takes_value(true)
+        .value_name(value_name::NUMBER)
+        .validator(positive_number)
+        .requires(arg_names::LIMIT_ARG)
+        .help(help_text)
+}
+
+/// Positional tag of the kind that is configured as `default_kind`. With a kind flag without value, the tag belongs to
+/// that kind instead.
+fn default_kind_tag_arg(help_text: &'static str) -> Arg {
+    Arg::new(arg_names::TAG_ARG).value_name(value_name::TAG).help(help_text)
+}
+
 fn tag_arg_group(required: bool) -> ArgGroup<'static> {
     ArgGroup::new(arg_group_names::TAG)
         .args(&[arg_names::PROTON_ARG, arg_names::WINE_ARG, arg_names::LOL_ARG])
@@ -166,6 +513,69 @@ fn setup_list_cmd() -> Command<'static> {
             wine_arg(help_text::LIST_WINE_TAG, 0).takes_value(false),
             lol_arg(help_text::LIST_WINE_LOL_TAG, 0).takes_value(false),
             newest_arg(help_text::LIST_NEWEST),
+            limit_arg(help_text::LIST_LIMIT),
+            page_arg(help_text::LIST_PAGE),
+            Arg::new(arg_names::MERGED_ARG)
+                .long(arg_names::MERGED_ARG)
+                .display_order(2)
+                .conflicts_with_all(&[arg_names::NEWEST_ARG, arg_names::LIMIT_ARG])
+                .help(help_text::LIST_MERGED),
+            Arg::new(arg_names::JSON_ARG)
+                .long(arg_names::JSON_ARG)
+                .display_order(2)
+                .conflicts_with(arg_names::MERGED_ARG)
+                .help(help_text::LIST_JSON),
+            Arg::new(arg_names::SIZE_ARG)
+                .long(arg_names::SIZE_ARG)
+                .display_order(2)
+                .conflicts_with(arg_names::JSON_ARG)
+                .help(help_text::LIST_SIZE),
+            Arg::new(arg_names::FILTER_ARG)
+                .long(arg_names::FILTER_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::PATTERN)
+                .forbid_empty_values(true)
+                .conflicts_with(arg_names::MERGED_ARG)
+                .help(help_text::LIST_FILTER),
+            Arg::new(arg_names::ALL_ARG)
+                .long(arg_names::ALL_ARG)
+                .display_order(2)
+                .conflicts_with_all(&[
+                    arg_names::PROTON_ARG,
+                    arg_names::WINE_ARG,
+                    arg_names::LOL_ARG,
+                    arg_names::MERGED_ARG,
+                    arg_names::JSON_ARG,
+                ])
+                .help(help_text::LIST_ALL),
+            Arg::new(arg_names::SORT_ARG)
+                .long(arg_names::SORT_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::ORDER)
+                .possible_values(ListSort::values())
+                .default_value("semver")
+                .conflicts_with(arg_names::MERGED_ARG)
+                .help(help_text::LIST_SORT),
+            Arg::new(arg_names::SHOW_ADDED_ARG)
+                .long(arg_names::SHOW_ADDED_ARG)
+                .display_order(2)
+                .conflicts_with_all(&[arg_names::MERGED_ARG, arg_names::JSON_ARG])
+                .help(help_text::LIST_SHOW_ADDED),
+            Arg::new(arg_names::INCLUDE_LOL_ARG)
+                .long(arg_names::INCLUDE_LOL_ARG)
+                .display_order(2)
+                .requires(arg_names::WINE_ARG)
+                .conflicts_with(arg_names::MERGED_ARG)
+                .help(help_text::LIST_INCLUDE_LOL),
+            Arg::new(arg_names::MANIFEST_ARG)
+                .long(arg_names::MANIFEST_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::PATH)
+                .conflicts_with(arg_names::MERGED_ARG)
+                .help(help_text::LIST_MANIFEST),
         ])
 }
 
@@ -179,8 +589,83 @@ fn setup_add_cmd() -> Command<'static> {
             lol_arg(help_text::ADD_WINE_LOL_TAG, 0),
             skip_checksum_arg(help_text::ADD_SKIP_CHECKSUM),
             apply_arg(help_text::ADD_APPLY),
+            checksum_arg(help_text::ADD_CHECKSUM),
+            no_apply_arg(help_text::ADD_NO_APPLY),
+            checksum_algorithm_arg(help_text::ADD_CHECKSUM_ALGORITHM),
+            Arg::new(arg_names::SKIP_EXISTING_ARG)
+                .long(arg_names::SKIP_EXISTING_ARG)
+                .display_order(2)
+                .help(help_text::ADD_SKIP_EXISTING),
+            Arg::new(arg_names::FORCE_ARG)
+                .long(arg_names::FORCE_ARG)
+                .display_order(2)
+                .conflicts_with_all(&[arg_names::SKIP_EXISTING_ARG, arg_names::DRY_RUN_ARG])
+                .help(help_text::ADD_FORCE),
+            Arg::new(arg_names::VERIFY_ONLY_ARG)
+                .long(arg_names::VERIFY_ONLY_ARG)
+                .alias("checksum-only")
+                .display_order(2)
+                .conflicts_with_all(&[
+                    arg_names::SKIP_CHECKSUM_ARG,
+                    arg_names::APPLY_ARG,
+                    arg_names::SKIP_EXISTING_ARG,
+                    arg_names::FORCE_ARG,
+                    arg_names::KEEP_ARCHIVE_CACHE_ARG,
+                    arg_names::KEEP_ARCHIVE_ARG,
+                    arg_names::DRY_RUN_ARG,
+                ])
+                .help(help_text::ADD_VERIFY_ONLY),
+            Arg::new(arg_names::KEEP_ARCHIVE_CACHE_ARG)
+                .long(arg_names::KEEP_ARCHIVE_CACHE_ARG)
+                .display_order(2)
+                .help(help_text::ADD_KEEP_ARCHIVE_CACHE),
+            Arg::new(arg_names::KEEP_ARCHIVE_ARG)
+                .long(arg_names::KEEP_ARCHIVE_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .min_values(0)
+                .require_equals(true)
+                .value_name(value_name::DIR)
+                .conflicts_with_all(&[arg_names::ARCHIVE_ARG, arg_names::DRY_RUN_ARG])
+                .help(help_text::ADD_KEEP_ARCHIVE),
+            Arg::new(arg_names::ARCHIVE_ARG)
+                .long(arg_names::ARCHIVE_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::PATH)
+                .conflicts_with(arg_names::KEEP_ARCHIVE_CACHE_ARG)
+                .help(help_text::ADD_ARCHIVE),
+            Arg::new(arg_names::CHECKSUM_FILE_ARG)
+                .long(arg_names::CHECKSUM_FILE_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::PATH)
+                .requires(arg_names::ARCHIVE_ARG)
+                .conflicts_with(arg_names::SKIP_CHECKSUM_ARG)
+                .help(help_text::ADD_CHECKSUM_FILE),
+            Arg::new(arg_names::LABEL_ARG)
+                .long(arg_names::LABEL_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::LABEL)
+                .validator(label)
+                .help(help_text::ADD_LABEL),
+            Arg::new(arg_names::DRY_RUN_ARG)
+                .long(arg_names::DRY_RUN_ARG)
+                .display_order(2)
+                .help(help_text::ADD_DRY_RUN),
+            Arg::new(arg_names::STEAM_ROOT_ARG)
+                .long(arg_names::STEAM_ROOT_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::DIR)
+                .validator(existing_directory)
+                .conflicts_with_all(&[arg_names::WINE_ARG, arg_names::LOL_ARG, arg_names::APPLY_ARG])
+                .help(help_text::ADD_STEAM_ROOT),
+            refresh_arg(help_text::ADD_REFRESH),
+            default_kind_tag_arg(help_text::ADD_TAG),
         ])
-        .group(tag_arg_group(true))
+        .group(tag_arg_group(false))
 }
 
 fn setup_rm_cmd() -> Command<'static> {
@@ -189,9 +674,18 @@ fn setup_rm_cmd() -> Command<'static> {
         .version(crate_version!())
         .alias("rm")
         .args(&[
-            proton_arg(help_text::REMOVE_PROTON_TAG, 1),
-            wine_arg(help_text::REMOVE_WINE_TAG, 1),
-            lol_arg(help_text::REMOVE_WINE_LOL_TAG, 1),
+            multiple_tags(proton_arg(help_text::REMOVE_PROTON_TAG, 1)),
+            multiple_tags(wine_arg(help_text::REMOVE_WINE_TAG, 1)),
+            multiple_tags(lol_arg(help_text::REMOVE_WINE_LOL_TAG, 1)),
+            Arg::new(arg_names::FORCE_ARG)
+                .long(arg_names::FORCE_ARG)
+                .display_order(2)
+                .help(help_text::REMOVE_FORCE),
+            Arg::new(arg_names::YES_ARG)
+                .long(arg_names::YES_ARG)
+                .short('y')
+                .display_order(2)
+                .help(help_text::REMOVE_YES),
         ])
         .group(tag_arg_group(true))
 }
@@ -205,6 +699,11 @@ fn setup_check_cmd() -> Command<'static> {
             proton_arg(help_text::CHECK_PROTON_TAG, 0).takes_value(false),
             wine_arg(help_text::CHECK_WINE_TAG, 0).takes_value(false),
             lol_arg(help_text::CHECK_WINE_LOL_TAG, 0).takes_value(false),
+            Arg::new(arg_names::NOTIFY_ARG)
+                .long(arg_names::NOTIFY_ARG)
+                .display_order(2)
+                .help(help_text::CHECK_NOTIFY),
+            refresh_arg(help_text::CHECK_REFRESH),
         ])
         .group(tag_arg_group(false))
 }
@@ -215,21 +714,47 @@ fn setup_migrate_cmd() -> Command<'static> {
         .version(crate_version!())
         .alias("mg")
         .args(&[
-            proton_arg(help_text::MIGRATE_PROTON_TAG, 1),
-            wine_arg(help_text::MIGRATE_WINE_TAG, 1),
-            lol_arg(help_text::MIGRATE_WINE_LOL_TAG, 1),
+            proton_arg(help_text::MIGRATE_PROTON_TAG, 0),
+            wine_arg(help_text::MIGRATE_WINE_TAG, 0),
+            lol_arg(help_text::MIGRATE_WINE_LOL_TAG, 0),
         ])
         .arg(
             Arg::new(arg_names::SOURCE_ARG)
                 .short('s')
                 .long(arg_names::SOURCE_ARG)
                 .help(help_text::MIGRATE_SOURCE)
-                .required(true)
+                .required_unless_present(arg_names::SOURCE_DIR_ARG)
+                .requires(arg_group_names::TAG)
                 .takes_value(true)
                 .display_order(1)
                 .value_name(value_name::PATH),
         )
-        .group(tag_arg_group(true))
+        .arg(
+            Arg::new(arg_names::SOURCE_DIR_ARG)
+                .long(arg_names::SOURCE_DIR_ARG)
+                .help(help_text::MIGRATE_SOURCE_DIR)
+                .conflicts_with(arg_names::SOURCE_ARG)
+                .takes_value(true)
+                .display_order(1)
+                .value_name(value_name::PATH),
+        )
+        .arg(
+            Arg::new(arg_names::DRY_RUN_ARG)
+                .long(arg_names::DRY_RUN_ARG)
+                .help(help_text::MIGRATE_DRY_RUN)
+                .display_order(2),
+        )
+        .arg(
+            Arg::new(arg_names::DIR_NAME_ARG)
+                .long(arg_names::DIR_NAME_ARG)
+                .help(help_text::MIGRATE_DIR_NAME)
+                .conflicts_with(arg_names::SOURCE_DIR_ARG)
+                .takes_value(true)
+                .display_order(2)
+                .value_name(value_name::NAME)
+                .validator(directory_name),
+        )
+        .group(tag_arg_group(false))
 }
 
 fn setup_apply_cmd() -> Command<'static> {
@@ -237,11 +762,58 @@ fn setup_apply_cmd() -> Command<'static> {
         .about(about_text::APPLY)
         .version(crate_version!())
         .args(&[
-            proton_arg(help_text::APPLY_PROTON_TAG, 1),
-            wine_arg(help_text::APPLY_WINE_TAG, 1),
-            lol_arg(help_text::APPLY_WINE_LOL_TAG, 1),
+            proton_arg(help_text::APPLY_PROTON_TAG, 0),
+            wine_arg(help_text::APPLY_WINE_TAG, 0),
+            lol_arg(help_text::APPLY_WINE_LOL_TAG, 0),
+            Arg::new(arg_names::NEXT_ARG)
+                .long(arg_names::NEXT_ARG)
+                .display_order(2)
+                .conflicts_with(arg_names::PREV_ARG)
+                .help(help_text::APPLY_NEXT),
+            Arg::new(arg_names::PREV_ARG)
+                .long(arg_names::PREV_ARG)
+                .display_order(2)
+                .help(help_text::APPLY_PREV),
+            Arg::new(arg_names::RESET_ARG)
+                .long(arg_names::RESET_ARG)
+                .display_order(2)
+                .conflicts_with_all(&[arg_names::NEXT_ARG, arg_names::PREV_ARG])
+                .help(help_text::APPLY_RESET),
+            Arg::new(arg_names::SYMLINK_ARG)
+                .long(arg_names::SYMLINK_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::NAME)
+                .validator(symlink_name)
+                .conflicts_with_all(&[arg_names::WINE_ARG, arg_names::LOL_ARG, arg_names::RESET_ARG])
+                .help(help_text::APPLY_SYMLINK),
+            Arg::new(arg_names::BOTTLES_ARG)
+                .long(arg_names::BOTTLES_ARG)
+                .display_order(2)
+                .conflicts_with_all(&[
+                    arg_names::PROTON_ARG,
+                    arg_names::NEXT_ARG,
+                    arg_names::PREV_ARG,
+                    arg_names::RESET_ARG,
+                    arg_names::SYMLINK_ARG,
+                ])
+                .help(help_text::APPLY_BOTTLES),
+            Arg::new(arg_names::NO_BACKUP_ARG)
+                .long(arg_names::NO_BACKUP_ARG)
+                .display_order(2)
+                .conflicts_with_all(&[arg_names::RESET_ARG, arg_names::SYMLINK_ARG, arg_names::BOTTLES_ARG])
+                .help(help_text::APPLY_NO_BACKUP),
+            Arg::new(arg_names::BACKUP_ARG)
+                .long(arg_names::BACKUP_ARG)
+                .display_order(2)
+                .takes_value(true)
+                .value_name(value_name::NUMBER)
+                .validator(positive_number)
+                .requires(arg_names::RESET_ARG)
+                .help(help_text::APPLY_BACKUP),
+            default_kind_tag_arg(help_text::APPLY_TAG),
         ])
-        .group(tag_arg_group(true))
+        .group(tag_arg_group(false))
 }
 
 fn setup_user_settings_cmd() -> Command<'static> {
@@ -259,10 +831,19 @@ fn setup_user_settings_cmd() -> Command<'static> {
                         .long(arg_names::SOURCE_ARG)
                         .help(help_text::USER_SETTINGS_COPY_SOURCE)
                         .takes_value(true)
-                        .required(true)
+                        .required_unless_present(arg_names::FROM_FILE_ARG)
                         .display_order(1)
                         .value_name(value_name::TAG),
                 )
+                .arg(
+                    Arg::new(arg_names::FROM_FILE_ARG)
+                        .long(arg_names::FROM_FILE_ARG)
+                        .help(help_text::USER_SETTINGS_COPY_FROM_FILE)
+                        .conflicts_with(arg_names::SOURCE_ARG)
+                        .takes_value(true)
+                        .display_order(1)
+                        .value_name(value_name::FILE),
+                )
                 .arg(
                     Arg::new(arg_names::DESTINATION_ARG)
                         .short('d')
@@ -274,6 +855,30 @@ fn setup_user_settings_cmd() -> Command<'static> {
                         .value_name(value_name::TAG),
                 ),
         )
+        .subcommand(
+            Command::new(commands::USER_SETTINGS_DIFF)
+                .about(about_text::USER_SETTINGS_DIFF)
+                .arg(
+                    Arg::new(arg_names::SOURCE_ARG)
+                        .short('s')
+                        .long(arg_names::SOURCE_ARG)
+                        .help(help_text::USER_SETTINGS_DIFF_SOURCE)
+                        .takes_value(true)
+                        .required(true)
+                        .display_order(1)
+                        .value_name(value_name::TAG),
+                )
+                .arg(
+                    Arg::new(arg_names::DESTINATION_ARG)
+                        .short('d')
+                        .long(arg_names::DESTINATION_ARG)
+                        .help(help_text::USER_SETTINGS_DIFF_DESTINATION)
+                        .takes_value(true)
+                        .required(true)
+                        .display_order(1)
+                        .value_name(value_name::TAG),
+                ),
+        )
 }
 
 fn setup_forget_cmd() -> Command<'static> {
@@ -288,9 +893,222 @@ fn setup_forget_cmd() -> Command<'static> {
         .group(tag_arg_group(true))
 }
 
+fn setup_pin_cmd() -> Command<'static> {
+    Command::new(commands::PIN)
+        .about(about_text::PIN)
+        .version(crate_version!())
+        .args(&[
+            proton_arg(help_text::PIN_PROTON_TAG, 1),
+            wine_arg(help_text::PIN_WINE_TAG, 1),
+            lol_arg(help_text::PIN_WINE_LOL_TAG, 1),
+        ])
+        .group(tag_arg_group(true))
+}
+
+fn setup_unpin_cmd() -> Command<'static> {
+    Command::new(commands::UNPIN)
+        .about(about_text::UNPIN)
+        .version(crate_version!())
+        .args(&[
+            proton_arg(help_text::UNPIN_PROTON_TAG, 1),
+            wine_arg(help_text::UNPIN_WINE_TAG, 1),
+            lol_arg(help_text::UNPIN_WINE_LOL_TAG, 1),
+        ])
+        .group(tag_arg_group(true))
+}
+
+fn setup_verify_cmd() -> Command<'static> {
+    Command::new(commands::VERIFY)
+        .about(about_text::VERIFY)
+        .version(crate_version!())
+        .args(&[
+            proton_arg(help_text::VERIFY_PROTON_TAG, 1),
+            wine_arg(help_text::VERIFY_WINE_TAG, 1),
+            lol_arg(help_text::VERIFY_WINE_LOL_TAG, 1),
+        ])
+        .group(tag_arg_group(false))
+}
+
+fn setup_completions_cmd() -> Command<'static> {
+    Command::new(commands::COMPLETIONS)
+        .about(about_text::COMPLETIONS)
+        .version(crate_version!())
+        .arg(
+            Arg::new(arg_names::SHELL_ARG)
+                .help(help_text::COMPLETIONS_SHELL)
+                .required(true)
+                .takes_value(true)
+                .value_name(value_name::SHELL)
+                .possible_values(["bash", "zsh", "fish", "powershell"]),
+        )
+}
+
+fn setup_export_cmd() -> Command<'static> {
+    Command::new(commands::EXPORT)
+        .about(about_text::EXPORT)
+        .version(crate_version!())
+        .arg(
+            Arg::new(arg_names::SCRIPT_ARG)
+                .long(arg_names::SCRIPT_ARG)
+                .help(help_text::EXPORT_SCRIPT)
+                .takes_value(true)
+                .display_order(1)
+                .value_name(value_name::PATH),
+        )
+        .arg(
+            Arg::new(arg_names::MANIFEST_ARG)
+                .help(help_text::EXPORT_MANIFEST)
+                .takes_value(true)
+                .value_name(value_name::PATH),
+        )
+        .group(
+            ArgGroup::new(arg_group_names::EXPORT_TARGET)
+                .args(&[arg_names::SCRIPT_ARG, arg_names::MANIFEST_ARG])
+                .required(true),
+        )
+}
+
+fn setup_import_cmd() -> Command<'static> {
+    Command::new(commands::IMPORT)
+        .about(about_text::IMPORT)
+        .version(crate_version!())
+        .arg(
+            Arg::new(arg_names::MANIFEST_ARG)
+                .help(help_text::IMPORT_MANIFEST)
+                .required(true)
+                .takes_value(true)
+                .value_name(value_name::PATH),
+        )
+        .arg(
+            Arg::new(arg_names::DOWNLOAD_ARG)
+                .long(arg_names::DOWNLOAD_ARG)
+                .help(help_text::IMPORT_DOWNLOAD)
+                .display_order(1),
+        )
+}
+
+fn setup_dirs_cmd() -> Command<'static> {
+    Command::new(commands::DIRS)
+        .about(about_text::DIRS)
+        .version(crate_version!())
+}
+
+fn setup_info_cmd() -> Command<'static> {
+    Command::new(commands::INFO)
+        .about(about_text::INFO)
+        .version(crate_version!())
+        .args(&[
+            proton_arg(help_text::INFO_PROTON_TAG, 1),
+            wine_arg(help_text::INFO_WINE_TAG, 1),
+            lol_arg(help_text::INFO_WINE_LOL_TAG, 1),
+        ])
+        .group(tag_arg_group(true))
+}
+
+fn setup_rebuild_cmd() -> Command<'static> {
+    Command::new(commands::REBUILD)
+        .about(about_text::REBUILD)
+        .version(crate_version!())
+}
+
+fn setup_status_cmd() -> Command<'static> {
+    Command::new(commands::STATUS)
+        .about(about_text::STATUS)
+        .version(crate_version!())
+}
+
 pub fn setup_clap() -> Command<'static> {
     command!()
         .subcommand_required(true)
+        .after_help(about_text::EXIT_CODES)
+        .arg(
+            Arg::new(arg_names::RETRY_ALL_ARG)
+                .long(arg_names::RETRY_ALL_ARG)
+                .help(help_text::RETRY_ALL)
+                .global(true)
+                .takes_value(true)
+                .value_name(value_name::NUMBER)
+                .validator(number),
+        )
+        .arg(
+            Arg::new(arg_names::NO_PROGRESS_ARG)
+                .long(arg_names::NO_PROGRESS_ARG)
+                .help(help_text::NO_PROGRESS)
+                .global(true),
+        )
+        .arg(
+            Arg::new(arg_names::PROGRESS_FORMAT_ARG)
+                .long(arg_names::PROGRESS_FORMAT_ARG)
+                .help(help_text::PROGRESS_FORMAT)
+                .global(true)
+                .takes_value(true)
+                .value_name(value_name::FORMAT)
+                .possible_values(ProgressFormat::values()),
+        )
+        .arg(
+            Arg::new(arg_names::FLATPAK_STEAM_ARG)
+                .long(arg_names::FLATPAK_STEAM_ARG)
+                .help(help_text::FLATPAK_STEAM)
+                .global(true),
+        )
+        .arg(
+            Arg::new(arg_names::FLATPAK_LUTRIS_ARG)
+                .long(arg_names::FLATPAK_LUTRIS_ARG)
+                .help(help_text::FLATPAK_LUTRIS)
+                .global(true),
+        )
+        .arg(
+            Arg::new(arg_names::ASSUME_YES_ARG)
+                .long(arg_names::ASSUME_YES_ARG)
+                .help(help_text::ASSUME_YES)
+                .global(true),
+        )
+        .arg(
+            Arg::new(arg_names::VERBOSE_ARG)
+                .short('v')
+                .long(arg_names::VERBOSE_ARG)
+                .help(help_text::VERBOSE)
+                .global(true)
+                .multiple_occurrences(true),
+        )
+        .arg(
+            Arg::new(arg_names::LOG_FILE_ARG)
+                .long(arg_names::LOG_FILE_ARG)
+                .help(help_text::LOG_FILE)
+                .global(true)
+                .takes_value(true)
+                .value_name(value_name::PATH),
+        )
+        .arg(
+            Arg::new(arg_names::LIMIT_RATE_ARG)
+                .long(arg_names::LIMIT_RATE_ARG)
+                .help(help_text::LIMIT_RATE)
+                .global(true)
+                .takes_value(true)
+                .value_name(value_name::RATE)
+                .validator(rate),
+        )
+        .arg(
+            Arg::new(arg_names::OFFLINE_ARG)
+                .long(arg_names::OFFLINE_ARG)
+                .help(help_text::OFFLINE)
+                .global(true),
+        )
+        .arg(
+            Arg::new(arg_names::QUIET_ARG)
+                .short('q')
+                .long(arg_names::QUIET_ARG)
+                .help(help_text::QUIET)
+                .global(true),
+        )
+        .arg(
+            Arg::new(arg_names::CONFIG_ARG)
+                .long(arg_names::CONFIG_ARG)
+                .help(help_text::CONFIG)
+                .global(true)
+                .takes_value(true)
+                .value_name(value_name::FILE),
+        )
         .subcommand(setup_list_cmd())
         .subcommand(setup_add_cmd())
         .subcommand(setup_rm_cmd())
@@ -299,4 +1117,14 @@ pub fn setup_clap() -> Command<'static> {
         .subcommand(setup_apply_cmd())
         .subcommand(setup_user_settings_cmd())
         .subcommand(setup_forget_cmd())
+        .subcommand(setup_pin_cmd())
+        .subcommand(setup_unpin_cmd())
+        .subcommand(setup_verify_cmd())
+        .subcommand(setup_export_cmd())
+        .subcommand(setup_import_cmd())
+        .subcommand(setup_rebuild_cmd())
+        .subcommand(setup_dirs_cmd())
+        .subcommand(setup_status_cmd())
+        .subcommand(setup_info_cmd())
+        .subcommand(setup_completions_cmd())
 }
@@ -0,0 +1,307 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use ge_man_lib::tag::TagKind;
+use serde::{Deserialize, Serialize};
+
+use crate::progress::ProgressFormat;
+
+/// File format used to store the managed versions.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ManagedVersionsFormat {
+    Json,
+    Toml,
+}
+
+impl ManagedVersionsFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ManagedVersionsFormat::Json => "json",
+            ManagedVersionsFormat::Toml => "toml",
+        }
+    }
+
+    /// Detect the format by the extension of the given path. Unknown extensions are treated as JSON.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => ManagedVersionsFormat::Toml,
+            _ => ManagedVersionsFormat::Json,
+        }
+    }
+}
+
+impl Default for ManagedVersionsFormat {
+    fn default() -> Self {
+        ManagedVersionsFormat::Json
+    }
+}
+
+/// Tag kind which is used by `add` and `apply` when no kind flag is provided.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultKind {
+    Proton,
+    Wine,
+    Lol,
+}
+
+impl DefaultKind {
+    pub fn tag_kind(&self) -> TagKind {
+        match self {
+            DefaultKind::Proton => TagKind::Proton,
+            DefaultKind::Wine => TagKind::wine(),
+            DefaultKind::Lol => TagKind::lol(),
+        }
+    }
+}
+
+/// Default behavior for one tag kind. These values are used when the corresponding command line flag is not provided.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct KindDefaults {
+    pub skip_checksum: bool,
+    pub apply_after_add: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct KindProfiles {
+    pub proton: KindDefaults,
+    pub wine: KindDefaults,
+    pub lol: KindDefaults,
+}
+
+/// User configuration of GE-Man which is read from the `config.json` file in the GE-Man config directory.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct GeManConfig {
+    pub defaults: KindProfiles,
+    pub managed_versions_format: ManagedVersionsFormat,
+    /// How often a failed network request is retried.
+    pub retries: usize,
+    /// Delay in milliseconds before the first retry. The delay doubles with every further retry.
+    pub retry_delay_ms: u64,
+    /// Maximum size of the archive cache in MiB.
+    pub archive_cache_size_mib: u64,
+    /// Whether progress bars are drawn while downloading and extracting a version.
+    pub show_progress: bool,
+    pub progress_format: ProgressFormat,
+    /// Steam compatibility tools directory which is used instead of the one in the Steam root directory.
+    pub steam_compat_tools_dir: Option<PathBuf>,
+    /// How long the latest release tags are cached in seconds. A value of 0 disables the cache.
+    pub latest_tag_cache_ttl_secs: u64,
+    /// Directory in which archives are extracted before they are moved into the Steam or Lutris directory.
+    pub extract_tmp_dir: Option<PathBuf>,
+    pub default_kind: Option<DefaultKind>,
+    /// Maximum download rate in bytes per second. Downloads are not limited if no rate is set.
+    pub limit_rate: Option<u64>,
+    /// How many backups of the Steam and Lutris config `apply` keeps. The oldest backup is removed first.
+    pub apply_backups: usize,
+    /// Executable which is run after a version was added. It gets the directory and the tag of the version.
+    pub post_add_hook: Option<PathBuf>,
+    /// Whether a failing post add hook fails the add command instead of only printing a warning.
+    pub post_add_hook_fatal: bool,
+}
+
+impl Default for GeManConfig {
+    fn default() -> Self {
+        GeManConfig {
+            defaults: KindProfiles::default(),
+            managed_versions_format: ManagedVersionsFormat::default(),
+            retries: 0,
+            retry_delay_ms: 500,
+            archive_cache_size_mib: 2048,
+            show_progress: true,
+            progress_format: ProgressFormat::default(),
+            steam_compat_tools_dir: None,
+            latest_tag_cache_ttl_secs: 3600,
+            extract_tmp_dir: None,
+            default_kind: None,
+            limit_rate: None,
+            apply_backups: 3,
+            post_add_hook: None,
+            post_add_hook_fatal: false,
+        }
+    }
+}
+
+impl GeManConfig {
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let config = match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).context(format!("Could not parse {}", path.display()))?,
+            Err(err) => {
+                if err.kind() == std::io::ErrorKind::NotFound {
+                    GeManConfig::default()
+                } else {
+                    bail!("Could not read GE-Man config from {}", path.display());
+                }
+            }
+        };
+
+        Ok(config)
+    }
+
+    pub fn retry_delay(&self) -> Duration {
+        Duration::from_millis(self.retry_delay_ms)
+    }
+
+    pub fn latest_tag_cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.latest_tag_cache_ttl_secs)
+    }
+
+    pub fn default_tag_kind(&self) -> Option<TagKind> {
+        self.default_kind.as_ref().map(DefaultKind::tag_kind)
+    }
+
+    pub fn defaults_for(&self, kind: &TagKind) -> &KindDefaults {
+        match kind {
+            TagKind::Proton => &self.defaults.proton,
+            TagKind::Wine { .. } if kind.eq(&TagKind::lol()) => &self.defaults.lol,
+            TagKind::Wine { .. } => &self.defaults.wine,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Write;
+
+    use assert_fs::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn read_missing_config_should_return_default() {
+        let tmp_dir = TempDir::new().unwrap();
+        let config = GeManConfig::from_file(&tmp_dir.join("config.json")).unwrap();
+        assert_eq!(config, GeManConfig::default());
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn read_partial_config() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("config.json");
+        let mut file = File::create(&path).unwrap();
+        file.write_all(br#"{"defaults": {"proton": {"apply_after_add": true}, "lol": {"skip_checksum": true}}}"#)
+            .unwrap();
+
+        let config = GeManConfig::from_file(&path).unwrap();
+        assert_eq!(
+            config.defaults_for(&TagKind::Proton),
+            &KindDefaults {
+                skip_checksum: false,
+                apply_after_add: true
+            }
+        );
+        assert_eq!(config.defaults_for(&TagKind::wine()), &KindDefaults::default());
+        assert_eq!(
+            config.defaults_for(&TagKind::lol()),
+            &KindDefaults {
+                skip_checksum: true,
+                apply_after_add: false
+            }
+        );
+
+        drop(file);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn read_managed_versions_format() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("config.json");
+        let mut file = File::create(&path).unwrap();
+        file.write_all(br#"{"managed_versions_format": "toml"}"#).unwrap();
+
+        let config = GeManConfig::from_file(&path).unwrap();
+        assert_eq!(config.managed_versions_format, ManagedVersionsFormat::Toml);
+        assert_eq!(config.defaults, KindProfiles::default());
+
+        drop(file);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn read_steam_compat_tools_dir() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("config.json");
+        let mut file = File::create(&path).unwrap();
+        file.write_all(br#"{"steam_compat_tools_dir": "/tmp/Steam/compatibilitytools.d"}"#)
+            .unwrap();
+
+        let config = GeManConfig::from_file(&path).unwrap();
+        assert_eq!(
+            config.steam_compat_tools_dir,
+            Some(PathBuf::from("/tmp/Steam/compatibilitytools.d"))
+        );
+
+        drop(file);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn read_default_kind() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("config.json");
+        fs::write(&path, r#"{"default_kind": "lol"}"#).unwrap();
+
+        let config = GeManConfig::from_file(&path).unwrap();
+        assert_eq!(config.default_kind, Some(DefaultKind::Lol));
+        assert_eq!(config.default_tag_kind(), Some(TagKind::lol()));
+        assert_eq!(GeManConfig::default().default_tag_kind(), None);
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn read_post_add_hook() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("config.json");
+        fs::write(&path, r#"{"post_add_hook": "/home/user/bin/patch-proton.sh"}"#).unwrap();
+
+        let config = GeManConfig::from_file(&path).unwrap();
+        assert_eq!(
+            config.post_add_hook,
+            Some(PathBuf::from("/home/user/bin/patch-proton.sh"))
+        );
+        assert!(!config.post_add_hook_fatal);
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn managed_versions_format_from_path() {
+        assert_eq!(
+            ManagedVersionsFormat::from_path(Path::new("managed_versions.toml")),
+            ManagedVersionsFormat::Toml
+        );
+        assert_eq!(
+            ManagedVersionsFormat::from_path(Path::new("managed_versions.json")),
+            ManagedVersionsFormat::Json
+        );
+        assert_eq!(
+            ManagedVersionsFormat::from_path(Path::new("managed_versions")),
+            ManagedVersionsFormat::Json
+        );
+    }
+
+    #[test]
+    fn read_invalid_config() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("config.json");
+        let mut file = File::create(&path).unwrap();
+        file.write_all(b"{").unwrap();
+
+        let result = GeManConfig::from_file(&path);
+        assert!(result.is_err());
+
+        drop(file);
+        tmp_dir.close().unwrap();
+    }
+}
@@ -1,13 +1,17 @@
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
-use std::fs;
-use std::path::Path;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{fs, io};
 
 use anyhow::{bail, Context};
 use ge_man_lib::tag::{Tag, TagKind};
 use serde::{Deserialize, Serialize};
 
-use crate::version::{Version, Versioned};
+use crate::config::ManagedVersionsFormat;
+use crate::version::{cmp_tags, normalize_tag, write_version, Version, Versioned};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(tag = "type")]
@@ -15,6 +19,19 @@ pub struct ManagedVersion {
     tag: Tag,
     kind: TagKind,
     directory_name: String,
+    #[serde(default)]
+    pinned: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    /// RFC 3339 timestamp of when the version was first registered. Manifests written by older releases lack it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    added_at: Option<String>,
+    /// Whether no checksum was compared when the version was added, so no checksum baseline exists.
+    #[serde(default)]
+    checksum_skipped: bool,
+    /// Steam root of a GE Proton version which was added with `--steam-root` instead of into the default Steam root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    steam_root: Option<PathBuf>,
 }
 
 impl ManagedVersion {
@@ -29,6 +46,11 @@ impl ManagedVersion {
             tag,
             kind,
             directory_name,
+            pinned: false,
+            label: None,
+            added_at: None,
+            checksum_skipped: false,
+            steam_root: None,
         }
     }
 
@@ -55,13 +77,51 @@ impl ManagedVersion {
     pub fn set_directory_name<S: Into<String>>(&mut self, name: S) {
         self.directory_name = name.into();
     }
+
+    pub fn pinned(&self) -> bool {
+        self.pinned
+    }
+
+    pub fn set_pinned(&mut self, pinned: bool) {
+        self.pinned = pinned;
+    }
+
+    pub fn label(&self) -> Option<&String> {
+        self.label.as_ref()
+    }
+
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
+    }
+
+    pub fn added_at(&self) -> Option<&String> {
+        self.added_at.as_ref()
+    }
+
+    pub fn set_added_at(&mut self, added_at: Option<String>) {
+        self.added_at = added_at;
+    }
+
+    pub fn checksum_skipped(&self) -> bool {
+        self.checksum_skipped
+    }
+
+    pub fn set_checksum_skipped(&mut self, checksum_skipped: bool) {
+        self.checksum_skipped = checksum_skipped;
+    }
+
+    pub fn steam_root(&self) -> Option<&Path> {
+        self.steam_root.as_deref()
+    }
+
+    pub fn set_steam_root(&mut self, steam_root: Option<PathBuf>) {
+        self.steam_root = steam_root;
+    }
 }
 
 impl From<Version> for ManagedVersion {
     fn from(v: Version) -> Self {
-        let tag = v.tag().clone();
-        let kind = *v.kind();
-        ManagedVersion::new(tag, kind, String::new())
+        ManagedVersion::from(&v)
     }
 }
 
@@ -69,7 +129,9 @@ impl From<&Version> for ManagedVersion {
     fn from(v: &Version) -> Self {
         let tag = v.tag().clone();
         let kind = *v.kind();
-        ManagedVersion::new(tag, kind, String::new())
+        let mut version = ManagedVersion::new(tag, kind, String::new());
+        version.set_label(v.label().cloned());
+        version
     }
 }
 
@@ -81,17 +143,21 @@ impl Versioned for ManagedVersion {
     fn kind(&self) -> &TagKind {
         &self.kind
     }
+
+    fn label(&self) -> Option<&String> {
+        self.label.as_ref()
+    }
 }
 
 impl PartialEq for ManagedVersion {
     fn eq(&self, other: &Self) -> bool {
-        self.tag().eq(other.tag()) && self.kind().eq(other.kind())
+        self.tag().eq(other.tag()) && self.kind().eq(other.kind()) && self.label.eq(&other.label)
     }
 }
 
 impl<'a> PartialEq<dyn Versioned + 'a> for ManagedVersion {
     fn eq(&self, other: &(dyn Versioned + 'a)) -> bool {
-        self.tag().eq(other.tag()) && self.kind().eq(other.kind())
+        self.tag().eq(other.tag()) && self.kind().eq(other.kind()) && self.label().eq(&other.label())
     }
 }
 
@@ -103,7 +169,11 @@ impl PartialOrd for ManagedVersion {
 
 impl<'a> PartialOrd<dyn Versioned + 'a> for ManagedVersion {
     fn partial_cmp(&self, other: &(dyn Versioned + 'a)) -> Option<Ordering> {
-        Some(self.tag().cmp(other.tag()).then(self.kind().cmp(other.kind())))
+        Some(
+            cmp_tags(self.tag(), other.tag())
+                .then(self.kind().cmp(other.kind()))
+                .then(self.label().cmp(&other.label())),
+        )
     }
 }
 
@@ -111,16 +181,80 @@ impl Eq for ManagedVersion {}
 
 impl Ord for ManagedVersion {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.tag().cmp(other.tag()).then(self.kind().cmp(other.kind()))
+        cmp_tags(self.tag(), other.tag())
+            .then(self.kind().cmp(other.kind()))
+            .then(self.label.cmp(&other.label))
     }
 }
 
 impl Display for ManagedVersion {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} ({})", self.tag, self.kind.compatibility_tool_kind())
+        write_version(f, &self.tag, self.label.as_ref(), &self.kind)
     }
 }
 
+/// The managed versions file exists but can not be parsed. A copy of the file is kept at `backup_path`.
+#[derive(Debug)]
+pub struct CorruptManifestError {
+    pub path: PathBuf,
+    pub backup_path: PathBuf,
+    pub reason: String,
+}
+
+impl Display for CorruptManifestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is corrupt: {}", self.path.display(), self.reason)
+    }
+}
+
+impl std::error::Error for CorruptManifestError {}
+
+/// Write the content to a temporary file next to the given path and rename it over the path afterwards. The rename is
+/// atomic on the same filesystem, so an interrupted write can not leave a truncated file behind.
+fn write_atomically(path: &Path, content: &[u8]) -> io::Result<()> {
+    let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    let tmp_path = path.with_file_name(format!(".{}.tmp", file_name));
+
+    let result = File::create(&tmp_path)
+        .and_then(|mut file| file.write_all(content).and_then(|_| file.sync_all()))
+        .and_then(|_| fs::rename(&tmp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+
+    result
+}
+
+/// Format the given time as RFC 3339 timestamp in UTC, e.g. `2022-08-01T12:30:00Z`.
+fn format_rfc3339(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let secs_of_day = secs % 86_400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Convert days since the Unix epoch to a (year, month, day) date of the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ManagedVersions {
     versions: Vec<ManagedVersion>,
@@ -131,14 +265,24 @@ impl ManagedVersions {
         ManagedVersions { versions: items }
     }
 
+    /// Read the managed versions from the given path. The file format is detected by the file extension.
     pub fn from_file(path: &Path) -> anyhow::Result<Self> {
         let managed_versions = match fs::read_to_string(path) {
-            Ok(json) => serde_json::from_str(&json)?,
+            Ok(content) => {
+                let parsed = match ManagedVersionsFormat::from_path(path) {
+                    ManagedVersionsFormat::Json => serde_json::from_str(&content).map_err(|err| err.to_string()),
+                    ManagedVersionsFormat::Toml => toml::from_str(&content).map_err(|err| err.to_string()),
+                };
+                match parsed {
+                    Ok(managed_versions) => managed_versions,
+                    Err(reason) => return Err(ManagedVersions::backup_corrupt_file(path, reason)),
+                }
+            }
             Err(err) => {
                 if err.kind() == std::io::ErrorKind::NotFound {
                     ManagedVersions::default()
                 } else {
-                    bail!("Could not convert managed_versions.json to struct");
+                    bail!("Could not convert {} to struct", path.display());
                 }
             }
         };
@@ -146,27 +290,71 @@ impl ManagedVersions {
         Ok(managed_versions)
     }
 
+    /// Copy a file which can not be parsed next to it, so a later write can not lose its content.
+    fn backup_corrupt_file(path: &Path, reason: String) -> anyhow::Error {
+        let mut backup_path = path.as_os_str().to_owned();
+        backup_path.push(".bak");
+        let backup_path = PathBuf::from(backup_path);
+
+        if let Err(err) = fs::copy(path, &backup_path) {
+            return anyhow::Error::new(err).context(format!(
+                "{} is corrupt and could not be backed up to {}",
+                path.display(),
+                backup_path.display()
+            ));
+        }
+
+        anyhow::Error::new(CorruptManifestError {
+            path: path.to_path_buf(),
+            backup_path,
+            reason,
+        })
+    }
+
+    /// Write the managed versions to the given path. The file format is detected by the file extension.
     pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
-        let json = serde_json::to_string(&self).context("Could not convert managed version struct to json")?;
+        let content = match ManagedVersionsFormat::from_path(path) {
+            ManagedVersionsFormat::Json => {
+                serde_json::to_string(&self).context("Could not convert managed version struct to json")?
+            }
+            // Convert to a TOML value first, so simple values are written before tables.
+            ManagedVersionsFormat::Toml => toml::Value::try_from(self)
+                .and_then(|value| toml::to_string(&value))
+                .context("Could not convert managed version struct to toml")?,
+        };
 
-        fs::write(path, json).context("Could not write changes to managed_versions.json")?;
+        write_atomically(path, content.as_bytes()).context(format!("Could not write changes to {}", path.display()))?;
 
         Ok(())
     }
 
+    /// Find the index of the given version. If no version with the exact tag exists, the normalized tags are compared
+    /// to allow slightly different spellings of the stored tag.
     fn get_version_index(&self, version: &dyn Versioned) -> Option<usize> {
-        self.versions.iter().position(|i| i.eq(version))
+        self.versions.iter().position(|i| i.eq(version)).or_else(|| {
+            let tag = normalize_tag(version.tag().value());
+            self.versions.iter().position(|i| {
+                i.kind().eq(version.kind()) && i.label().eq(&version.label()) && normalize_tag(i.tag().value()).eq(&tag)
+            })
+        })
     }
 
+    /// Find the newest version of the given kind. Versions of other kinds are never considered, even if they have a
+    /// newer tag.
     pub fn find_latest_by_kind(&self, kind: &TagKind) -> Option<ManagedVersion> {
         self.versions
             .iter()
             .filter(|v| v.kind().eq(kind))
-            .max_by(|a, b| a.tag().semver().cmp(&b.tag().semver()))
+            .max_by(|a, b| cmp_tags(a.tag(), b.tag()))
             .cloned()
     }
 
-    pub fn add(&mut self, version: ManagedVersion) -> anyhow::Result<ManagedVersion> {
+    /// Register the given version. A version without a timestamp gets the current time as `added_at`, so imported
+    /// versions keep their original timestamp.
+    pub fn add(&mut self, mut version: ManagedVersion) -> anyhow::Result<ManagedVersion> {
+        if version.added_at.is_none() {
+            version.added_at = Some(format_rfc3339(SystemTime::now()));
+        }
         self.versions.push(version.clone());
 
         Ok(version)
@@ -174,16 +362,40 @@ impl ManagedVersions {
 
     pub fn remove(&mut self, version: &dyn Versioned) -> Option<ManagedVersion> {
         match self.get_version_index(version) {
-            Some(index) => Some(self.versions.swap_remove(index)),
+            Some(index) => Some(self.versions.remove(index)),
             None => None,
         }
     }
 
+    /// Pin or unpin the given version. Returns the updated version or `None` if the version is not managed.
+    pub fn set_pinned(&mut self, version: &dyn Versioned, pinned: bool) -> Option<ManagedVersion> {
+        let index = self.get_version_index(version)?;
+        let managed_version = &mut self.versions[index];
+        managed_version.set_pinned(pinned);
+
+        Some(managed_version.clone())
+    }
+
+    /// Find the given version. The lookup is scoped to the kind of the version, so the same tag of a different kind is
+    /// never returned.
     pub fn find_version(&self, version: &dyn Versioned) -> Option<ManagedVersion> {
         self.get_version_index(version)
             .and_then(|index| self.versions.get(index).cloned())
     }
 
+    /// Kinds for which a version with the given tag is managed. More than one kind means the tag is ambiguous without a
+    /// kind flag.
+    pub fn kinds_of_tag(&self, tag: &Tag) -> Vec<TagKind> {
+        let tag = normalize_tag(tag.value());
+        let mut kinds: Vec<TagKind> = Vec::new();
+        for version in self.versions.iter() {
+            if normalize_tag(version.tag().value()).eq(&tag) && !kinds.contains(version.kind()) {
+                kinds.push(*version.kind());
+            }
+        }
+        kinds
+    }
+
     pub fn latest_versions(&self) -> Vec<ManagedVersion> {
         let kinds = TagKind::values();
         let mut versions = Vec::with_capacity(kinds.len());
@@ -264,7 +476,9 @@ mod managed_versions_tests {
 
     use assert_fs::TempDir;
     use ge_man_lib::tag::TagKind;
+    use itertools::Itertools;
     use lazy_static::lazy_static;
+    use test_case::test_case;
 
     use super::*;
 
@@ -279,6 +493,45 @@ mod managed_versions_tests {
         ];
     }
 
+    #[test]
+    fn kinds_of_tag_should_list_every_kind_of_the_tag() {
+        let managed_versions = ManagedVersions::new(VERSIONS.clone());
+
+        assert_eq!(
+            managed_versions.kinds_of_tag(&Tag::from("6.20-GE-1")),
+            vec![TagKind::Proton, TagKind::wine()]
+        );
+        assert_eq!(
+            managed_versions.kinds_of_tag(&Tag::from("6.16-GE-3-LoL")),
+            vec![TagKind::lol()]
+        );
+        assert!(managed_versions.kinds_of_tag(&Tag::from("6.21-GE-1")).is_empty());
+    }
+
+    #[test]
+    fn latest_by_kind_with_mixed_proton_tag_schemes() {
+        let managed_versions = ManagedVersions::new(vec![
+            ManagedVersion::from(Version::proton("GE-Proton7-22")),
+            ManagedVersion::from(Version::proton("GE-Proton8-1")),
+            ManagedVersion::from(Version::proton("6.21-GE-2")),
+            ManagedVersion::from(Version::proton("GE-Proton7-8")),
+        ]);
+
+        let latest_proton = managed_versions.find_latest_by_kind(&TagKind::Proton).unwrap();
+        assert_eq!(latest_proton, ManagedVersion::from(Version::proton("GE-Proton8-1")));
+
+        let sorted = managed_versions.versions().into_iter().sorted().collect_vec();
+        assert_eq!(
+            sorted,
+            vec![
+                ManagedVersion::from(Version::proton("6.21-GE-2")),
+                ManagedVersion::from(Version::proton("GE-Proton7-8")),
+                ManagedVersion::from(Version::proton("GE-Proton7-22")),
+                ManagedVersion::from(Version::proton("GE-Proton8-1")),
+            ]
+        );
+    }
+
     #[test]
     fn latest_by_kind() {
         let managed_versions = ManagedVersions::new(VERSIONS.clone());
@@ -332,6 +585,120 @@ mod managed_versions_tests {
         assert!(managed_versions.versions().is_empty());
     }
 
+    #[test]
+    fn add_should_set_added_at_only_for_new_versions() {
+        let mut managed_versions = ManagedVersions::default();
+        let added = managed_versions
+            .add(ManagedVersion::from(Version::proton("6.20-GE-1")))
+            .unwrap();
+        assert!(added.added_at().is_some());
+
+        let mut imported = ManagedVersion::from(Version::proton("6.21-GE-1"));
+        imported.set_added_at(Some(String::from("2022-08-01T12:30:00Z")));
+        let imported = managed_versions.add(imported).unwrap();
+        assert_eq!(imported.added_at(), Some(&String::from("2022-08-01T12:30:00Z")));
+    }
+
+    #[test]
+    fn read_manifest_without_added_at() {
+        let json = r#"{"versions":[{"type":"ManagedVersion","tag":"6.20-GE-1","kind":{"type":"Proton"},
+            "directory_name":"Proton-6.20-GE-1"}]}"#;
+        let managed_versions: ManagedVersions = serde_json::from_str(json).unwrap();
+        assert_eq!(managed_versions.versions()[0].added_at(), None);
+        assert!(!managed_versions.versions()[0].checksum_skipped());
+        assert_eq!(managed_versions.versions()[0].steam_root(), None);
+    }
+
+    #[test]
+    fn steam_root_should_survive_a_manifest_round_trip() {
+        let mut version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        version.set_steam_root(Some(PathBuf::from("/mnt/games/steam")));
+        let json = serde_json::to_string(&ManagedVersions::new(vec![version])).unwrap();
+
+        let managed_versions: ManagedVersions = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            managed_versions.versions()[0].steam_root(),
+            Some(Path::new("/mnt/games/steam"))
+        );
+    }
+
+    #[test_case(0, "1970-01-01T00:00:00Z"; "Epoch")]
+    #[test_case(951_782_400, "2000-02-29T00:00:00Z"; "Leap day")]
+    #[test_case(1_659_357_000, "2022-08-01T12:30:00Z"; "Regular date")]
+    fn format_rfc3339_timestamp(secs: u64, expected: &str) {
+        let time = UNIX_EPOCH + std::time::Duration::from_secs(secs);
+        assert_eq!(format_rfc3339(time), expected);
+    }
+
+    #[test]
+    fn remove_should_keep_order_of_remaining_versions() {
+        let mut managed_versions = ManagedVersions::new(vec![
+            ManagedVersion::from(Version::proton("6.19-GE-1")),
+            ManagedVersion::from(Version::proton("6.20-GE-1")),
+            ManagedVersion::from(Version::proton("6.21-GE-1")),
+        ]);
+        managed_versions.remove(&Version::proton("6.19-GE-1")).unwrap();
+        assert_eq!(
+            managed_versions.versions(),
+            vec![
+                ManagedVersion::from(Version::proton("6.20-GE-1")),
+                ManagedVersion::from(Version::proton("6.21-GE-1")),
+            ]
+        );
+    }
+
+    #[test]
+    fn pin_and_unpin_version() {
+        let version = ManagedVersion::from(Version::proton("6.20-GE-1"));
+        let mut managed_versions = ManagedVersions::new(vec![version]);
+
+        let pinned = managed_versions
+            .set_pinned(&Version::proton("6.20-GE-1"), true)
+            .unwrap();
+        assert!(pinned.pinned());
+        assert!(managed_versions
+            .find_version(&Version::proton("6.20-GE-1"))
+            .unwrap()
+            .pinned());
+
+        managed_versions
+            .set_pinned(&Version::proton("6.20-GE-1"), false)
+            .unwrap();
+        assert!(!managed_versions
+            .find_version(&Version::proton("6.20-GE-1"))
+            .unwrap()
+            .pinned());
+    }
+
+    #[test]
+    fn find_version_with_label() {
+        let mut labeled = Version::proton("6.20-GE-1");
+        labeled.set_label(Some(String::from("patched")));
+        let managed_versions = ManagedVersions::new(vec![ManagedVersion::from(&labeled)]);
+
+        assert!(managed_versions.find_version(&labeled).is_some());
+        assert!(managed_versions.find_version(&Version::proton("6.20-GE-1")).is_none());
+    }
+
+    #[test]
+    fn pin_version_that_does_not_exist() {
+        let mut managed_versions = ManagedVersions::default();
+        assert!(managed_versions
+            .set_pinned(&Version::proton("6.20-GE-1"), true)
+            .is_none());
+    }
+
+    #[test]
+    fn find_version_with_tag_spelling_variant() {
+        let version = ManagedVersion::from(Version::lol("6.16-GE-3-LoL"));
+        let managed_versions = ManagedVersions::new(vec![version.clone()]);
+
+        let found = managed_versions.find_version(&Version::lol("6.16-3-ge-lol"));
+        assert_eq!(found, Some(version));
+        assert_eq!(found.unwrap().tag(), &Tag::from("6.16-GE-3-LoL"));
+        assert!(managed_versions.find_version(&Version::wine("6.16-3-ge-lol")).is_none());
+    }
+
     #[test]
     fn remove_version_that_does_not_exist() {
         let mut managed_versions = ManagedVersions::default();
@@ -367,4 +734,83 @@ mod managed_versions_tests {
         drop(file);
         tmp_dir.close().unwrap();
     }
+
+    #[test]
+    fn read_invalid_json_should_back_up_file() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("managed_versions.json");
+        fs::write(&path, r#"{"versions":[{"type":"Managed"#).unwrap();
+
+        let err = ManagedVersions::from_file(&path).unwrap_err();
+        let err = err.downcast_ref::<CorruptManifestError>().unwrap();
+
+        let backup_path = tmp_dir.join("managed_versions.json.bak");
+        assert_eq!(err.path, path);
+        assert_eq!(err.backup_path, backup_path);
+        assert_eq!(
+            fs::read_to_string(&backup_path).unwrap(),
+            r#"{"versions":[{"type":"Managed"#
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), r#"{"versions":[{"type":"Managed"#);
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test_case("managed_versions.json"; "JSON round trip")]
+    #[test_case("managed_versions.toml"; "TOML round trip")]
+    fn write_and_read_round_trip(file_name: &str) {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join(file_name);
+
+        let managed_versions = ManagedVersions::new(vec![
+            ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1"),
+            ManagedVersion::new("6.20-GE-1", TagKind::wine(), "lutris-ge-6.20-1-x86_64"),
+            ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), "lutris-ge-6.16-3-lol-x86_64"),
+        ]);
+        managed_versions.write_to_file(&path).unwrap();
+
+        let result = ManagedVersions::from_file(&path).unwrap();
+        assert_eq!(result.versions(), managed_versions.versions());
+        for (read, written) in result.versions().iter().zip(managed_versions.versions().iter()) {
+            assert_eq!(read.directory_name(), written.directory_name());
+        }
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn write_to_file_should_replace_existing_file_without_leftovers() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("managed_versions.json");
+        fs::write(&path, "old content").unwrap();
+
+        let managed_versions = ManagedVersions::new(vec![ManagedVersion::new(
+            "6.20-GE-1",
+            TagKind::Proton,
+            "Proton-6.20-GE-1",
+        )]);
+        managed_versions.write_to_file(&path).unwrap();
+
+        let result = ManagedVersions::from_file(&path).unwrap();
+        assert_eq!(result.versions(), managed_versions.versions());
+        let file_names = fs::read_dir(tmp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect::<Vec<_>>();
+        assert_eq!(file_names, vec!["managed_versions.json"]);
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn read_invalid_toml() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path = tmp_dir.join("managed_versions.toml");
+        fs::write(&path, "versions = [").unwrap();
+
+        let result = ManagedVersions::from_file(&path);
+        assert!(result.is_err());
+
+        tmp_dir.close().unwrap();
+    }
 }
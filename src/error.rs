@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+
+use ge_man_lib::error::GithubError;
+
+/// Category of a failed command. Every category exits with its own code, so scripts can react to the cause of an
+/// error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ErrorCategory {
+    General,
+    Network,
+    Filesystem,
+    NotFound,
+    AlreadyExists,
+    InUse,
+}
+
+impl ErrorCategory {
+    /// Category of the given error. The first categorized error in the chain wins. Failed GitHub requests are network
+    /// errors and IO errors are filesystem errors.
+    pub fn of(err: &anyhow::Error) -> Self {
+        for cause in err.chain() {
+            if let Some(err) = cause.downcast_ref::<CommandError>() {
+                return err.category;
+            }
+            if cause.downcast_ref::<GithubError>().is_some() {
+                return ErrorCategory::Network;
+            }
+            if cause.downcast_ref::<io::Error>().is_some() {
+                return ErrorCategory::Filesystem;
+            }
+        }
+        ErrorCategory::General
+    }
+}
+
+impl From<ErrorCategory> for i32 {
+    fn from(category: ErrorCategory) -> Self {
+        // 2 is used by clap for invalid arguments.
+        match category {
+            ErrorCategory::General => 1,
+            ErrorCategory::Network => 3,
+            ErrorCategory::Filesystem => 4,
+            ErrorCategory::NotFound => 5,
+            ErrorCategory::AlreadyExists => 6,
+            ErrorCategory::InUse => 7,
+        }
+    }
+}
+
+/// Error of a command with a category that decides the exit code of GE-Man.
+#[derive(Debug)]
+pub struct CommandError {
+    pub category: ErrorCategory,
+    pub message: String,
+}
+
+impl CommandError {
+    pub fn new<S: Into<String>>(category: ErrorCategory, message: S) -> Self {
+        CommandError {
+            category,
+            message: message.into(),
+        }
+    }
+
+    pub fn network<S: Into<String>>(message: S) -> Self {
+        CommandError::new(ErrorCategory::Network, message)
+    }
+
+    pub fn not_found<S: Into<String>>(message: S) -> Self {
+        CommandError::new(ErrorCategory::NotFound, message)
+    }
+
+    pub fn already_exists<S: Into<String>>(message: S) -> Self {
+        CommandError::new(ErrorCategory::AlreadyExists, message)
+    }
+
+    pub fn in_use<S: Into<String>>(message: S) -> Self {
+        CommandError::new(ErrorCategory::InUse, message)
+    }
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{anyhow, Context};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(anyhow!("Something failed") => ErrorCategory::General; "Plain error")]
+    #[test_case(anyhow!(CommandError::not_found("Version is not managed")) => ErrorCategory::NotFound; "Command error")]
+    #[test_case(
+        anyhow!(CommandError::in_use("Version is in use")).context("Could not remove version") => ErrorCategory::InUse;
+        "Command error with context"
+    )]
+    #[test_case(
+        anyhow!(io::Error::from(io::ErrorKind::NotFound)).context("Could not read") => ErrorCategory::Filesystem;
+        "IO error"
+    )]
+    fn category_of_error(err: anyhow::Error) -> ErrorCategory {
+        ErrorCategory::of(&err)
+    }
+
+    #[test]
+    fn categories_should_have_distinct_exit_codes() {
+        let codes: Vec<i32> = [
+            ErrorCategory::General,
+            ErrorCategory::Network,
+            ErrorCategory::Filesystem,
+            ErrorCategory::NotFound,
+            ErrorCategory::AlreadyExists,
+            ErrorCategory::InUse,
+        ]
+        .into_iter()
+        .map(i32::from)
+        .collect();
+        assert_eq!(codes, vec![1, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn command_error_should_display_message() {
+        let err = anyhow!(CommandError::already_exists("Directory already exists")).context("Could not migrate");
+        assert_eq!(format!("{:#}", err), "Could not migrate: Directory already exists");
+    }
+}
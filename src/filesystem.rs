@@ -1,7 +1,8 @@
+use std::fs;
 use std::io::Read;
 use std::os::unix::ffi::OsStrExt;
-use std::path::Path;
-use std::{fs, io};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, bail, Context};
 use ge_man_lib::archive;
@@ -9,8 +10,10 @@ use ge_man_lib::config::{LutrisConfig, SteamConfig};
 use ge_man_lib::tag::TagKind;
 #[cfg(test)]
 use mockall::{automock, predicate::*};
+use nix::sys::statvfs::statvfs;
 
 use crate::data::ManagedVersion;
+use crate::error::CommandError;
 use crate::path::{
     steam_path, xdg_config_home, xdg_data_home, PathConfiguration, LUTRIS_WINE_RUNNERS_DIR, STEAM_COMP_DIR,
 };
@@ -22,24 +25,256 @@ wine:
   version: VERSION
 "#;
 
+/// Extracted GE versions are roughly two to three times the size of their compressed archive.
+const EXTRACTION_SIZE_FACTOR: u64 = 3;
+
+/// Describes how an application config was changed when applying a version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AppConfigUpdate {
+    /// No config existed, so an initial config using the version was created.
+    Created,
+    /// An existing config was modified to use the version.
+    Modified,
+}
+
+/// Describes how an application config was restored from its backup.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AppConfigRestore {
+    /// The config was replaced with its backup.
+    Restored,
+    /// No backup existed because GE-Man created the config, so the created config was removed.
+    Removed,
+}
+
+/// Check whether a Lutris runner config is the initial config GE-Man writes when no runner config exists.
+fn is_initial_lutris_config(content: &str) -> bool {
+    let (prefix, suffix) = LUTRIS_INITIAL_WINE_RUNNER_CONFIG.split_once("VERSION").unwrap();
+    content.len() > prefix.len() + suffix.len()
+        && content.starts_with(prefix)
+        && content.ends_with(suffix)
+        && !content[prefix.len()..content.len() - suffix.len()].contains('\n')
+}
+
 #[cfg_attr(test, automock)]
 pub trait FilesystemManager {
-    fn setup_version(&self, version: Version, compressed_tar: Box<dyn Read>) -> anyhow::Result<ManagedVersion>;
+    fn setup_version(
+        &self,
+        version: Version,
+        compressed_tar: Box<dyn Read>,
+        steam_root: Option<&Path>,
+    ) -> anyhow::Result<ManagedVersion>;
+    fn reinstall_version(
+        &self,
+        version: &ManagedVersion,
+        compressed_tar: Box<dyn Read>,
+    ) -> anyhow::Result<ManagedVersion>;
     fn remove_version(&self, version: &ManagedVersion) -> anyhow::Result<()>;
-    fn migrate_folder(&self, version: Version, source_path: &Path) -> anyhow::Result<ManagedVersion>;
-    fn apply_to_app_config(&self, version: &ManagedVersion) -> anyhow::Result<()>;
+    fn plan_migration(
+        &self,
+        version: Version,
+        source_path: &Path,
+        dir_name: Option<&str>,
+    ) -> anyhow::Result<MigrationPlan>;
+    fn migrate_folder(
+        &self,
+        version: Version,
+        source_path: &Path,
+        dir_name: Option<&str>,
+    ) -> anyhow::Result<ManagedVersion>;
+    fn apply_to_app_config(&self, version: &ManagedVersion, backups: usize) -> anyhow::Result<AppConfigUpdate>;
+    fn restore_app_config(&self, kind: &TagKind, backup_index: usize) -> anyhow::Result<AppConfigRestore>;
+    fn apply_symlink(&self, version: &ManagedVersion, link_name: &str) -> anyhow::Result<PathBuf>;
+    fn link_to_bottles(&self, version: &ManagedVersion) -> anyhow::Result<PathBuf>;
     fn copy_user_settings(&self, src_version: &ManagedVersion, dst_version: &ManagedVersion) -> anyhow::Result<()>;
+    fn copy_user_settings_from_file(&self, src_path: &Path, dst_version: &ManagedVersion) -> anyhow::Result<()>;
+    fn user_settings_path(&self, version: &ManagedVersion) -> PathBuf;
+    fn list_directory_names(&self, kind: &TagKind) -> anyhow::Result<Vec<String>>;
+    fn list_compat_tool_path_directories(&self, steam_compat_tool_paths: Option<String>) -> Vec<(PathBuf, String)>;
+    fn version_path(&self, version: &ManagedVersion) -> PathBuf;
+    fn version_size(&self, version: &ManagedVersion) -> anyhow::Result<u64>;
+    fn directory_size(&self, path: &Path) -> anyhow::Result<u64>;
+    fn missing_version_files(&self, version: &ManagedVersion) -> anyhow::Result<Vec<String>>;
+    fn ensure_free_space(&self, kind: &TagKind, steam_root: Option<&Path>, archive_size: u64) -> anyhow::Result<()>;
+    fn version_exists(&self, version: &ManagedVersion) -> bool;
+}
+
+/// Available space of the filesystem the given path is located on. The path does not need to exist yet, the nearest
+/// existing ancestor is used instead.
+fn available_space(path: &Path) -> anyhow::Result<u64> {
+    let existing_path = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .ok_or_else(|| anyhow!("No part of '{}' exists", path.display()))?;
+    let stat = statvfs(existing_path).context(format!(
+        "Could not determine the free space of '{}'",
+        existing_path.display()
+    ))?;
+
+    Ok(stat.blocks_available() as u64 * stat.fragment_size() as u64)
+}
+
+/// Files every extracted version of the given kind contains at its top level.
+fn expected_version_files(kind: &TagKind) -> &'static [&'static str] {
+    match kind {
+        TagKind::Proton => &["proton", "compatibilitytool.vdf", "toolmanifest.vdf"],
+        TagKind::Wine { .. } => &["bin/wine"],
+    }
+}
+
+/// Path of the backup with the given index. The newest backup has the index 0 and keeps the original file name, older
+/// backups get their index appended.
+fn numbered_backup_path(backup_path: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        return backup_path.to_path_buf();
+    }
+
+    let mut file_name = backup_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{}", index));
+    backup_path.with_file_name(file_name)
+}
+
+/// Make room for a new backup by shifting the existing backups one index up. Backups which would exceed the given
+/// amount of backups are removed.
+fn rotate_backups(backup_path: &Path, backups: usize) -> anyhow::Result<()> {
+    let mut index = backups.saturating_sub(1);
+    while numbered_backup_path(backup_path, index).exists() {
+        let path = numbered_backup_path(backup_path, index);
+        fs::remove_file(&path).context(format!(r#"Could not remove old backup "{}""#, path.display()))?;
+        index += 1;
+    }
+
+    for index in (0..backups.saturating_sub(1)).rev() {
+        let path = numbered_backup_path(backup_path, index);
+        if path.exists() {
+            let rotated_path = numbered_backup_path(backup_path, index + 1);
+            fs::rename(&path, &rotated_path).context(format!(
+                r#"Could not move backup "{}" to "{}""#,
+                path.display(),
+                rotated_path.display()
+            ))?;
+        }
+    }
+    Ok(())
+}
+
+/// Back up the given config. Without any backups to keep, no backup is created.
+fn backup_app_config(config_path: &Path, backup_path: &Path, backups: usize) -> anyhow::Result<()> {
+    if backups == 0 {
+        return Ok(());
+    }
+
+    rotate_backups(backup_path, backups)?;
+    fs::copy(config_path, backup_path)?;
+    Ok(())
+}
+
+/// Removes the wrapped directory when dropped, so an early return does not leave a half extracted version behind.
+struct RemoveOnDrop(PathBuf);
+
+impl Drop for RemoveOnDrop {
+    fn drop(&mut self) {
+        if self.0.exists() {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+}
+
+/// What a migration does with the source directory.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MigrationAction {
+    /// The source is already located in the Steam or Lutris directory and is only registered.
+    InPlace,
+    /// The source is renamed into the destination because both are on the same device.
+    Move,
+    /// The source is copied into the destination because it is on a different device.
+    Copy,
+}
+
+/// The result of a migration computed without touching the filesystem.
+pub struct MigrationPlan {
+    pub version: ManagedVersion,
+    pub destination: PathBuf,
+    pub action: MigrationAction,
 }
 
 /// Inside this struct it is assumed that all data passed to the methods of this struct contain valid data which
 /// passed clap's or the ui module's validations.
 pub struct FsMng<'a> {
     path_config: &'a dyn PathConfiguration,
+    /// Directory in which archives are extracted before they are moved into place. Defaults to the directory of the
+    /// versions of the kind.
+    pub extract_tmp_dir: Option<PathBuf>,
 }
 
 impl<'a> FsMng<'a> {
     pub fn new(path_config: &'a dyn PathConfiguration) -> Self {
-        FsMng { path_config }
+        FsMng {
+            path_config,
+            extract_tmp_dir: None,
+        }
+    }
+
+    /// Extract a version into a staging directory and move it into place afterwards. The staging directory is located
+    /// next to the other versions, unless an extraction directory is configured. A labeled version gets `_<label>`
+    /// appended to its directory name, so an already extracted version of the same tag is not overwritten. The
+    /// staging directory is removed even if the extraction fails partway.
+    fn extract_version(
+        &self,
+        version: &Version,
+        compressed_tar: Box<dyn Read>,
+        dst_path: &Path,
+    ) -> anyhow::Result<String> {
+        let staging_root = match &self.extract_tmp_dir {
+            Some(dir) if !dir.is_dir() => bail!("Extraction directory '{}' does not exist", dir.display()),
+            Some(dir) => dir.as_path(),
+            None => dst_path,
+        };
+        let staging_name = match version.label() {
+            Some(label) => format!(".ge_man_{}_{}", version.tag(), label),
+            None => format!(".ge_man_{}", version.tag()),
+        };
+        let staging_dir = RemoveOnDrop(staging_root.join(staging_name));
+        log::debug!("Extracting {} into {}", version.tag(), staging_dir.0.display());
+        fs::create_dir_all(&staging_dir.0).context("Failed to create staging directory")?;
+
+        let extracted_location = archive::extract_compressed(version.kind(), compressed_tar, &staging_dir.0)
+            .context("Failed to extract compressed archive")?;
+        let mut directory_name =
+            String::from_utf8_lossy(extracted_location.file_name().unwrap().as_bytes()).into_owned();
+        if let Some(label) = version.label() {
+            directory_name = format!("{}_{}", directory_name, label);
+        }
+
+        let version_path = dst_path.join(&directory_name);
+        if version_path.exists() {
+            bail!(CommandError::already_exists(format!(
+                "Directory '{}' already exists",
+                version_path.display()
+            )));
+        }
+        self.move_or_copy(&extracted_location, &version_path).context(format!(
+            "Could not move extracted version to '{}'",
+            version_path.display()
+        ))?;
+        log::info!("Extracted {} to {}", version.tag(), version_path.display());
+
+        Ok(directory_name)
+    }
+
+    fn directory_names(&self, path: &Path) -> anyhow::Result<Vec<String>> {
+        let mut names = Vec::new();
+        for entry in path
+            .read_dir()
+            .context(format!("Could not read directory '{}'", path.display()))?
+        {
+            let entry = entry?;
+            if entry.path().is_dir() {
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+        names.sort_unstable();
+
+        Ok(names)
     }
 
     fn copy_directory(&self, src: &Path, dst: &Path) -> anyhow::Result<()> {
@@ -58,21 +293,33 @@ impl<'a> FsMng<'a> {
         Ok(())
     }
 
+    /// Directory the versions of the given kind are installed into. A GE Proton version which was added with a Steam
+    /// root lives in the `compatibilitytools.d` directory of that root instead of the default one.
+    fn versions_dir(&self, kind: &TagKind, steam_root: Option<&Path>) -> PathBuf {
+        match (kind, steam_root) {
+            (TagKind::Proton, Some(steam_root)) => steam_root.join("compatibilitytools.d"),
+            (TagKind::Proton, None) => self.path_config.steam_compatibility_tools_dir(steam_path()),
+            (TagKind::Wine { .. }, _) => self.path_config.lutris_runners_dir(xdg_data_home()),
+        }
+    }
+
     fn move_or_copy_directory(&self, version: &ManagedVersion, src_path: &Path) -> anyhow::Result<()> {
-        let dst_path = match version.kind() {
-            TagKind::Proton => self.path_config.steam_compatibility_tools_dir(steam_path()),
-            TagKind::Wine { .. } => self.path_config.lutris_runners_dir(xdg_data_home()),
-        };
-        let dst_path = dst_path.join(version.directory_name());
+        let dst_path = self
+            .versions_dir(version.kind(), version.steam_root())
+            .join(version.directory_name());
+
+        self.move_or_copy(src_path, &dst_path)
+    }
 
+    fn move_or_copy(&self, src_path: &Path, dst_path: &Path) -> anyhow::Result<()> {
         // A rename is used here to move the directory into the destination folder. We could just copy the files but
         // Proton GE releases tend to be 400 MB in size and Wine GE releases about 100 MB.
-        if let Err(err) = fs::rename(src_path, &dst_path) {
+        if let Err(err) = fs::rename(src_path, dst_path) {
             match err.raw_os_error() {
                 // Rename only works when the source and destination are on the same device. In the case that the
                 // destination is a different device the source must be copied to the destination.
                 Some(18) => {
-                    self.copy_directory(src_path, &dst_path).context(format!(
+                    self.copy_directory(src_path, dst_path).context(format!(
                         "Failed to copy source to destination.\n\
                                          Source: {}\n\
                                          Destination: {}\n",
@@ -89,54 +336,176 @@ impl<'a> FsMng<'a> {
 }
 
 impl<'a> FilesystemManager for FsMng<'a> {
-    fn setup_version(&self, version: Version, compressed_tar: Box<dyn Read>) -> anyhow::Result<ManagedVersion> {
-        let dst_path = match version.kind() {
-            TagKind::Proton => self.path_config.steam_compatibility_tools_dir(steam_path()),
-            TagKind::Wine { .. } => self.path_config.lutris_runners_dir(xdg_data_home()),
-        };
-        let extracted_location = archive::extract_compressed(version.kind(), compressed_tar, &dst_path)
-            .context("Failed to extract compressed archive")?;
-
-        let directory_name = String::from_utf8_lossy(extracted_location.file_name().unwrap().as_bytes()).into_owned();
+    /// Extract a version into the directory of its application. A given Steam root replaces the default Steam root
+    /// for a GE Proton version, its `compatibilitytools.d` directory is created if it does not exist yet. The Steam
+    /// root is stored with the version, so later commands find the version there.
+    fn setup_version(
+        &self,
+        version: Version,
+        compressed_tar: Box<dyn Read>,
+        steam_root: Option<&Path>,
+    ) -> anyhow::Result<ManagedVersion> {
+        let steam_root = steam_root.filter(|_| version.kind().eq(&TagKind::Proton));
+        let dst_path = self.versions_dir(version.kind(), steam_root);
+        if let Some(steam_root) = steam_root {
+            fs::create_dir_all(&dst_path).context(format!(
+                r#"Failed to create directory "compatibilitytools.d" in {}"#,
+                steam_root.display()
+            ))?;
+        }
+        let directory_name = self.extract_version(&version, compressed_tar, &dst_path)?;
 
         let mut version = ManagedVersion::from(version);
         version.set_directory_name(directory_name);
+        version.set_steam_root(steam_root.map(Path::to_path_buf));
 
         Ok(version)
     }
 
+    /// Replace the directory of a managed version with a fresh extraction. The old directory is kept aside until the
+    /// extraction succeeded and restored otherwise. The new directory gets the directory name of the managed version.
+    fn reinstall_version(
+        &self,
+        version: &ManagedVersion,
+        compressed_tar: Box<dyn Read>,
+    ) -> anyhow::Result<ManagedVersion> {
+        let version_path = self.version_path(version);
+        let dst_path = version_path.parent().unwrap().to_path_buf();
+        let backup_path = dst_path.join(format!(".ge_man_old_{}", version.directory_name()));
+
+        let has_backup = version_path.exists();
+        if has_backup {
+            fs::rename(&version_path, &backup_path).context(format!(
+                "Could not move '{}' aside for reinstalling",
+                version_path.display()
+            ))?;
+        }
+
+        let mut new_version = Version::new(version.tag().clone(), *version.kind());
+        new_version.set_label(version.label().cloned());
+        let result = self
+            .extract_version(&new_version, compressed_tar, &dst_path)
+            .and_then(|directory_name| {
+                if directory_name.eq(version.directory_name()) {
+                    return Ok(());
+                }
+                fs::rename(dst_path.join(&directory_name), &version_path).context(format!(
+                    "Could not rename '{}' to '{}'",
+                    directory_name,
+                    version.directory_name()
+                ))
+            });
+
+        match result {
+            Ok(()) if has_backup => fs::remove_dir_all(&backup_path)
+                .context(format!("Could not remove old directory '{}'", backup_path.display()))?,
+            Ok(()) => (),
+            Err(err) => {
+                if has_backup {
+                    fs::rename(&backup_path, &version_path).context(format!(
+                        "Could not restore '{}' after failed reinstall",
+                        version_path.display()
+                    ))?;
+                }
+                return Err(err);
+            }
+        }
+
+        Ok(version.clone())
+    }
+
     fn remove_version(&self, version: &ManagedVersion) -> anyhow::Result<()> {
-        let path = match version.kind() {
-            TagKind::Proton => self.path_config.steam_compatibility_tools_dir(steam_path()),
-            TagKind::Wine { .. } => self.path_config.lutris_runners_dir(xdg_data_home()),
-        };
-        let path = path.join(version.directory_name());
+        let path = self.version_path(version);
 
-        fs::remove_dir_all(&path).context(format!("Could not remove directory '{}'", path.display()))
+        fs::remove_dir_all(&path).context(format!("Could not remove directory '{}'", path.display()))?;
+
+        // Bottles would otherwise list a runner which does not exist anymore.
+        let bottles_link = self
+            .path_config
+            .bottles_runners_dir(xdg_data_home())
+            .join(version.directory_name());
+        if fs::read_link(&bottles_link).map_or(false, |target| target == path) {
+            fs::remove_file(&bottles_link).context(format!("Could not remove symlink '{}'", bottles_link.display()))?;
+        }
+
+        Ok(())
     }
 
-    fn migrate_folder(&self, version: Version, source_path: &Path) -> anyhow::Result<ManagedVersion> {
+    /// Plan the migration of the source directory. A directory outside of the Steam and Lutris directories is named
+    /// `GEH_<kind>_<tag>` in its destination, unless a directory name is given. A directory which already is in the
+    /// Steam or Lutris directory is registered in place, or renamed if a different directory name is given.
+    fn plan_migration(
+        &self,
+        version: Version,
+        source_path: &Path,
+        dir_name: Option<&str>,
+    ) -> anyhow::Result<MigrationPlan> {
+        if !source_path.is_dir() {
+            bail!("Source path '{}' is not a directory", source_path.display());
+        }
+
         let mut managed_version = ManagedVersion::from(version);
-        let dir_name = format!("GEH_{}_{}", managed_version.kind(), managed_version.tag());
+        let in_place = match source_path.parent() {
+            Some(parent) => parent.ends_with(STEAM_COMP_DIR) || parent.ends_with(LUTRIS_WINE_RUNNERS_DIR),
+            None => false,
+        };
+        let source_name = String::from_utf8_lossy(source_path.file_name().unwrap().as_bytes()).into_owned();
+
+        if in_place && dir_name.map_or(true, |name| name.eq(&source_name)) {
+            managed_version.set_directory_name(source_name);
+            return Ok(MigrationPlan {
+                version: managed_version,
+                destination: source_path.to_path_buf(),
+                action: MigrationAction::InPlace,
+            });
+        }
+
+        let dir_name = match dir_name {
+            Some(name) => String::from(name),
+            None => format!("GEH_{}_{}", managed_version.kind(), managed_version.tag()),
+        };
         managed_version.set_directory_name(dir_name);
 
-        match source_path.parent() {
-            Some(parent) => {
-                if parent.ends_with(STEAM_COMP_DIR) || parent.ends_with(LUTRIS_WINE_RUNNERS_DIR) {
-                    managed_version
-                        .set_directory_name(String::from_utf8_lossy(source_path.file_name().unwrap().as_bytes()));
-                    return Ok(managed_version);
-                } else {
-                    self.move_or_copy_directory(&managed_version, source_path)?;
-                }
-            }
-            None => self.move_or_copy_directory(&managed_version, source_path)?,
+        let dst_dir = match managed_version.kind() {
+            TagKind::Proton => self.path_config.steam_compatibility_tools_dir(steam_path()),
+            TagKind::Wine { .. } => self.path_config.lutris_runners_dir(xdg_data_home()),
+        };
+        let destination = dst_dir.join(managed_version.directory_name());
+        if destination.exists() {
+            bail!(CommandError::already_exists(format!(
+                "Directory '{}' already exists",
+                destination.display()
+            )));
+        }
+        let same_device = fs::metadata(source_path)?.dev() == fs::metadata(&dst_dir)?.dev();
+        let action = if same_device {
+            MigrationAction::Move
+        } else {
+            MigrationAction::Copy
+        };
+
+        Ok(MigrationPlan {
+            destination,
+            version: managed_version,
+            action,
+        })
+    }
+
+    fn migrate_folder(
+        &self,
+        version: Version,
+        source_path: &Path,
+        dir_name: Option<&str>,
+    ) -> anyhow::Result<ManagedVersion> {
+        let plan = self.plan_migration(version, source_path, dir_name)?;
+        if plan.action != MigrationAction::InPlace {
+            self.move_or_copy_directory(&plan.version, source_path)?;
         }
 
-        Ok(managed_version)
+        Ok(plan.version)
     }
 
-    fn apply_to_app_config(&self, version: &ManagedVersion) -> anyhow::Result<()> {
+    fn apply_to_app_config(&self, version: &ManagedVersion, backups: usize) -> anyhow::Result<AppConfigUpdate> {
         match version.kind() {
             TagKind::Proton => {
                 let steam_cfg_path = self.path_config.steam_config(steam_path());
@@ -144,7 +513,7 @@ impl<'a> FilesystemManager for FsMng<'a> {
                     .path_config
                     .app_config_backup_file(xdg_config_home(), version.kind());
 
-                fs::copy(&steam_cfg_path, &backup_path).context(format!(
+                backup_app_config(&steam_cfg_path, &backup_path, backups).context(format!(
                     r#"Could not create backup of Steam config from "{}" to "{}" "#,
                     steam_cfg_path.display(),
                     backup_path.display()
@@ -155,6 +524,8 @@ impl<'a> FilesystemManager for FsMng<'a> {
 
                 let new_config: Vec<u8> = config.into();
                 fs::write(steam_cfg_path, new_config)?;
+
+                Ok(AppConfigUpdate::Modified)
             }
             TagKind::Wine { .. } => {
                 let runner_cfg_path = self.path_config.lutris_wine_runner_config(xdg_config_home());
@@ -162,46 +533,116 @@ impl<'a> FilesystemManager for FsMng<'a> {
                     .path_config
                     .app_config_backup_file(xdg_config_home(), version.kind());
 
-                let copy_result = fs::copy(&runner_cfg_path, &backup_path);
-
-                if let Err(io_err) = copy_result {
-                    if let io::ErrorKind::NotFound = io_err.kind() {
-                        fs::write(
-                            runner_cfg_path,
-                            LUTRIS_INITIAL_WINE_RUNNER_CONFIG.replace("VERSION", version.directory_name()),
-                        )
-                        .context("Failed to create initial Wine runner configuration for Lutris")?;
-                    } else {
-                        return Err(anyhow!(io_err)).context(format!(
-                            r#"Could not create backup of Wine runner config from "{}" to "{}""#,
-                            runner_cfg_path.display(),
-                            backup_path.display()
-                        ));
-                    }
-                } else {
-                    let mut config = LutrisConfig::create_copy(&runner_cfg_path)?;
-                    config.set_wine_version(version.directory_name());
-
-                    let new_config: Vec<u8> = config.into();
-                    fs::write(runner_cfg_path, new_config)?;
-                };
+                if !runner_cfg_path.exists() {
+                    fs::write(
+                        runner_cfg_path,
+                        LUTRIS_INITIAL_WINE_RUNNER_CONFIG.replace("VERSION", version.directory_name()),
+                    )
+                    .context("Failed to create initial Wine runner configuration for Lutris")?;
+
+                    return Ok(AppConfigUpdate::Created);
+                }
+
+                backup_app_config(&runner_cfg_path, &backup_path, backups).context(format!(
+                    r#"Could not create backup of Wine runner config from "{}" to "{}""#,
+                    runner_cfg_path.display(),
+                    backup_path.display()
+                ))?;
+
+                let mut config = LutrisConfig::create_copy(&runner_cfg_path)?;
+                config.set_wine_version(version.directory_name());
+
+                let new_config: Vec<u8> = config.into();
+                fs::write(runner_cfg_path, new_config)?;
+
+                Ok(AppConfigUpdate::Modified)
+            }
+        }
+    }
+
+    fn restore_app_config(&self, kind: &TagKind, backup_index: usize) -> anyhow::Result<AppConfigRestore> {
+        let backup_path = numbered_backup_path(
+            &self.path_config.app_config_backup_file(xdg_config_home(), kind),
+            backup_index,
+        );
+        let config_path = match kind {
+            TagKind::Proton => self.path_config.steam_config(steam_path()),
+            TagKind::Wine { .. } => self.path_config.lutris_wine_runner_config(xdg_config_home()),
+        };
+
+        if !backup_path.exists() {
+            // Without a backup the Lutris runner config was created by GE-Man, so there is nothing to go back to.
+            if let (TagKind::Wine { .. }, 0) = (kind, backup_index) {
+                let content = fs::read_to_string(&config_path).unwrap_or_default();
+                if is_initial_lutris_config(&content) {
+                    fs::remove_file(&config_path).context(format!(
+                        r#"Could not remove Wine runner config "{}""#,
+                        config_path.display()
+                    ))?;
+                    return Ok(AppConfigRestore::Removed);
+                }
             }
+            bail!(r#"No backup exists at "{}""#, backup_path.display());
         }
 
-        Ok(())
+        fs::copy(&backup_path, &config_path).context(format!(
+            r#"Could not restore backup from "{}" to "{}""#,
+            backup_path.display(),
+            config_path.display()
+        ))?;
+        Ok(AppConfigRestore::Restored)
+    }
+
+    fn apply_symlink(&self, version: &ManagedVersion, link_name: &str) -> anyhow::Result<PathBuf> {
+        let version_path = self.version_path(version);
+        if !version_path.is_dir() {
+            bail!("Version directory '{}' does not exist", version_path.display());
+        }
+
+        let link_path = version_path.with_file_name(link_name);
+        if let Ok(metadata) = fs::symlink_metadata(&link_path) {
+            if !metadata.file_type().is_symlink() {
+                bail!("'{}' already exists and is not a symlink", link_path.display());
+            }
+        }
+
+        // Renaming a new symlink over the old one replaces it atomically, so the link never points nowhere.
+        let tmp_link_path = version_path.with_file_name(format!(".ge_man_{}", link_name));
+        if fs::symlink_metadata(&tmp_link_path).is_ok() {
+            fs::remove_file(&tmp_link_path)?;
+        }
+        std::os::unix::fs::symlink(version.directory_name(), &tmp_link_path)
+            .context(format!("Could not create symlink '{}'", tmp_link_path.display()))?;
+        fs::rename(&tmp_link_path, &link_path)
+            .context(format!("Could not replace symlink '{}'", link_path.display()))?;
+
+        Ok(link_path)
+    }
+
+    fn link_to_bottles(&self, version: &ManagedVersion) -> anyhow::Result<PathBuf> {
+        let version_path = self.version_path(version);
+        if !version_path.is_dir() {
+            bail!("Version directory '{}' does not exist", version_path.display());
+        }
+
+        let runners_dir = self.path_config.bottles_runners_dir(xdg_data_home());
+        fs::create_dir_all(&runners_dir).context("Could not create Bottles runners directory")?;
+
+        let link_path = runners_dir.join(version.directory_name());
+        match fs::symlink_metadata(&link_path) {
+            Ok(metadata) if metadata.file_type().is_symlink() => fs::remove_file(&link_path)?,
+            Ok(_) => bail!("'{}' already exists and is not a symlink", link_path.display()),
+            Err(_) => {}
+        }
+        std::os::unix::fs::symlink(&version_path, &link_path)
+            .context(format!("Could not create symlink '{}'", link_path.display()))?;
+
+        Ok(link_path)
     }
 
     fn copy_user_settings(&self, src_version: &ManagedVersion, dst_version: &ManagedVersion) -> anyhow::Result<()> {
-        let src_path = self
-            .path_config
-            .steam_compatibility_tools_dir(steam_path())
-            .join(src_version.directory_name())
-            .join(USER_SETTINGS_PY);
-        let dst_path = self
-            .path_config
-            .steam_compatibility_tools_dir(steam_path())
-            .join(dst_version.directory_name())
-            .join(USER_SETTINGS_PY);
+        let src_path = self.user_settings_path(src_version);
+        let dst_path = self.user_settings_path(dst_version);
 
         fs::copy(src_path, dst_path).context(format!(
             "Could not copy user_settings.py from {} to {}",
@@ -209,17 +650,129 @@ impl<'a> FilesystemManager for FsMng<'a> {
         ))?;
         Ok(())
     }
+
+    fn copy_user_settings_from_file(&self, src_path: &Path, dst_version: &ManagedVersion) -> anyhow::Result<()> {
+        let dst_path = self.user_settings_path(dst_version);
+
+        fs::copy(src_path, dst_path).context(format!("Could not copy {} to {}", src_path.display(), dst_version))?;
+        Ok(())
+    }
+
+    fn user_settings_path(&self, version: &ManagedVersion) -> PathBuf {
+        self.versions_dir(&TagKind::Proton, version.steam_root())
+            .join(version.directory_name())
+            .join(USER_SETTINGS_PY)
+    }
+
+    fn list_directory_names(&self, kind: &TagKind) -> anyhow::Result<Vec<String>> {
+        let path = match kind {
+            TagKind::Proton => self.path_config.steam_compatibility_tools_dir(steam_path()),
+            TagKind::Wine { .. } => self.path_config.lutris_runners_dir(xdg_data_home()),
+        };
+
+        self.directory_names(&path)
+    }
+
+    fn version_path(&self, version: &ManagedVersion) -> PathBuf {
+        self.versions_dir(version.kind(), version.steam_root())
+            .join(version.directory_name())
+    }
+
+    fn version_size(&self, version: &ManagedVersion) -> anyhow::Result<u64> {
+        let path = self.version_path(version);
+
+        self.directory_size(&path)
+            .context(format!("Could not calculate the size of '{}'", path.display()))
+    }
+
+    fn directory_size(&self, path: &Path) -> anyhow::Result<u64> {
+        let mut size = 0;
+        for entry in path.read_dir()? {
+            let entry = entry?;
+            let metadata = entry.path().symlink_metadata()?;
+            if metadata.is_dir() {
+                size += self.directory_size(&entry.path())?;
+            } else {
+                size += metadata.len();
+            }
+        }
+
+        Ok(size)
+    }
+
+    fn ensure_free_space(&self, kind: &TagKind, steam_root: Option<&Path>, archive_size: u64) -> anyhow::Result<()> {
+        let path = self.versions_dir(kind, steam_root);
+
+        let required = archive_size.saturating_mul(EXTRACTION_SIZE_FACTOR);
+        // With a separate extraction directory the version takes up space in both places until it is moved.
+        for path in std::iter::once(&path).chain(self.extract_tmp_dir.as_ref()) {
+            let available = available_space(path)?;
+            if available < required {
+                let missing_mib = (required - available + 1024 * 1024 - 1) / (1024 * 1024);
+                bail!(
+                    "Not enough free space in '{}' to extract the archive: {} MiB more are required",
+                    path.display(),
+                    missing_mib
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn version_exists(&self, version: &ManagedVersion) -> bool {
+        self.version_path(version).is_dir()
+    }
+
+    fn missing_version_files(&self, version: &ManagedVersion) -> anyhow::Result<Vec<String>> {
+        let path = self.version_path(version);
+        if !path.is_dir() {
+            bail!("Version directory '{}' does not exist", path.display());
+        }
+
+        // An empty file is as useless as a missing one, so both are reported.
+        let missing = expected_version_files(version.kind())
+            .iter()
+            .filter(|file| match fs::metadata(path.join(file)) {
+                Ok(meta) => meta.len() == 0,
+                Err(_) => true,
+            })
+            .map(|file| file.to_string())
+            .collect();
+
+        Ok(missing)
+    }
+
+    fn list_compat_tool_path_directories(&self, steam_compat_tool_paths: Option<String>) -> Vec<(PathBuf, String)> {
+        let steam_compat_tool_paths = match steam_compat_tool_paths {
+            Some(paths) => paths,
+            None => return Vec::new(),
+        };
+
+        // Steam ignores entries that do not exist, so unreadable paths are skipped as well.
+        steam_compat_tool_paths
+            .split(':')
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .flat_map(|path| {
+                let names = self.directory_names(&path).unwrap_or_default();
+                names.into_iter().map(move |name| (path.clone(), name))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::fs::File;
+    use std::io;
     use std::io::BufReader;
     use std::path::PathBuf;
 
     use assert_fs::prelude::{PathAssert, PathChild};
     use assert_fs::TempDir;
     use ge_man_lib::tag::Tag;
+    use test_case::test_case;
 
     use super::*;
 
@@ -261,7 +814,9 @@ mod tests {
 
         let compressed_tar = BufReader::new(File::open("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap());
         let version = Version::new(tag.clone(), kind.clone());
-        let managed_version = fs_manager.setup_version(version, Box::new(compressed_tar)).unwrap();
+        let managed_version = fs_manager
+            .setup_version(version, Box::new(compressed_tar), None)
+            .unwrap();
 
         assert_eq!(managed_version.tag(), &Tag::from(tag));
         assert_eq!(managed_version.kind(), &kind);
@@ -276,127 +831,356 @@ mod tests {
     }
 
     #[test]
-    fn setup_wine_version() {
-        let tag = String::from("6.20-GE-1");
-        let kind = TagKind::wine();
-        let dir_name = "Wine-6.20-GE-1";
-
+    fn setup_proton_version_with_steam_root_should_install_into_its_compatibility_tools_dir() {
         let tmp_dir = TempDir::new().unwrap();
         let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
-        fs::create_dir_all(path_config.lutris_runners_dir(None)).unwrap();
+        fs::create_dir_all(path_config.steam_compatibility_tools_dir(None)).unwrap();
+        let steam_root = tmp_dir.child("other-steam");
+        steam_root.create_dir_all().unwrap();
 
         let fs_manager = FsMng::new(&path_config);
 
-        let compressed_tar = BufReader::new(File::open("test_resources/assets/Wine-6.20-GE-1.tar.xz").unwrap());
-        let version = Version::new(tag.clone(), kind.clone());
-        let managed_version = fs_manager.setup_version(version, Box::new(compressed_tar)).unwrap();
-
-        assert_eq!(managed_version.tag(), &Tag::from(tag));
-        assert_eq!(managed_version.kind(), &kind);
-        assert_eq!(managed_version.directory_name(), &dir_name);
-        tmp_dir
-            .child(".local/share/lutris/runners/wine")
-            .child(&dir_name)
+        let compressed_tar = BufReader::new(File::open("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap());
+        let managed_version = fs_manager
+            .setup_version(
+                Version::proton("6.20-GE-1"),
+                Box::new(compressed_tar),
+                Some(steam_root.path()),
+            )
+            .unwrap();
+
+        assert_eq!(managed_version.directory_name(), "Proton-6.20-GE-1");
+        assert_eq!(managed_version.steam_root(), Some(steam_root.path()));
+        assert_eq!(
+            fs_manager.version_path(&managed_version),
+            steam_root.join("compatibilitytools.d/Proton-6.20-GE-1")
+        );
+        steam_root
+            .child("compatibilitytools.d/Proton-6.20-GE-1")
             .assert(predicates::path::exists());
+        tmp_dir
+            .child(".steam/root/compatibilitytools.d/Proton-6.20-GE-1")
+            .assert(predicates::path::missing());
 
         drop(fs_manager);
         tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn setup_wine_lol_version() {
-        let tag = String::from("6.20-GE-1");
-        let kind = TagKind::lol();
-        let dir_name = "Wine-6.20-GE-1-LoL";
-
+    fn setup_version_with_extract_tmp_dir_should_extract_there_and_move_into_place() {
         let tmp_dir = TempDir::new().unwrap();
         let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
-        fs::create_dir_all(path_config.lutris_runners_dir(None)).unwrap();
+        fs::create_dir_all(path_config.steam_compatibility_tools_dir(None)).unwrap();
+        let extract_dir = tmp_dir.child("extract");
+        extract_dir.create_dir_all().unwrap();
 
-        let fs_manager = FsMng::new(&path_config);
+        let mut fs_manager = FsMng::new(&path_config);
+        fs_manager.extract_tmp_dir = Some(extract_dir.to_path_buf());
 
-        let compressed_tar = BufReader::new(File::open("test_resources/assets/Wine-6.20-GE-1-LoL.tar.xz").unwrap());
-        let version = Version::new(tag.clone(), kind.clone());
-        let managed_version = fs_manager.setup_version(version, Box::new(compressed_tar)).unwrap();
+        let compressed_tar = BufReader::new(File::open("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap());
+        let managed_version = fs_manager
+            .setup_version(Version::proton("6.20-GE-1"), Box::new(compressed_tar), None)
+            .unwrap();
 
-        assert_eq!(managed_version.tag(), &Tag::from(tag));
-        assert_eq!(managed_version.kind(), &kind);
-        assert_eq!(managed_version.directory_name(), &dir_name);
+        assert_eq!(managed_version.directory_name(), "Proton-6.20-GE-1");
         tmp_dir
-            .child(".local/share/lutris/runners/wine")
-            .child(&dir_name)
+            .child(".steam/root/compatibilitytools.d/Proton-6.20-GE-1")
             .assert(predicates::path::exists());
+        assert_eq!(extract_dir.read_dir().unwrap().count(), 0);
 
         drop(fs_manager);
         tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn remove_proton_version() {
-        let tag = String::from("6.20-GE-1");
-        let dir_name = String::from("Proton-6.20-GE-1");
-        let kind = TagKind::Proton;
-
+    fn setup_version_with_missing_extract_tmp_dir_should_fail() {
         let tmp_dir = TempDir::new().unwrap();
         let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
-        std::fs::create_dir_all(path_config.steam_compatibility_tools_dir(None).join(&dir_name)).unwrap();
+        fs::create_dir_all(path_config.steam_compatibility_tools_dir(None)).unwrap();
+        let extract_dir = tmp_dir.join("missing");
 
-        let fs_manager = FsMng::new(&path_config);
+        let mut fs_manager = FsMng::new(&path_config);
+        fs_manager.extract_tmp_dir = Some(extract_dir.clone());
 
-        let version = ManagedVersion::new(Tag::from(tag), kind, dir_name.clone());
-        fs_manager.remove_version(&version).unwrap();
+        let compressed_tar = BufReader::new(File::open("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap());
+        let err = fs_manager
+            .setup_version(Version::proton("6.20-GE-1"), Box::new(compressed_tar), None)
+            .unwrap_err();
 
-        tmp_dir
-            .child(".local/share/game-compatibility-manager/versions/proton-ge")
-            .child(&dir_name)
-            .assert(predicates::path::missing());
+        assert_eq!(
+            err.to_string(),
+            format!("Extraction directory '{}' does not exist", extract_dir.display())
+        );
+        assert!(!extract_dir.exists());
 
         drop(fs_manager);
         tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn remove_wine_version() {
-        let tag = String::from("6.20-GE-1");
-        let dir_name = String::from("Wine-6.20-GE-1");
-        let kind = TagKind::wine();
-
+    fn setup_version_with_truncated_archive_should_remove_partial_extraction() {
         let tmp_dir = TempDir::new().unwrap();
         let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
-        std::fs::create_dir_all(path_config.lutris_runners_dir(None).join(&dir_name)).unwrap();
+        fs::create_dir_all(path_config.steam_compatibility_tools_dir(None)).unwrap();
 
         let fs_manager = FsMng::new(&path_config);
 
-        let version = ManagedVersion::new(Tag::from(tag), kind, dir_name.clone());
-        fs_manager.remove_version(&version).unwrap();
+        let mut archive = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
+        archive.truncate(archive.len() / 2);
+        let result = fs_manager.setup_version(Version::proton("6.20-GE-1"), Box::new(io::Cursor::new(archive)), None);
 
-        tmp_dir
-            .child(".local/share/game-compatibility-manager/versions/wine-ge")
-            .child(&dir_name)
-            .assert(predicates::path::missing());
+        assert!(result.is_err());
+        let compat_tools_dir = path_config.steam_compatibility_tools_dir(None);
+        assert_eq!(compat_tools_dir.read_dir().unwrap().count(), 0);
 
         drop(fs_manager);
         tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn remove_wine_lol_version() {
-        let tag = String::from("6.20-GE-1-LoL");
-        let dir_name = String::from("Wine-6.20-GE-1-LoL");
-        let kind = TagKind::lol();
-
+    fn reinstall_version_should_replace_version_directory() {
         let tmp_dir = TempDir::new().unwrap();
         let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
-        std::fs::create_dir_all(path_config.lutris_runners_dir(None).join(&dir_name)).unwrap();
+        let version_dir = path_config.steam_compatibility_tools_dir(None).join("Proton-6.20-GE-1");
+        fs::create_dir_all(&version_dir).unwrap();
+        fs::write(version_dir.join("corrupt"), "").unwrap();
 
         let fs_manager = FsMng::new(&path_config);
 
-        let version = ManagedVersion::new(Tag::from(tag), kind, dir_name.clone());
-        fs_manager.remove_version(&version).unwrap();
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        let compressed_tar = BufReader::new(File::open("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap());
+        let reinstalled = fs_manager
+            .reinstall_version(&version, Box::new(compressed_tar))
+            .unwrap();
+
+        assert_eq!(reinstalled.directory_name(), "Proton-6.20-GE-1");
+        assert!(version_dir.exists());
+        assert!(!version_dir.join("corrupt").exists());
+        assert_eq!(
+            path_config
+                .steam_compatibility_tools_dir(None)
+                .read_dir()
+                .unwrap()
+                .count(),
+            1
+        );
 
-        tmp_dir
-            .child(".local/share/game-compatibility-manager/versions/wine-ge")
-            .child(&dir_name)
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn reinstall_version_with_truncated_archive_should_restore_old_directory() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let version_dir = path_config.steam_compatibility_tools_dir(None).join("Proton-6.20-GE-1");
+        fs::create_dir_all(&version_dir).unwrap();
+        fs::write(version_dir.join("proton"), "").unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+
+        let mut archive = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
+        archive.truncate(archive.len() / 2);
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        let result = fs_manager.reinstall_version(&version, Box::new(io::Cursor::new(archive)));
+
+        assert!(result.is_err());
+        assert!(version_dir.join("proton").exists());
+        assert_eq!(
+            path_config
+                .steam_compatibility_tools_dir(None)
+                .read_dir()
+                .unwrap()
+                .count(),
+            1
+        );
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn setup_labeled_proton_version_next_to_unlabeled_version() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        fs::create_dir_all(path_config.steam_compatibility_tools_dir(None)).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+
+        let compressed_tar = BufReader::new(File::open("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap());
+        fs_manager
+            .setup_version(Version::proton("6.20-GE-1"), Box::new(compressed_tar), None)
+            .unwrap();
+
+        let compressed_tar = BufReader::new(File::open("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap());
+        let mut version = Version::proton("6.20-GE-1");
+        version.set_label(Some(String::from("patched")));
+        let managed_version = fs_manager
+            .setup_version(version, Box::new(compressed_tar), None)
+            .unwrap();
+
+        assert_eq!(managed_version.label(), Some(&String::from("patched")));
+        assert_eq!(managed_version.directory_name(), "Proton-6.20-GE-1_patched");
+        let compat_tools_dir = tmp_dir.child(".steam/root/compatibilitytools.d");
+        compat_tools_dir
+            .child("Proton-6.20-GE-1")
+            .assert(predicates::path::exists());
+        compat_tools_dir
+            .child("Proton-6.20-GE-1_patched")
+            .assert(predicates::path::exists());
+        compat_tools_dir
+            .child(".ge_man_6.20-GE-1_patched")
+            .assert(predicates::path::missing());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn setup_wine_version() {
+        let tag = String::from("6.20-GE-1");
+        let kind = TagKind::wine();
+        let dir_name = "Wine-6.20-GE-1";
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        fs::create_dir_all(path_config.lutris_runners_dir(None)).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+
+        let compressed_tar = BufReader::new(File::open("test_resources/assets/Wine-6.20-GE-1.tar.xz").unwrap());
+        let version = Version::new(tag.clone(), kind.clone());
+        let managed_version = fs_manager
+            .setup_version(version, Box::new(compressed_tar), None)
+            .unwrap();
+
+        assert_eq!(managed_version.tag(), &Tag::from(tag));
+        assert_eq!(managed_version.kind(), &kind);
+        assert_eq!(managed_version.directory_name(), &dir_name);
+        tmp_dir
+            .child(".local/share/lutris/runners/wine")
+            .child(&dir_name)
+            .assert(predicates::path::exists());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn setup_wine_lol_version() {
+        let tag = String::from("6.20-GE-1");
+        let kind = TagKind::lol();
+        let dir_name = "Wine-6.20-GE-1-LoL";
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        fs::create_dir_all(path_config.lutris_runners_dir(None)).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+
+        let compressed_tar = BufReader::new(File::open("test_resources/assets/Wine-6.20-GE-1-LoL.tar.xz").unwrap());
+        let version = Version::new(tag.clone(), kind.clone());
+        let managed_version = fs_manager
+            .setup_version(version, Box::new(compressed_tar), None)
+            .unwrap();
+
+        assert_eq!(managed_version.tag(), &Tag::from(tag));
+        assert_eq!(managed_version.kind(), &kind);
+        assert_eq!(managed_version.directory_name(), &dir_name);
+        tmp_dir
+            .child(".local/share/lutris/runners/wine")
+            .child(&dir_name)
+            .assert(predicates::path::exists());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn remove_proton_version() {
+        let tag = String::from("6.20-GE-1");
+        let dir_name = String::from("Proton-6.20-GE-1");
+        let kind = TagKind::Proton;
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        std::fs::create_dir_all(path_config.steam_compatibility_tools_dir(None).join(&dir_name)).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+
+        let version = ManagedVersion::new(Tag::from(tag), kind, dir_name.clone());
+        fs_manager.remove_version(&version).unwrap();
+
+        tmp_dir
+            .child(".local/share/game-compatibility-manager/versions/proton-ge")
+            .child(&dir_name)
+            .assert(predicates::path::missing());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn remove_proton_version_with_steam_root() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let version_dir = tmp_dir.child("other-steam/compatibilitytools.d/Proton-6.20-GE-1");
+        version_dir.create_dir_all().unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+
+        let mut version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        version.set_steam_root(Some(tmp_dir.join("other-steam")));
+        fs_manager.remove_version(&version).unwrap();
+
+        version_dir.assert(predicates::path::missing());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn remove_wine_version() {
+        let tag = String::from("6.20-GE-1");
+        let dir_name = String::from("Wine-6.20-GE-1");
+        let kind = TagKind::wine();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        std::fs::create_dir_all(path_config.lutris_runners_dir(None).join(&dir_name)).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+
+        let version = ManagedVersion::new(Tag::from(tag), kind, dir_name.clone());
+        fs_manager.remove_version(&version).unwrap();
+
+        tmp_dir
+            .child(".local/share/game-compatibility-manager/versions/wine-ge")
+            .child(&dir_name)
+            .assert(predicates::path::missing());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn remove_wine_lol_version() {
+        let tag = String::from("6.20-GE-1-LoL");
+        let dir_name = String::from("Wine-6.20-GE-1-LoL");
+        let kind = TagKind::lol();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        std::fs::create_dir_all(path_config.lutris_runners_dir(None).join(&dir_name)).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+
+        let version = ManagedVersion::new(Tag::from(tag), kind, dir_name.clone());
+        fs_manager.remove_version(&version).unwrap();
+
+        tmp_dir
+            .child(".local/share/game-compatibility-manager/versions/wine-ge")
+            .child(&dir_name)
             .assert(predicates::path::missing());
 
         drop(fs_manager);
@@ -413,7 +1197,7 @@ mod tests {
         let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
         let fs_mng = FsMng::new(&path_cfg);
 
-        let version = fs_mng.migrate_folder(version, &source_path).unwrap();
+        let version = fs_mng.migrate_folder(version, &source_path, None).unwrap();
         assert_eq!(version.tag(), &Tag::from("6.20-GE-1"));
         assert_eq!(version.kind(), &TagKind::Proton);
         assert_eq!(version.directory_name(), &String::from("Proton-6.20-GE-1"));
@@ -440,7 +1224,7 @@ mod tests {
         let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
         let fs_mng = FsMng::new(&path_cfg);
 
-        let version = fs_mng.migrate_folder(version, &source_path).unwrap();
+        let version = fs_mng.migrate_folder(version, &source_path, None).unwrap();
         assert_eq!(version.tag(), &Tag::from("6.20-GE-1"));
         assert_eq!(version.kind(), &TagKind::Proton);
         assert_eq!(version.directory_name(), &String::from("GEH_PROTON_6.20-GE-1"));
@@ -456,6 +1240,52 @@ mod tests {
         tmp_dir.close().unwrap();
     }
 
+    #[test]
+    fn plan_migration_should_not_touch_the_source() {
+        let tmp_dir = TempDir::new().unwrap();
+        let source_path = PathBuf::from(tmp_dir.join("some/dir/Proton-6.20-GE-1"));
+        let version = Version::new("6.20-GE-1", TagKind::Proton);
+        fs::create_dir_all(&source_path).unwrap();
+        fs::create_dir_all(tmp_dir.join(".steam/root/compatibilitytools.d")).unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let plan = fs_mng.plan_migration(version, &source_path, None).unwrap();
+        assert_eq!(plan.action, MigrationAction::Move);
+        assert_eq!(plan.version.directory_name(), &String::from("GEH_PROTON_6.20-GE-1"));
+        assert_eq!(
+            plan.destination,
+            tmp_dir.join(".steam/root/compatibilitytools.d/GEH_PROTON_6.20-GE-1")
+        );
+
+        tmp_dir
+            .child("some/dir/Proton-6.20-GE-1")
+            .assert(predicates::path::exists());
+        tmp_dir
+            .child(".steam/root/compatibilitytools.d/GEH_PROTON_6.20-GE-1")
+            .assert(predicates::path::missing());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn plan_migration_for_missing_source_should_fail() {
+        let tmp_dir = TempDir::new().unwrap();
+        let source_path = PathBuf::from(tmp_dir.join("some/dir/Proton-6.20-GE-1"));
+        let version = Version::new("6.20-GE-1", TagKind::Proton);
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let result = fs_mng.plan_migration(version, &source_path, None);
+        assert!(result.is_err());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
     #[test]
     fn migrate_wine_version_in_lutris_directory() {
         let tmp_dir = TempDir::new().unwrap();
@@ -467,7 +1297,7 @@ mod tests {
         let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
         let fs_mng = FsMng::new(&path_cfg);
 
-        let version = fs_mng.migrate_folder(version, &source_path).unwrap();
+        let version = fs_mng.migrate_folder(version, &source_path, None).unwrap();
         assert_eq!(version.tag(), &Tag::from("6.20-GE-1"));
         assert_eq!(version.kind(), &TagKind::wine());
         assert_eq!(version.directory_name(), &String::from("Wine-6.20-GE-1"));
@@ -494,7 +1324,7 @@ mod tests {
         let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
         let fs_mng = FsMng::new(&path_cfg);
 
-        let version = fs_mng.migrate_folder(version, &source_path).unwrap();
+        let version = fs_mng.migrate_folder(version, &source_path, None).unwrap();
         assert_eq!(version.tag(), &Tag::from("6.20-GE-1"));
         assert_eq!(version.kind(), &TagKind::wine());
         assert_eq!(version.directory_name(), &String::from("GEH_WINE_6.20-GE-1"));
@@ -510,6 +1340,83 @@ mod tests {
         tmp_dir.close().unwrap();
     }
 
+    #[test]
+    fn migrate_wine_version_with_dir_name_should_use_given_directory_name() {
+        let tmp_dir = TempDir::new().unwrap();
+        let source_path = PathBuf::from(tmp_dir.join("some/dir/Wine-6.20-GE-1"));
+        let version = Version::new("6.20-GE-1", TagKind::wine());
+        fs::create_dir_all(&source_path).unwrap();
+        fs::create_dir_all(tmp_dir.join(".local/share/lutris/runners/wine")).unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let version = fs_mng
+            .migrate_folder(version, &source_path, Some("shared-wine-ge"))
+            .unwrap();
+        assert_eq!(version.directory_name(), &String::from("shared-wine-ge"));
+
+        tmp_dir
+            .child("some/dir/Wine-6.20-GE-1")
+            .assert(predicates::path::missing());
+        tmp_dir
+            .child(".local/share/lutris/runners/wine/shared-wine-ge")
+            .assert(predicates::path::exists());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn migrate_wine_version_in_lutris_directory_with_dir_name_should_rename_it() {
+        let tmp_dir = TempDir::new().unwrap();
+        let source_path = PathBuf::from(tmp_dir.join(".local/share/lutris/runners/wine/Wine-6.20-GE-1"));
+        let version = Version::new("6.20-GE-1", TagKind::wine());
+        fs::create_dir_all(&source_path).unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let plan = fs_mng
+            .plan_migration(version, &source_path, Some("shared-wine-ge"))
+            .unwrap();
+        assert_eq!(plan.action, MigrationAction::Move);
+        assert_eq!(
+            plan.destination,
+            tmp_dir.join(".local/share/lutris/runners/wine/shared-wine-ge")
+        );
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn migrate_with_dir_name_of_existing_directory_should_fail() {
+        let tmp_dir = TempDir::new().unwrap();
+        let source_path = PathBuf::from(tmp_dir.join("some/dir/Wine-6.20-GE-1"));
+        let version = Version::new("6.20-GE-1", TagKind::wine());
+        fs::create_dir_all(&source_path).unwrap();
+        fs::create_dir_all(tmp_dir.join(".local/share/lutris/runners/wine/lutris-7.2")).unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let result = fs_mng.migrate_folder(version, &source_path, Some("lutris-7.2"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "Directory '{}' already exists",
+                tmp_dir.join(".local/share/lutris/runners/wine/lutris-7.2").display()
+            )
+        );
+        tmp_dir
+            .child("some/dir/Wine-6.20-GE-1")
+            .assert(predicates::path::exists());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
     #[test]
     fn migrate_lol_version_in_lutris_directory() {
         let tmp_dir = TempDir::new().unwrap();
@@ -521,7 +1428,7 @@ mod tests {
         let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
         let fs_mng = FsMng::new(&path_cfg);
 
-        let version = fs_mng.migrate_folder(version, &source_path).unwrap();
+        let version = fs_mng.migrate_folder(version, &source_path, None).unwrap();
         assert_eq!(version.tag(), &Tag::from("6.20-GE-1"));
         assert_eq!(version.kind(), &TagKind::lol());
         assert_eq!(version.directory_name(), &String::from("Wine-LoL-6.20-GE-1"));
@@ -548,7 +1455,7 @@ mod tests {
         let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
         let fs_mng = FsMng::new(&path_cfg);
 
-        let version = fs_mng.migrate_folder(version, &source_path).unwrap();
+        let version = fs_mng.migrate_folder(version, &source_path, None).unwrap();
         assert_eq!(version.tag(), &Tag::from("6.20-GE-1"));
         assert_eq!(version.kind(), &TagKind::lol());
         assert_eq!(version.directory_name(), &String::from("GEH_LOL_WINE_6.20-GE-1"));
@@ -584,7 +1491,8 @@ mod tests {
         let fs_mng = FsMng::new(&path_cfg);
 
         let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, proton_dir_name);
-        fs_mng.apply_to_app_config(&version).unwrap();
+        let update = fs_mng.apply_to_app_config(&version, 1).unwrap();
+        assert_eq!(update, AppConfigUpdate::Modified);
 
         let modified_config = SteamConfig::create_copy(&steam_cfg_file).unwrap();
         assert_eq!(modified_config.proton_version(), proton_dir_name);
@@ -617,7 +1525,8 @@ mod tests {
         let fs_mng = FsMng::new(&path_cfg);
 
         let version = ManagedVersion::new("6.20-GE-1", TagKind::wine(), dir_name);
-        fs_mng.apply_to_app_config(&version).unwrap();
+        let update = fs_mng.apply_to_app_config(&version, 1).unwrap();
+        assert_eq!(update, AppConfigUpdate::Modified);
 
         let modified_config = LutrisConfig::create_copy(&cfg_file).unwrap();
         assert_eq!(modified_config.wine_version(), dir_name);
@@ -649,7 +1558,8 @@ mod tests {
         let fs_mng = FsMng::new(&path_cfg);
 
         let version = ManagedVersion::new("6.21-GE-1", TagKind::wine(), dir_name);
-        fs_mng.apply_to_app_config(&version).unwrap();
+        let update = fs_mng.apply_to_app_config(&version, 1).unwrap();
+        assert_eq!(update, AppConfigUpdate::Created);
 
         let modified_config = LutrisConfig::create_copy(&cfg_file).unwrap();
         assert_eq!(modified_config.wine_version(), dir_name);
@@ -662,6 +1572,213 @@ mod tests {
         tmp_dir.close().unwrap();
     }
 
+    #[test]
+    fn restore_steam_config_should_undo_apply() {
+        let tmp_dir = TempDir::new().unwrap();
+        let steam_cfg_dir = tmp_dir.join(".steam/root/config");
+        let steam_cfg_file = steam_cfg_dir.join("config.vdf");
+        fs::create_dir_all(&steam_cfg_dir).unwrap();
+        fs::copy("test_resources/assets/config.vdf", &steam_cfg_file).unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        fs::create_dir_all(
+            path_cfg
+                .app_config_backup_file(None, &TagKind::Proton)
+                .parent()
+                .unwrap(),
+        )
+        .unwrap();
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        fs_mng.apply_to_app_config(&version, 1).unwrap();
+        let restore = fs_mng.restore_app_config(&TagKind::Proton, 0).unwrap();
+        assert_eq!(restore, AppConfigRestore::Restored);
+
+        let restored_config = SteamConfig::create_copy(&steam_cfg_file).unwrap();
+        assert_eq!(restored_config.proton_version(), "Proton-6.21-GE-2");
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn restore_steam_config_without_backup_should_fail() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let err = fs_mng.restore_app_config(&TagKind::Proton, 0).unwrap_err();
+        assert!(err.to_string().starts_with("No backup exists at"));
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn apply_should_keep_the_given_amount_of_backups() {
+        let tmp_dir = TempDir::new().unwrap();
+        let steam_cfg_dir = tmp_dir.join(".steam/root/config");
+        let steam_cfg_file = steam_cfg_dir.join("config.vdf");
+        fs::create_dir_all(&steam_cfg_dir).unwrap();
+        fs::copy("test_resources/assets/config.vdf", &steam_cfg_file).unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let backup_path = path_cfg.app_config_backup_file(None, &TagKind::Proton);
+        fs::create_dir_all(backup_path.parent().unwrap()).unwrap();
+        let fs_mng = FsMng::new(&path_cfg);
+
+        for (tag, dir_name) in [
+            ("6.19-GE-1", "Proton-6.19-GE-1"),
+            ("6.20-GE-1", "Proton-6.20-GE-1"),
+            ("6.21-GE-1", "Proton-6.21-GE-1"),
+        ] {
+            let version = ManagedVersion::new(tag, TagKind::Proton, dir_name);
+            fs_mng.apply_to_app_config(&version, 2).unwrap();
+        }
+
+        let newest_backup = SteamConfig::create_copy(&backup_path).unwrap();
+        assert_eq!(newest_backup.proton_version(), "Proton-6.20-GE-1");
+        let older_backup = SteamConfig::create_copy(&numbered_backup_path(&backup_path, 1)).unwrap();
+        assert_eq!(older_backup.proton_version(), "Proton-6.19-GE-1");
+        assert!(!numbered_backup_path(&backup_path, 2).exists());
+
+        let restore = fs_mng.restore_app_config(&TagKind::Proton, 1).unwrap();
+        assert_eq!(restore, AppConfigRestore::Restored);
+        let restored_config = SteamConfig::create_copy(&steam_cfg_file).unwrap();
+        assert_eq!(restored_config.proton_version(), "Proton-6.19-GE-1");
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn apply_without_backups_should_not_create_a_backup() {
+        let tmp_dir = TempDir::new().unwrap();
+        let steam_cfg_dir = tmp_dir.join(".steam/root/config");
+        let steam_cfg_file = steam_cfg_dir.join("config.vdf");
+        fs::create_dir_all(&steam_cfg_dir).unwrap();
+        fs::copy("test_resources/assets/config.vdf", &steam_cfg_file).unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        let update = fs_mng.apply_to_app_config(&version, 0).unwrap();
+        assert_eq!(update, AppConfigUpdate::Modified);
+
+        let modified_config = SteamConfig::create_copy(&steam_cfg_file).unwrap();
+        assert_eq!(modified_config.proton_version(), "Proton-6.20-GE-1");
+        tmp_dir
+            .child(path_cfg.app_config_backup_file(None, &TagKind::Proton))
+            .assert(predicates::path::missing());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test_case(0 => "steam-config-backup.vdf"; "newest backup")]
+    #[test_case(2 => "steam-config-backup.vdf.2"; "older backup")]
+    fn numbered_backup_file_name(index: usize) -> String {
+        let path = numbered_backup_path(Path::new("/tmp/ge_man/steam-config-backup.vdf"), index);
+        path.file_name().unwrap().to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn apply_symlink_should_point_to_version_and_replace_old_link() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let compat_dir = path_cfg.steam_compatibility_tools_dir(None);
+        fs::create_dir_all(compat_dir.join("Proton-6.20-GE-1")).unwrap();
+        fs::create_dir_all(compat_dir.join("Proton-6.21-GE-2")).unwrap();
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let old_version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        fs_mng.apply_symlink(&old_version, "GE-Proton-latest").unwrap();
+        let new_version = ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2");
+        let link_path = fs_mng.apply_symlink(&new_version, "GE-Proton-latest").unwrap();
+
+        assert_eq!(link_path, compat_dir.join("GE-Proton-latest"));
+        assert_eq!(fs::read_link(&link_path).unwrap(), PathBuf::from("Proton-6.21-GE-2"));
+        assert!(!compat_dir.join(".ge_man_GE-Proton-latest").exists());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn apply_symlink_should_not_replace_directory() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let compat_dir = path_cfg.steam_compatibility_tools_dir(None);
+        fs::create_dir_all(compat_dir.join("Proton-6.20-GE-1")).unwrap();
+        fs::create_dir_all(compat_dir.join("GE-Proton-latest")).unwrap();
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        let err = fs_mng.apply_symlink(&version, "GE-Proton-latest").unwrap_err();
+
+        assert!(err.to_string().ends_with("already exists and is not a symlink"));
+        assert!(compat_dir.join("GE-Proton-latest").is_dir());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn link_to_bottles_should_link_version_into_bottles_runners() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let version_path = path_cfg.lutris_runners_dir(None).join("lutris-GE-Proton7-20-x86_64");
+        fs::create_dir_all(&version_path).unwrap();
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let version = ManagedVersion::new("GE-Proton7-20", TagKind::wine(), "lutris-GE-Proton7-20-x86_64");
+        let link_path = fs_mng.link_to_bottles(&version).unwrap();
+        // Linking again replaces the existing link.
+        fs_mng.link_to_bottles(&version).unwrap();
+
+        assert_eq!(
+            link_path,
+            path_cfg.bottles_runners_dir(None).join("lutris-GE-Proton7-20-x86_64")
+        );
+        assert_eq!(fs::read_link(&link_path).unwrap(), version_path);
+
+        fs_mng.remove_version(&version).unwrap();
+        assert!(fs::symlink_metadata(&link_path).is_err());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn restore_lutris_config_created_by_apply_should_remove_it() {
+        let tmp_dir = TempDir::new().unwrap();
+        let cfg_dir = tmp_dir.join(".config/lutris/runners");
+        let cfg_file = cfg_dir.join("wine.yml");
+        fs::create_dir_all(&cfg_dir).unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        fs::create_dir_all(
+            path_cfg
+                .app_config_backup_file(None, &TagKind::wine())
+                .parent()
+                .unwrap(),
+        )
+        .unwrap();
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let version = ManagedVersion::new("6.21-GE-1", TagKind::wine(), "Wine-6.21-GE-1");
+        fs_mng.apply_to_app_config(&version, 1).unwrap();
+        let restore = fs_mng.restore_app_config(&TagKind::wine(), 0).unwrap();
+        assert_eq!(restore, AppConfigRestore::Removed);
+
+        tmp_dir.child(&cfg_file).assert(predicates::path::missing());
+
+        drop(fs_mng);
+        tmp_dir.close().unwrap();
+    }
+
     #[test]
     fn copy_proton_settings() {
         let tmp_dir = TempDir::new().unwrap();
@@ -676,8 +1793,8 @@ mod tests {
         let dst = Version::new("6.20-GE-2", TagKind::Proton);
         let dst_tar = File::open("test_resources/assets/Proton-6.20-GE-2.tar.gz").unwrap();
 
-        let src = fs_mng.setup_version(src, Box::new(src_tar)).unwrap();
-        let dst = fs_mng.setup_version(dst, Box::new(dst_tar)).unwrap();
+        let src = fs_mng.setup_version(src, Box::new(src_tar), None).unwrap();
+        let dst = fs_mng.setup_version(dst, Box::new(dst_tar), None).unwrap();
 
         tmp_dir
             .child(".steam/root/compatibilitytools.d/Proton-6.19-GE-1")
@@ -704,4 +1821,175 @@ mod tests {
 
         tmp_dir.close().unwrap();
     }
+
+    #[test]
+    fn copy_user_settings_from_file_into_version_directory() {
+        let tmp_dir = TempDir::new().unwrap();
+        let version_dir = tmp_dir.child(".steam/root/compatibilitytools.d/Proton-6.20-GE-1");
+        fs::create_dir_all(&version_dir).unwrap();
+        let template = tmp_dir.child("user_settings.py");
+        fs::write(&template, "user_settings = {}\n").unwrap();
+
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        fs_mng.copy_user_settings_from_file(&template, &version).unwrap();
+
+        version_dir
+            .child("user_settings.py")
+            .assert(predicates::str::contains("user_settings = {}"));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn user_settings_path_points_into_version_directory() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_cfg = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let fs_mng = FsMng::new(&path_cfg);
+
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        assert_eq!(
+            fs_mng.user_settings_path(&version),
+            tmp_dir.join(".steam/root/compatibilitytools.d/Proton-6.20-GE-1/user_settings.py")
+        );
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn list_directory_names_of_steam_compatibility_tools_dir() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let compat_dir = path_config.steam_compatibility_tools_dir(None);
+        fs::create_dir_all(compat_dir.join("Proton-6.20-GE-1")).unwrap();
+        fs::create_dir_all(compat_dir.join("Proton-6.19-GE-2")).unwrap();
+        fs::write(compat_dir.join("some-file.txt"), "").unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+        let names = fs_manager.list_directory_names(&TagKind::Proton).unwrap();
+        assert_eq!(names, vec!["Proton-6.19-GE-2", "Proton-6.20-GE-1"]);
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn list_compat_tool_path_directories_skips_missing_paths() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let tools_dir = tmp_dir.join("tools");
+        fs::create_dir_all(tools_dir.join("GE-Proton7-8")).unwrap();
+        let missing_dir = tmp_dir.join("missing");
+
+        let fs_manager = FsMng::new(&path_config);
+        let env_value = format!("{}::{}", missing_dir.display(), tools_dir.display());
+        let directories = fs_manager.list_compat_tool_path_directories(Some(env_value));
+        assert_eq!(
+            directories,
+            vec![(tools_dir.to_path_buf(), String::from("GE-Proton7-8"))]
+        );
+
+        assert!(fs_manager.list_compat_tool_path_directories(None).is_empty());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn version_size_should_sum_up_all_files() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let version_dir = path_config.steam_compatibility_tools_dir(None).join("Proton-6.20-GE-1");
+        fs::create_dir_all(version_dir.join("files/bin")).unwrap();
+        fs::write(version_dir.join("proton"), vec![0; 100]).unwrap();
+        fs::write(version_dir.join("files/bin/wine"), vec![0; 23]).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        assert_eq!(fs_manager.version_size(&version).unwrap(), 123);
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn missing_version_files_should_report_missing_and_empty_files() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let version_dir = path_config.steam_compatibility_tools_dir(None).join("Proton-6.20-GE-1");
+        fs::create_dir_all(&version_dir).unwrap();
+        fs::write(version_dir.join("proton"), vec![0; 100]).unwrap();
+        fs::write(version_dir.join("toolmanifest.vdf"), vec![]).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        assert_eq!(
+            fs_manager.missing_version_files(&version).unwrap(),
+            vec![String::from("compatibilitytool.vdf"), String::from("toolmanifest.vdf")]
+        );
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn missing_version_files_of_complete_wine_version_should_be_empty() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+        let version_dir = path_config.lutris_runners_dir(None).join("lutris-ge-6.20-1-x86_64");
+        fs::create_dir_all(version_dir.join("bin")).unwrap();
+        fs::write(version_dir.join("bin/wine"), vec![0; 10]).unwrap();
+
+        let fs_manager = FsMng::new(&path_config);
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::wine(), "lutris-ge-6.20-1-x86_64");
+        assert!(fs_manager.missing_version_files(&version).unwrap().is_empty());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn missing_version_files_without_version_directory_should_fail() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+
+        let fs_manager = FsMng::new(&path_config);
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        assert!(fs_manager.missing_version_files(&version).is_err());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn ensure_free_space_for_small_archive_in_missing_directory() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+
+        let fs_manager = FsMng::new(&path_config);
+        assert!(fs_manager.ensure_free_space(&TagKind::Proton, None, 1024).is_ok());
+        assert!(fs_manager.ensure_free_space(&TagKind::wine(), None, 1024).is_ok());
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn ensure_free_space_for_too_large_archive_should_fail() {
+        let tmp_dir = TempDir::new().unwrap();
+        let path_config = MockPathConfig::new(PathBuf::from(tmp_dir.path()));
+
+        let fs_manager = FsMng::new(&path_config);
+        let err = fs_manager
+            .ensure_free_space(&TagKind::Proton, None, u64::MAX)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(&format!("Not enough free space in '{}", tmp_dir.path().display())));
+
+        drop(fs_manager);
+        tmp_dir.close().unwrap();
+    }
 }
@@ -0,0 +1,95 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{bail, Context};
+
+use crate::data::ManagedVersion;
+use crate::version::Versioned;
+
+pub const VERSION_DIR_ENV: &str = "GEMAN_VERSION_DIR";
+pub const TAG_ENV: &str = "GEMAN_TAG";
+pub const KIND_ENV: &str = "GEMAN_KIND";
+
+/// Run the configured post add hook for a freshly installed version. The hook gets the directory and the tag of the
+/// version as arguments and additionally as environment variables, together with the kind of the version.
+pub fn run_post_add_hook(hook: &Path, version_dir: &Path, version: &ManagedVersion) -> anyhow::Result<()> {
+    let status = Command::new(hook)
+        .arg(version_dir)
+        .arg(version.tag().value())
+        .env(VERSION_DIR_ENV, version_dir)
+        .env(TAG_ENV, version.tag().value())
+        .env(KIND_ENV, version.kind().compatibility_tool_name())
+        .status()
+        .context(format!("Could not run post add hook {}", hook.display()))?;
+
+    if !status.success() {
+        bail!("Post add hook {} exited with {}", hook.display(), status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    use assert_fs::TempDir;
+    use ge_man_lib::tag::TagKind;
+
+    use super::*;
+
+    fn write_hook(dir: &Path, script: &str) -> PathBuf {
+        let path = dir.join("hook.sh");
+        fs::write(&path, format!("#!/bin/sh\n{}\n", script)).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn hook_should_get_version_dir_and_tag() {
+        let tmp_dir = TempDir::new().unwrap();
+        let out = tmp_dir.join("out");
+        let hook = write_hook(
+            &tmp_dir,
+            &format!(
+                r#"echo "$1 $2 ${} ${} ${}" > {}"#,
+                VERSION_DIR_ENV,
+                TAG_ENV,
+                KIND_ENV,
+                out.display()
+            ),
+        );
+        let version = ManagedVersion::new("GE-Proton7-20", TagKind::Proton, "GE-Proton7-20");
+
+        run_post_add_hook(&hook, Path::new("/tmp/GE-Proton7-20"), &version).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&out).unwrap(),
+            "/tmp/GE-Proton7-20 GE-Proton7-20 /tmp/GE-Proton7-20 GE-Proton7-20 Proton GE\n"
+        );
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn failing_hook_should_return_error() {
+        let tmp_dir = TempDir::new().unwrap();
+        let hook = write_hook(&tmp_dir, "exit 3");
+        let version = ManagedVersion::new("GE-Proton7-20", TagKind::Proton, "GE-Proton7-20");
+
+        let err = run_post_add_hook(&hook, Path::new("/tmp/GE-Proton7-20"), &version).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Post add hook {} exited with exit status: 3", hook.display())
+        );
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn missing_hook_should_return_error() {
+        let version = ManagedVersion::new("GE-Proton7-20", TagKind::Proton, "GE-Proton7-20");
+        let err = run_post_add_hook(Path::new("/does-not-exist"), Path::new("/tmp"), &version).unwrap_err();
+        assert_eq!(err.to_string(), "Could not run post add hook /does-not-exist");
+    }
+}
@@ -1,8 +1,15 @@
-mod data;
-
+pub mod application;
 pub mod args;
+pub mod cache;
+pub mod checksum;
 pub mod clap;
+pub mod config;
+pub mod data;
+pub mod error;
 pub mod filesystem;
+pub mod hook;
+pub mod logging;
+pub mod notification;
 pub mod path;
 pub mod progress;
 pub mod ui;
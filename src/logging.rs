@@ -0,0 +1,148 @@
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use anyhow::Context;
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Log targets of the library and the binary crate.
+const LOG_TARGET_PREFIXES: [&str; 2] = ["ge_man", "geman"];
+
+/// Map the amount of times the verbose flag was provided to a log level filter.
+///
+/// Without the flag only warnings and errors are logged. One flag enables info messages, two or more enable debug
+/// messages.
+pub fn level_filter(verbosity: u64) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        _ => LevelFilter::Debug,
+    }
+}
+
+/// Logger which writes the log messages of GE-Man to stderr and, if configured, appends them to a log file.
+///
+/// Messages of dependencies are ignored.
+pub struct Logger {
+    level: LevelFilter,
+    log_file: Option<Mutex<File>>,
+}
+
+impl Logger {
+    pub fn new(level: LevelFilter, log_file: Option<File>) -> Self {
+        Logger {
+            level,
+            log_file: log_file.map(Mutex::new),
+        }
+    }
+
+    fn format(record: &Record) -> String {
+        format!("[{} {}] {}", record.level(), record.target(), record.args())
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+            && LOG_TARGET_PREFIXES
+                .iter()
+                .any(|prefix| metadata.target().starts_with(prefix))
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = Logger::format(record);
+        eprintln!("{}", line);
+        if let Some(file) = &self.log_file {
+            if let Ok(mut file) = file.lock() {
+                // A failing log file should never abort the running command.
+                let _ = writeln!(file, "{}", line);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.log_file {
+            if let Ok(mut file) = file.lock() {
+                let _ = file.flush();
+            }
+        }
+    }
+}
+
+/// Install the GE-Man logger for the whole process.
+///
+/// If a log file is given, the log messages are appended to it in addition to being written to stderr.
+pub fn init(verbosity: u64, log_file: Option<&Path>) -> anyhow::Result<()> {
+    let level = level_filter(verbosity);
+    let file = match log_file {
+        Some(path) => Some(
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open log file {}", path.display()))?,
+        ),
+        None => None,
+    };
+
+    log::set_boxed_logger(Box::new(Logger::new(level, file))).context("Failed to setup logger")?;
+    log::set_max_level(level);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use assert_fs::prelude::PathChild;
+    use assert_fs::TempDir;
+    use log::{Level, Record};
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(0 => LevelFilter::Warn; "no verbose flag")]
+    #[test_case(1 => LevelFilter::Info; "one verbose flag")]
+    #[test_case(2 => LevelFilter::Debug; "two verbose flags")]
+    #[test_case(5 => LevelFilter::Debug; "more verbose flags")]
+    fn verbosity_maps_to_level_filter(verbosity: u64) -> LevelFilter {
+        level_filter(verbosity)
+    }
+
+    fn log_message(logger: &Logger, level: Level, target: &str, message: &str) {
+        logger.log(
+            &Record::builder()
+                .level(level)
+                .target(target)
+                .args(format_args!("{}", message))
+                .build(),
+        );
+        logger.flush();
+    }
+
+    #[test]
+    fn logger_should_append_enabled_messages_to_log_file() {
+        let tmp_dir = TempDir::new().unwrap();
+        let log_file = tmp_dir.child("ge-man.log");
+        fs::write(&log_file, "[INFO ge_man::ui] Previous run\n").unwrap();
+        let file = OpenOptions::new().append(true).open(&log_file).unwrap();
+
+        let logger = Logger::new(LevelFilter::Info, Some(file));
+        log_message(&logger, Level::Info, "ge_man::ui", "Downloading 6.20-GE-1");
+        log_message(&logger, Level::Debug, "ge_man::ui", "Hidden debug message");
+        log_message(&logger, Level::Warn, "reqwest::connect", "Dependency message");
+
+        let content = fs::read_to_string(&log_file).unwrap();
+        assert_eq!(
+            content,
+            "[INFO ge_man::ui] Previous run\n[INFO ge_man::ui] Downloading 6.20-GE-1\n"
+        );
+
+        tmp_dir.close().unwrap();
+    }
+}
@@ -1,31 +1,73 @@
 use std::io;
-use std::io::Write;
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
+use std::process;
 
 use anyhow::bail;
 use ge_man_lib::download::GeDownloader;
 
 use ge_man::args::{
-    AddArgs, ApplyArgs, CheckArgs, CopyUserSettingsArgs, ForgetArgs, ListArgs, MigrationArgs, RemoveArgs,
+    AddArgs, ApplyArgs, CheckArgs, CompletionsArgs, CopyUserSettingsArgs, DiffUserSettingsArgs, ExportArgs, ForgetArgs,
+    GlobalArgs, ImportArgs, InfoArgs, ListArgs, MigrateDirArgs, MigrationArgs, PinArgs, RemoveArgs, VerifyArgs,
 };
 use ge_man::clap::commands::{
-    ADD, APPLY, CHECK, FORGET, LIST, MIGRATE, PROTON_USER_SETTINGS, REMOVE, USER_SETTINGS_COPY,
+    ADD, APPLY, CHECK, COMPLETIONS, DIRS, EXPORT, FORGET, IMPORT, INFO, LIST, MIGRATE, PIN, PROTON_USER_SETTINGS,
+    REBUILD, REMOVE, STATUS, UNPIN, USER_SETTINGS_COPY, USER_SETTINGS_DIFF, VERIFY,
 };
+use ge_man::cache::LatestTagCache;
+use ge_man::config::GeManConfig;
+use ge_man::data::CorruptManifestError;
+use ge_man::error::ErrorCategory;
 use ge_man::filesystem::FsMng;
 use ge_man::path::{AppConfigPaths, PathConfig, PathConfiguration};
 use ge_man::ui::TerminalWriter;
-use ge_man::{clap, path};
+use ge_man::{clap, logging, path};
 
-fn main() -> anyhow::Result<()> {
+fn main() {
+    if let Err(err) = run() {
+        let category = ErrorCategory::of(&err);
+        let err = match err.downcast_ref::<CorruptManifestError>() {
+            Some(corrupt) => anyhow::anyhow!(corrupt_manifest_message(corrupt)),
+            None => err,
+        };
+        eprintln!("Error: {:?}", err);
+        process::exit(category.into());
+    }
+}
+
+fn run() -> anyhow::Result<()> {
     let matches = clap::setup_clap().get_matches();
 
     let stderr = io::stderr();
     let mut err_handle = stderr.lock();
 
-    let path_config = PathConfig::default();
+    let global_args = GlobalArgs::from(&matches);
+    if let Err(err) = logging::init(global_args.verbose, global_args.log_file.as_deref()) {
+        bail!("Failed to setup logging: {:#}", err);
+    }
+
+    let mut path_config = PathConfig::default();
+    path_config.flatpak_steam = global_args.flatpak_steam;
+    path_config.flatpak_lutris = global_args.flatpak_lutris;
+    if let Some(config_file) = &global_args.config {
+        // A missing default config means that no settings were made, a missing given config is most likely a typo.
+        if !config_file.is_file() {
+            bail!("GE-Man config {} does not exist", config_file.display());
+        }
+        path_config.ge_man_config_file = Some(config_file.clone());
+    }
     if let Err(err) = path_config.create_ge_man_dirs(path::xdg_config_home(), path::xdg_data_home()) {
         bail!("Failed to setup xdg directory structure: {:#}", err);
     }
 
+    let config_path = path_config.ge_man_config_file(path::xdg_config_home());
+    log::info!("Reading GE-Man config from {}", config_path.display());
+    let mut ge_man_config = match GeManConfig::from_file(&config_path) {
+        Ok(config) => config,
+        Err(err) => bail!("Failed to read GE-Man config: {:#}", err),
+    };
+    path_config.steam_compat_tools_dir = ge_man_config.steam_compat_tools_dir.clone();
+
     if let Err(err) = path_config.create_app_dirs(path::xdg_config_home(), path::xdg_data_home(), path::steam_path()) {
         bail!(
             "Failed to setup required directory paths for Steam and Lutris: {:#}",
@@ -33,41 +75,101 @@ fn main() -> anyhow::Result<()> {
         );
     }
 
+    if let Some(retries) = global_args.retry_all {
+        ge_man_config.retries = retries;
+    }
+    if !global_args.show_progress() {
+        ge_man_config.show_progress = false;
+    }
+    if let Some(progress_format) = global_args.progress_format {
+        ge_man_config.progress_format = progress_format;
+    }
+    if let Some(limit_rate) = global_args.limit_rate {
+        ge_man_config.limit_rate = Some(limit_rate);
+    }
+
     let compatibility_tool_downloader = GeDownloader::default();
-    let fs_mng = FsMng::new(&path_config);
+    let mut fs_mng = FsMng::new(&path_config);
+    fs_mng.extract_tmp_dir = path::extract_tmp_dir()
+        .map(PathBuf::from)
+        .or_else(|| ge_man_config.extract_tmp_dir.clone());
 
     let stdout = io::stdout();
-    let mut out_handle = stdout.lock();
+    let mut out_handle: Box<dyn Write> = if global_args.quiet {
+        Box::new(io::sink())
+    } else {
+        Box::new(stdout.lock())
+    };
 
-    let output_writer = TerminalWriter::new(&compatibility_tool_downloader, &fs_mng, &path_config);
+    let stdin = io::stdin();
+    let mut in_handle = stdin.lock();
+
+    let mut output_writer = TerminalWriter::with_config(
+        &compatibility_tool_downloader,
+        &fs_mng,
+        &path_config,
+        ge_man_config.clone(),
+    );
+    output_writer.set_interactive(stdin.is_terminal() && !global_args.quiet);
+    output_writer.set_assume_yes(global_args.assume_yes);
+    output_writer.set_offline(global_args.offline || path::offline());
+    if ge_man_config.latest_tag_cache_ttl_secs > 0 {
+        output_writer.set_latest_tag_cache(LatestTagCache::new(
+            path_config.ge_man_cache_dir(path::xdg_cache_home()),
+            ge_man_config.latest_tag_cache_ttl(),
+        ));
+    }
     let result = match matches.subcommand_name() {
         Some(LIST) => output_writer.list(
             &mut out_handle,
+            &mut err_handle,
             ListArgs::from(matches),
             AppConfigPaths::from(&path_config),
         ),
-        Some(ADD) => output_writer.add(&mut out_handle, AddArgs::from(matches)),
+        Some(ADD) => output_writer.add(&mut out_handle, AddArgs::create_from(matches, &ge_man_config)),
         Some(REMOVE) => output_writer.remove(
             &mut out_handle,
+            &mut in_handle,
             RemoveArgs::from(matches),
             AppConfigPaths::from(&path_config),
         ),
-        Some(CHECK) => {
-            output_writer.check(&mut out_handle, &mut err_handle, CheckArgs::from(matches));
-            Ok(())
+        Some(CHECK) => output_writer.check(&mut out_handle, &mut err_handle, CheckArgs::from(matches)),
+        Some(MIGRATE) if MigrateDirArgs::is_present(&matches) => {
+            output_writer.migrate_dir(&mut out_handle, MigrateDirArgs::from(matches))
         }
         Some(MIGRATE) => output_writer.migrate(&mut out_handle, MigrationArgs::from(matches)),
-        Some(APPLY) => output_writer.apply_to_app_config(&mut out_handle, ApplyArgs::from(matches)),
+        Some(APPLY) => output_writer.apply_to_app_config(
+            &mut out_handle,
+            &mut in_handle,
+            ApplyArgs::create_from(matches, &ge_man_config),
+            AppConfigPaths::from(&path_config),
+        ),
         Some(PROTON_USER_SETTINGS) => {
             let sub_cmd_matches = matches.subcommand_matches(PROTON_USER_SETTINGS).unwrap();
             match sub_cmd_matches.subcommand_name() {
                 Some(USER_SETTINGS_COPY) => {
                     output_writer.copy_user_settings(&mut out_handle, CopyUserSettingsArgs::from(matches))
                 }
+                Some(USER_SETTINGS_DIFF) => {
+                    output_writer.diff_user_settings(&mut out_handle, DiffUserSettingsArgs::from(matches))
+                }
                 _ => Ok(()),
             }
         }
         Some(FORGET) => output_writer.forget(&mut out_handle, ForgetArgs::from(matches)),
+        Some(PIN) | Some(UNPIN) => output_writer.pin(&mut out_handle, PinArgs::from(matches)),
+        Some(VERIFY) => output_writer.verify(&mut out_handle, VerifyArgs::from(matches)),
+        Some(EXPORT) => output_writer.export(&mut out_handle, ExportArgs::from(matches)),
+        Some(IMPORT) => output_writer.import(&mut out_handle, ImportArgs::from(matches)),
+        Some(REBUILD) => output_writer.rebuild(&mut out_handle),
+        Some(COMPLETIONS) => output_writer.completions(&mut out_handle, CompletionsArgs::from(matches)),
+        Some(DIRS) => output_writer.dirs(&mut out_handle),
+        Some(INFO) => output_writer.info(
+            &mut out_handle,
+            InfoArgs::from(matches),
+            AppConfigPaths::from(&path_config),
+        ),
+        Some(STATUS) => output_writer.status(&mut out_handle, AppConfigPaths::from(&path_config)),
         None => Ok(()),
         _ => Ok(()),
     };
@@ -77,3 +179,14 @@ fn main() -> anyhow::Result<()> {
 
     result
 }
+
+fn corrupt_manifest_message(err: &CorruptManifestError) -> String {
+    format!(
+        "The managed versions file {} can not be read: {}\n\
+        A copy of it was saved to {}. GE-Man does not modify the file until it can be read again. Fix the file by \
+        hand or run the rebuild command to register the installed versions again.",
+        err.path.display(),
+        err.reason,
+        err.backup_path.display()
+    )
+}
@@ -0,0 +1,20 @@
+use std::io;
+use std::process::Command;
+
+const NOTIFY_SEND: &str = "notify-send";
+
+/// Show a desktop notification by invoking `notify-send`.
+pub fn send(summary: &str, body: &str) -> io::Result<()> {
+    let status = Command::new(NOTIFY_SEND)
+        .args(["--app-name=GE-Man", summary, body])
+        .status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("{} exited with {}", NOTIFY_SEND, status),
+        ))
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{env, fs};
 
 use anyhow::Context;
@@ -8,11 +8,19 @@ use mockall::automock;
 
 pub const STEAM_COMP_DIR: &str = "Steam/compatibilitytools.d";
 pub const LUTRIS_WINE_RUNNERS_DIR: &str = "lutris/runners/wine";
+pub const BOTTLES_RUNNERS_DIR: &str = "bottles/runners";
+const STEAM_ROOT_SYMLINK: &str = ".steam/root";
+const FLATPAK_STEAM_ROOT: &str = ".var/app/com.valvesoftware.Steam/.local/share/Steam";
+const FLATPAK_LUTRIS_ROOT: &str = ".var/app/net.lutris.Lutris";
 
 const HOME: &str = "HOME";
 const XDG_DATA_HOME: &str = "XDG_DATA_HOME";
 const XDG_CONFIG_HOME: &str = "XDG_CONFIG_HOME";
+const XDG_CACHE_HOME: &str = "XDG_CACHE_HOME";
 const STEAM_PATH_ENV: &str = "STEAM_PATH";
+pub const STEAM_COMPAT_TOOL_PATHS_ENV: &str = "STEAM_COMPAT_TOOL_PATHS";
+const EXTRACT_TMP_DIR_ENV: &str = "GE_MAN_EXTRACT_TMP_DIR";
+const OFFLINE_ENV: &str = "GEMAN_OFFLINE";
 
 const APP_NAME: &str = "ge_man";
 
@@ -24,10 +32,46 @@ pub fn xdg_config_home() -> Option<String> {
     env::var(XDG_CONFIG_HOME).ok()
 }
 
+pub fn xdg_cache_home() -> Option<String> {
+    env::var(XDG_CACHE_HOME).ok()
+}
+
 pub fn steam_path() -> Option<String> {
     env::var(STEAM_PATH_ENV).ok()
 }
 
+pub fn steam_compat_tool_paths() -> Option<String> {
+    env::var(STEAM_COMPAT_TOOL_PATHS_ENV).ok()
+}
+
+pub fn extract_tmp_dir() -> Option<String> {
+    env::var(EXTRACT_TMP_DIR_ENV).ok()
+}
+
+/// Whether offline mode is enabled with `GEMAN_OFFLINE=1`.
+pub fn offline() -> bool {
+    env::var(OFFLINE_ENV).map_or(false, |value| value == "1")
+}
+
+/// Steam root directory in the given home directory. The root of a Flatpak installation of Steam is used if it is
+/// forced or if only the Flatpak installation exists.
+fn steam_root(home: &Path, force_flatpak: bool) -> PathBuf {
+    let steam_root_symlink = home.join(STEAM_ROOT_SYMLINK);
+    let flatpak_steam_root = home.join(FLATPAK_STEAM_ROOT);
+
+    if force_flatpak || (!steam_root_symlink.exists() && flatpak_steam_root.exists()) {
+        flatpak_steam_root
+    } else {
+        steam_root_symlink
+    }
+}
+
+/// Whether the Flatpak installation of Lutris in the given home directory is used. It is used if it is forced or if
+/// only the Flatpak installation exists.
+fn use_flatpak_lutris(home: &Path, native_lutris_dir: &Path, force_flatpak: bool) -> bool {
+    force_flatpak || (!native_lutris_dir.exists() && home.join(FLATPAK_LUTRIS_ROOT).exists())
+}
+
 #[cfg_attr(test, automock)]
 pub trait PathConfiguration {
     fn xdg_data_dir(&self, xdg_data_home: Option<String>) -> PathBuf {
@@ -46,16 +90,21 @@ pub trait PathConfiguration {
         PathBuf::from(config_dir)
     }
 
+    fn xdg_cache_dir(&self, xdg_cache_home: Option<String>) -> PathBuf {
+        let cache_dir = xdg_cache_home
+            .or_else(|| env::var(HOME).ok().map(|home| format!("{}/.cache", home)))
+            .unwrap();
+
+        PathBuf::from(cache_dir)
+    }
+
     fn steam(&self, steam_root_path_override: Option<String>) -> PathBuf {
-        let steam_root_symlink = env::var(HOME)
+        let steam_root = env::var(HOME)
             .ok()
-            .map(|home| format!("{}/.steam/root", home))
-            .map(PathBuf::from)
+            .map(|home| steam_root(Path::new(&home), false))
             .unwrap();
 
-        steam_root_path_override
-            .map(PathBuf::from)
-            .unwrap_or_else(|| steam_root_symlink)
+        steam_root_path_override.map(PathBuf::from).unwrap_or(steam_root)
     }
 
     fn lutris_local(&self, xdg_data_home: Option<String>) -> PathBuf {
@@ -86,6 +135,10 @@ pub trait PathConfiguration {
         self.lutris_local(xdg_data_home).join("runners/wine")
     }
 
+    fn bottles_runners_dir(&self, xdg_data_home: Option<String>) -> PathBuf {
+        self.xdg_data_dir(xdg_data_home).join(BOTTLES_RUNNERS_DIR)
+    }
+
     fn ge_man_data_dir(&self, xdg_data_home: Option<String>) -> PathBuf {
         self.xdg_data_dir(xdg_data_home).join(APP_NAME)
     }
@@ -94,10 +147,18 @@ pub trait PathConfiguration {
         self.xdg_config_dir(xdg_config_home).join(APP_NAME)
     }
 
+    fn ge_man_cache_dir(&self, xdg_cache_home: Option<String>) -> PathBuf {
+        self.xdg_cache_dir(xdg_cache_home).join(APP_NAME)
+    }
+
     fn managed_versions_config(&self, xdg_data_home: Option<String>) -> PathBuf {
         self.ge_man_data_dir(xdg_data_home).join("managed_versions.json")
     }
 
+    fn ge_man_config_file(&self, xdg_config_home: Option<String>) -> PathBuf {
+        self.ge_man_config_dir(xdg_config_home).join("config.json")
+    }
+
     fn app_config_backup_file(&self, xdg_config_home: Option<String>, kind: &TagKind) -> PathBuf {
         let config_file = match kind {
             TagKind::Proton => "steam-config-backup.vdf",
@@ -152,13 +213,16 @@ pub trait PathConfiguration {
 pub struct AppConfigPaths {
     pub steam: PathBuf,
     pub lutris: PathBuf,
+    /// Bottles has no config which names a version, the versions linked into its runners directory are used instead.
+    pub bottles_runners: PathBuf,
 }
 
 impl AppConfigPaths {
-    pub fn new<P: Into<PathBuf>>(steam: P, lutris: P) -> Self {
+    pub fn new<P: Into<PathBuf>>(steam: P, lutris: P, bottles_runners: P) -> Self {
         AppConfigPaths {
             steam: steam.into(),
             lutris: lutris.into(),
+            bottles_runners: bottles_runners.into(),
         }
     }
 }
@@ -168,15 +232,39 @@ impl<T: PathConfiguration> From<&T> for AppConfigPaths {
         AppConfigPaths::new(
             path_cfg.steam_config(steam_path()),
             path_cfg.lutris_wine_runner_config(xdg_config_home()),
+            path_cfg.bottles_runners_dir(xdg_data_home()),
         )
     }
 }
 
-pub struct PathConfig {}
+pub struct PathConfig {
+    /// Used instead of the `compatibilitytools.d` directory of the Steam root, e.g. for a Flatpak installation of Steam.
+    pub steam_compat_tools_dir: Option<PathBuf>,
+    /// Always use the Flatpak installation of Steam, even if a native installation exists.
+    pub flatpak_steam: bool,
+    /// Used instead of the `config.json` in the GE-Man config directory, e.g. for separate config profiles.
+    pub ge_man_config_file: Option<PathBuf>,
+    /// Always use the Flatpak installation of Lutris, even if a native installation exists.
+    pub flatpak_lutris: bool,
+}
 
 impl PathConfig {
     pub fn new() -> Self {
-        PathConfig {}
+        PathConfig {
+            steam_compat_tools_dir: None,
+            flatpak_steam: false,
+            ge_man_config_file: None,
+            flatpak_lutris: false,
+        }
+    }
+
+    /// Root of the Flatpak installation of Lutris, if it is used instead of the native installation. The data and
+    /// config directories of Flatpak Lutris are located in this directory. The given native Lutris directory decides
+    /// whether a native installation exists.
+    fn flatpak_lutris_root(&self, native_lutris_dir: &Path) -> Option<PathBuf> {
+        let home = PathBuf::from(env::var(HOME).ok()?);
+
+        use_flatpak_lutris(&home, native_lutris_dir, self.flatpak_lutris).then(|| home.join(FLATPAK_LUTRIS_ROOT))
     }
 }
 
@@ -186,13 +274,52 @@ impl Default for PathConfig {
     }
 }
 
-impl PathConfiguration for PathConfig {}
+impl PathConfiguration for PathConfig {
+    fn steam(&self, steam_root_path_override: Option<String>) -> PathBuf {
+        let steam_root = env::var(HOME)
+            .ok()
+            .map(|home| steam_root(Path::new(&home), self.flatpak_steam))
+            .unwrap();
+
+        steam_root_path_override.map(PathBuf::from).unwrap_or(steam_root)
+    }
+
+    fn steam_compatibility_tools_dir(&self, steam_root_path_override: Option<String>) -> PathBuf {
+        match &self.steam_compat_tools_dir {
+            Some(dir) => dir.clone(),
+            None => self.steam(steam_root_path_override).join("compatibilitytools.d"),
+        }
+    }
+
+    fn lutris_local(&self, xdg_data_home: Option<String>) -> PathBuf {
+        let native_lutris_dir = self.xdg_data_dir(xdg_data_home).join("lutris");
+        match self.flatpak_lutris_root(&native_lutris_dir) {
+            Some(root) => root.join("data/lutris"),
+            None => native_lutris_dir,
+        }
+    }
+
+    fn lutris_config(&self, xdg_config_home: Option<String>) -> PathBuf {
+        let native_lutris_dir = self.xdg_config_dir(xdg_config_home).join("lutris");
+        match self.flatpak_lutris_root(&native_lutris_dir) {
+            Some(root) => root.join("config/lutris"),
+            None => native_lutris_dir,
+        }
+    }
+
+    fn ge_man_config_file(&self, xdg_config_home: Option<String>) -> PathBuf {
+        match &self.ge_man_config_file {
+            Some(file) => file.clone(),
+            None => self.ge_man_config_dir(xdg_config_home).join("config.json"),
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
-    use assert_fs::prelude::{PathAssert, PathChild};
+    use assert_fs::prelude::{PathAssert, PathChild, PathCreateDir};
     use assert_fs::TempDir;
     use ge_man_lib::tag::TagKind;
 
@@ -213,6 +340,21 @@ mod tests {
         assert_eq!(path, PathBuf::from("/tmp/xdg-data/"));
     }
 
+    #[test]
+    fn get_xdg_cache_path_with_no_override() {
+        let path_cfg = PathConfig::default();
+        let path = path_cfg.xdg_cache_dir(None);
+        assert!(path.to_string_lossy().contains("home"));
+        assert!(path.to_string_lossy().contains(".cache"));
+    }
+
+    #[test]
+    fn ge_man_cache_dir_with_xdg_cache_override() {
+        let path_cfg = PathConfig::default();
+        let path = path_cfg.ge_man_cache_dir(Some(String::from("/tmp/xdg-cache")));
+        assert_eq!(path, PathBuf::from("/tmp/xdg-cache/ge_man"));
+    }
+
     #[test]
     fn steam_path_with_no_overrides() {
         let path_cfg = PathConfig::default();
@@ -230,6 +372,124 @@ mod tests {
         assert_eq!(path, PathBuf::from("/tmp/steam"));
     }
 
+    #[test]
+    fn steam_root_should_prefer_native_installation() {
+        let tmp_dir = TempDir::new().unwrap();
+        tmp_dir.child(".steam/root").create_dir_all().unwrap();
+        tmp_dir.child(FLATPAK_STEAM_ROOT).create_dir_all().unwrap();
+
+        assert_eq!(steam_root(&tmp_dir, false), tmp_dir.join(".steam/root"));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn steam_root_should_detect_flatpak_installation() {
+        let tmp_dir = TempDir::new().unwrap();
+        tmp_dir.child(FLATPAK_STEAM_ROOT).create_dir_all().unwrap();
+
+        assert_eq!(steam_root(&tmp_dir, false), tmp_dir.join(FLATPAK_STEAM_ROOT));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn steam_root_without_installation_should_use_native_path() {
+        let tmp_dir = TempDir::new().unwrap();
+        assert_eq!(steam_root(&tmp_dir, false), tmp_dir.join(".steam/root"));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn flatpak_lutris_should_not_be_used_with_native_installation() {
+        let tmp_dir = TempDir::new().unwrap();
+        tmp_dir.child(".local/share/lutris").create_dir_all().unwrap();
+        tmp_dir.child(FLATPAK_LUTRIS_ROOT).create_dir_all().unwrap();
+
+        assert!(!use_flatpak_lutris(
+            &tmp_dir,
+            &tmp_dir.join(".local/share/lutris"),
+            false
+        ));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn flatpak_lutris_should_be_detected_without_native_installation() {
+        let tmp_dir = TempDir::new().unwrap();
+        tmp_dir.child(FLATPAK_LUTRIS_ROOT).create_dir_all().unwrap();
+
+        assert!(use_flatpak_lutris(
+            &tmp_dir,
+            &tmp_dir.join(".local/share/lutris"),
+            false
+        ));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn flatpak_lutris_should_not_be_used_without_any_installation() {
+        let tmp_dir = TempDir::new().unwrap();
+        assert!(!use_flatpak_lutris(
+            &tmp_dir,
+            &tmp_dir.join(".local/share/lutris"),
+            false
+        ));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn forced_flatpak_lutris_should_be_used_with_native_installation() {
+        let tmp_dir = TempDir::new().unwrap();
+        tmp_dir.child(".local/share/lutris").create_dir_all().unwrap();
+
+        assert!(use_flatpak_lutris(&tmp_dir, &tmp_dir.join(".local/share/lutris"), true));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn lutris_paths_with_native_lutris_in_given_xdg_dirs() {
+        let tmp_dir = TempDir::new().unwrap();
+        tmp_dir.child("xdg-data/lutris").create_dir_all().unwrap();
+        tmp_dir.child("xdg-config/lutris").create_dir_all().unwrap();
+        let path_cfg = PathConfig::default();
+
+        let lutris_local = path_cfg.lutris_local(Some(tmp_dir.join("xdg-data").to_string_lossy().into_owned()));
+        let lutris_config = path_cfg.lutris_config(Some(tmp_dir.join("xdg-config").to_string_lossy().into_owned()));
+
+        assert_eq!(lutris_local, tmp_dir.join("xdg-data/lutris"));
+        assert_eq!(lutris_config, tmp_dir.join("xdg-config/lutris"));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn lutris_paths_with_forced_flatpak_lutris() {
+        let mut path_cfg = PathConfig::default();
+        path_cfg.flatpak_lutris = true;
+
+        let runners_dir = path_cfg.lutris_runners_dir(Some(String::from("/tmp/xdg-data")));
+        let runner_config = path_cfg.lutris_wine_runner_config(Some(String::from("/tmp/xdg-config")));
+
+        assert!(runners_dir.ends_with(".var/app/net.lutris.Lutris/data/lutris/runners/wine"));
+        assert!(runner_config.ends_with(".var/app/net.lutris.Lutris/config/lutris/runners/wine.yml"));
+    }
+
+    #[test]
+    fn steam_root_with_forced_flatpak() {
+        let tmp_dir = TempDir::new().unwrap();
+        tmp_dir.child(".steam/root").create_dir_all().unwrap();
+
+        assert_eq!(steam_root(&tmp_dir, true), tmp_dir.join(FLATPAK_STEAM_ROOT));
+
+        tmp_dir.close().unwrap();
+    }
+
     #[test]
     fn lutris_local_path_with_no_override() {
         let path_cfg = PathConfig::default();
@@ -298,6 +558,15 @@ mod tests {
         assert_eq!(path, PathBuf::from("/tmp/steam/compatibilitytools.d"));
     }
 
+    #[test]
+    fn steam_compatibilitytools_with_configured_dir() {
+        let mut path_cfg = PathConfig::default();
+        path_cfg.steam_compat_tools_dir = Some(PathBuf::from("/tmp/flatpak/Steam/compatibilitytools.d"));
+        let path = path_cfg.steam_compatibility_tools_dir(Some(String::from("/tmp/steam")));
+
+        assert_eq!(path, PathBuf::from("/tmp/flatpak/Steam/compatibilitytools.d"));
+    }
+
     #[test]
     fn lutris_wine_config_with_no_override() {
         let path_cfg = PathConfig::default();
@@ -332,6 +601,14 @@ mod tests {
         assert_eq!(path, PathBuf::from("/tmp/xdg-data/lutris/runners/wine"));
     }
 
+    #[test]
+    fn bottles_runners_dir_with_xdg_data_override() {
+        let path_cfg = PathConfig::default();
+        let path = path_cfg.bottles_runners_dir(Some(String::from("/tmp/xdg-data")));
+
+        assert_eq!(path, PathBuf::from("/tmp/xdg-data/bottles/runners"));
+    }
+
     #[test]
     fn ge_man_data_dir_with_no_override() {
         let path_cfg = PathConfig::default();
@@ -385,6 +662,32 @@ mod tests {
         assert_eq!(path, PathBuf::from("/tmp/xdg-config/ge_man/managed_versions.json"));
     }
 
+    #[test]
+    fn ge_man_config_file_with_no_override() {
+        let path_cfg = PathConfig::default();
+        let path = path_cfg.ge_man_config_file(None);
+
+        assert!(path.to_string_lossy().contains("home"));
+        assert!(path.to_string_lossy().contains(".config/ge_man/config.json"));
+    }
+
+    #[test]
+    fn ge_man_config_file_with_xdg_config_override() {
+        let path_cfg = PathConfig::default();
+        let path = path_cfg.ge_man_config_file(Some(String::from("/tmp/xdg-config")));
+
+        assert_eq!(path, PathBuf::from("/tmp/xdg-config/ge_man/config.json"));
+    }
+
+    #[test]
+    fn ge_man_config_file_with_config_file_override() {
+        let mut path_cfg = PathConfig::default();
+        path_cfg.ge_man_config_file = Some(PathBuf::from("/tmp/profiles/proton.json"));
+        let path = path_cfg.ge_man_config_file(Some(String::from("/tmp/xdg-config")));
+
+        assert_eq!(path, PathBuf::from("/tmp/profiles/proton.json"));
+    }
+
     #[test]
     fn ge_man_backup_file_for_steam_with_no_override() {
         let path_cfg = PathConfig::default();
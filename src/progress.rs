@@ -1,9 +1,56 @@
+use std::io;
 use std::io::Read;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+use serde::{Deserialize, Serialize};
 
-use ge_man_lib::download::ReadProgressWrapper;
 use ge_man_lib::download::response::GeAsset;
+use ge_man_lib::download::ReadProgressWrapper;
+
+const DOWNLOAD_PHASE: &str = "download";
+const EXTRACT_PHASE: &str = "extract";
+const JSON_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+const RATE_UPDATE_INTERVAL: Duration = Duration::from_millis(250);
+/// Weight of the latest sample in the smoothed transfer rate.
+const RATE_SMOOTHING: f64 = 0.3;
+const MIB: f64 = 1024.0 * 1024.0;
+
+/// How the download and extraction progress is reported.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressFormat {
+    /// Progress bars on stdout.
+    Bar,
+    /// One JSON object per update on stderr, meant to be read by other programs.
+    Json,
+}
+
+impl ProgressFormat {
+    pub fn values() -> [&'static str; 2] {
+        ["bar", "json"]
+    }
+}
+
+impl Default for ProgressFormat {
+    fn default() -> Self {
+        ProgressFormat::Bar
+    }
+}
+
+impl FromStr for ProgressFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bar" => Ok(ProgressFormat::Bar),
+            "json" => Ok(ProgressFormat::Json),
+            _ => Err(format!("Unsupported progress format: {}", s)),
+        }
+    }
+}
 
 fn style() -> ProgressStyle {
     ProgressStyle::default_bar()
@@ -11,6 +58,182 @@ fn style() -> ProgressStyle {
         .progress_chars("=>-")
 }
 
+fn download_style() -> ProgressStyle {
+    ProgressStyle::default_bar()
+        .template(
+            "{msg} {spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} {percent}% \
+            {prefix}",
+        )
+        .progress_chars("=>-")
+}
+
+/// Parse a download rate in bytes per second. The value may end with a `K`, `M` or `G` suffix for KiB, MiB or GiB per
+/// second, e.g. `500K` or `2M`.
+pub fn parse_rate(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let (number, multiplier) = match value.char_indices().last() {
+        Some((idx, 'k' | 'K')) => (&value[..idx], 1024),
+        Some((idx, 'm' | 'M')) => (&value[..idx], 1024 * 1024),
+        Some((idx, 'g' | 'G')) => (&value[..idx], 1024 * 1024 * 1024),
+        _ => (value, 1),
+    };
+
+    match number.parse::<u64>() {
+        Ok(number) if number > 0 => number
+            .checked_mul(multiplier)
+            .ok_or_else(|| format!("Rate is too large: {}", value)),
+        _ => Err(format!(
+            "Unsupported rate: {}. The rate must be a number greater than 0, optionally followed by K, M or G",
+            value
+        )),
+    }
+}
+
+/// Reader which sleeps between reads so that on average no more than `bytes_per_sec` bytes are read per second.
+struct ThrottledReader<R> {
+    inner: R,
+    bytes_per_sec: u64,
+    start: Instant,
+    read: u64,
+}
+
+impl<R: Read> ThrottledReader<R> {
+    fn new(bytes_per_sec: u64, inner: R) -> Self {
+        ThrottledReader {
+            inner,
+            bytes_per_sec,
+            start: Instant::now(),
+            read: 0,
+        }
+    }
+}
+
+impl<R: Read> Read for ThrottledReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Never read more than one second worth of bytes at once, so the rate stays even for large buffers.
+        let max_len = buf.len().min(self.bytes_per_sec as usize);
+        let read = self.inner.read(&mut buf[..max_len])?;
+        self.read += read as u64;
+
+        let expected = Duration::from_secs_f64(self.read as f64 / self.bytes_per_sec as f64);
+        let elapsed = self.start.elapsed();
+        if expected > elapsed {
+            thread::sleep(expected - elapsed);
+        }
+
+        Ok(read)
+    }
+}
+
+/// Download tracker which limits the download rate of the reader of another tracker.
+pub struct RateLimitedTracker {
+    inner: Box<dyn ReadProgressWrapper>,
+    bytes_per_sec: u64,
+}
+
+impl RateLimitedTracker {
+    pub fn new(inner: Box<dyn ReadProgressWrapper>, bytes_per_sec: u64) -> Self {
+        RateLimitedTracker { inner, bytes_per_sec }
+    }
+}
+
+impl ReadProgressWrapper for RateLimitedTracker {
+    fn init(self: Box<Self>, len: u64) -> Box<dyn ReadProgressWrapper> {
+        Box::new(RateLimitedTracker::new(self.inner.init(len), self.bytes_per_sec))
+    }
+
+    fn wrap(&self, reader: Box<dyn Read>) -> Box<dyn Read> {
+        self.inner
+            .wrap(Box::new(ThrottledReader::new(self.bytes_per_sec, reader)))
+    }
+
+    fn finish(&self, asset: &GeAsset) {
+        self.inner.finish(asset)
+    }
+}
+
+/// Exponentially smoothed transfer rate in bytes per second. Read bytes are collected into samples of at least
+/// `RATE_UPDATE_INTERVAL`, so single small reads do not make the rate jump around.
+struct TransferRate {
+    bytes_per_sec: Option<f64>,
+    sample_start: Instant,
+    sample_bytes: u64,
+}
+
+impl TransferRate {
+    fn new(start: Instant) -> Self {
+        TransferRate {
+            bytes_per_sec: None,
+            sample_start: start,
+            sample_bytes: 0,
+        }
+    }
+
+    /// Add the bytes of one read. Returns true if the sample is complete and the rate was updated.
+    fn add(&mut self, bytes: u64, now: Instant) -> bool {
+        self.sample_bytes += bytes;
+        let elapsed = now.duration_since(self.sample_start);
+        if elapsed < RATE_UPDATE_INTERVAL {
+            return false;
+        }
+
+        let sample = self.sample_bytes as f64 / elapsed.as_secs_f64();
+        self.bytes_per_sec = Some(match self.bytes_per_sec {
+            Some(rate) => RATE_SMOOTHING * sample + (1.0 - RATE_SMOOTHING) * rate,
+            None => sample,
+        });
+        self.sample_start = now;
+        self.sample_bytes = 0;
+
+        true
+    }
+}
+
+/// Format the rate and the estimated time until the remaining bytes are transferred, e.g. `2.5 MiB/s, ETA 01:30`.
+fn speed_and_eta(bytes_per_sec: f64, remaining: u64) -> String {
+    let eta = if bytes_per_sec > 0.0 {
+        let secs = (remaining as f64 / bytes_per_sec).ceil() as u64;
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    } else {
+        String::from("--:--")
+    };
+
+    format!("{:.1} MiB/s, ETA {}", bytes_per_sec / MIB, eta)
+}
+
+/// Reader which advances the progress bar and shows the transfer rate and ETA in its prefix.
+struct RateReader<R> {
+    inner: R,
+    pb: ProgressBar,
+    rate: TransferRate,
+}
+
+impl<R: Read> RateReader<R> {
+    fn new(pb: ProgressBar, inner: R) -> Self {
+        RateReader {
+            inner,
+            pb,
+            rate: TransferRate::new(Instant::now()),
+        }
+    }
+}
+
+impl<R: Read> Read for RateReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.pb.inc(read as u64);
+
+        if self.rate.add(read as u64, Instant::now()) {
+            if let Some(bytes_per_sec) = self.rate.bytes_per_sec {
+                let remaining = self.pb.length().saturating_sub(self.pb.position());
+                self.pb.set_prefix(speed_and_eta(bytes_per_sec, remaining));
+            }
+        }
+
+        Ok(read)
+    }
+}
+
 #[derive(Clone)]
 pub struct DownloadProgressTracker {
     pb: ProgressBar,
@@ -31,14 +254,14 @@ impl Default for DownloadProgressTracker {
 impl ReadProgressWrapper for DownloadProgressTracker {
     fn init(self: Box<Self>, len: u64) -> Box<dyn ReadProgressWrapper> {
         let pb = ProgressBar::with_draw_target(len, ProgressDrawTarget::stdout())
-            .with_style(style())
+            .with_style(download_style())
             .with_message("Downloading archive:");
 
         Box::new(DownloadProgressTracker::new(pb))
     }
 
     fn wrap(&self, reader: Box<dyn Read>) -> Box<dyn Read> {
-        Box::new(self.pb.wrap_read(reader))
+        Box::new(RateReader::new(self.pb.clone(), reader))
     }
 
     fn finish(&self, asset: &GeAsset) {
@@ -47,8 +270,113 @@ impl ReadProgressWrapper for DownloadProgressTracker {
     }
 }
 
+/// Download tracker which does not draw anything, e.g. when GE-Man runs in a script.
+#[derive(Clone, Default)]
+pub struct SilentProgressTracker;
+
+impl ReadProgressWrapper for SilentProgressTracker {
+    fn init(self: Box<Self>, _len: u64) -> Box<dyn ReadProgressWrapper> {
+        self
+    }
+
+    fn wrap(&self, reader: Box<dyn Read>) -> Box<dyn Read> {
+        reader
+    }
+
+    fn finish(&self, _asset: &GeAsset) {}
+}
+
+/// Download tracker which writes its progress as JSON events to stderr, see [`JsonProgressReader`].
+#[derive(Clone)]
+pub struct JsonProgressTracker {
+    total: u64,
+}
+
+impl JsonProgressTracker {
+    pub fn new() -> Self {
+        JsonProgressTracker { total: 0 }
+    }
+}
+
+impl Default for JsonProgressTracker {
+    fn default() -> Self {
+        JsonProgressTracker::new()
+    }
+}
+
+impl ReadProgressWrapper for JsonProgressTracker {
+    fn init(self: Box<Self>, len: u64) -> Box<dyn ReadProgressWrapper> {
+        Box::new(JsonProgressTracker { total: len })
+    }
+
+    fn wrap(&self, reader: Box<dyn Read>) -> Box<dyn Read> {
+        Box::new(JsonProgressReader::new(DOWNLOAD_PHASE, self.total, reader))
+    }
+
+    fn finish(&self, _asset: &GeAsset) {}
+}
+
+/// Reader which reports how many bytes passed through it as one JSON object per line on stderr, e.g.
+/// `{"phase":"download","done":1024,"total":4096}`. Events are throttled to one per `JSON_PROGRESS_INTERVAL`, the
+/// last event is always written once the inner reader is exhausted.
+pub struct JsonProgressReader<R> {
+    inner: R,
+    phase: &'static str,
+    done: u64,
+    total: u64,
+    last_event: Option<Instant>,
+}
+
+impl<R: Read> JsonProgressReader<R> {
+    pub fn new(phase: &'static str, total: u64, inner: R) -> Self {
+        JsonProgressReader {
+            inner,
+            phase,
+            done: 0,
+            total,
+            last_event: None,
+        }
+    }
+
+    fn report(&mut self, finished: bool) {
+        let now = Instant::now();
+        let due = self
+            .last_event
+            .map_or(true, |last| now.duration_since(last) >= JSON_PROGRESS_INTERVAL);
+        if finished || due {
+            self.last_event = Some(now);
+            eprintln!("{}", progress_event(self.phase, self.done, self.total));
+        }
+    }
+}
+
+impl<R: Read> Read for JsonProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.done += read as u64;
+        self.report(read == 0 && !buf.is_empty());
+        Ok(read)
+    }
+}
+
+#[derive(Serialize)]
+struct ProgressEvent<'a> {
+    phase: &'a str,
+    done: u64,
+    total: u64,
+}
+
+fn progress_event(phase: &str, done: u64, total: u64) -> String {
+    serde_json::to_string(&ProgressEvent { phase, done, total }).unwrap()
+}
+
+enum ExtractionProgress {
+    Bar(ProgressBar),
+    Json(u64),
+}
+
 pub struct ExtractionProgressTracker {
-    pb: ProgressBar,
+    progress: ExtractionProgress,
 }
 
 impl ExtractionProgressTracker {
@@ -57,14 +385,127 @@ impl ExtractionProgressTracker {
             .with_message("Extracting archive:")
             .with_style(style());
 
-        ExtractionProgressTracker { pb }
+        ExtractionProgressTracker {
+            progress: ExtractionProgress::Bar(pb),
+        }
+    }
+
+    pub fn hidden(len: u64) -> Self {
+        ExtractionProgressTracker {
+            progress: ExtractionProgress::Bar(ProgressBar::with_draw_target(len, ProgressDrawTarget::hidden())),
+        }
+    }
+
+    pub fn json(len: u64) -> Self {
+        ExtractionProgressTracker {
+            progress: ExtractionProgress::Json(len),
+        }
     }
 
-    pub fn inner(&self) -> &ProgressBar {
-        &self.pb
+    pub fn wrap(&self, reader: impl Read + 'static) -> Box<dyn Read> {
+        match &self.progress {
+            ExtractionProgress::Bar(pb) => Box::new(pb.wrap_read(reader)),
+            ExtractionProgress::Json(len) => Box::new(JsonProgressReader::new(EXTRACT_PHASE, *len, reader)),
+        }
     }
 
     pub fn finish(&self) {
-        self.pb.finish_with_message("Finished archive extraction");
+        if let ExtractionProgress::Bar(pb) = &self.progress {
+            pb.finish_with_message("Finished archive extraction");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn progress_event_is_json_object() {
+        assert_eq!(
+            progress_event(DOWNLOAD_PHASE, 1024, 4096),
+            r#"{"phase":"download","done":1024,"total":4096}"#
+        );
+    }
+
+    #[test]
+    fn json_progress_reader_should_pass_content_through() {
+        let content = vec![7u8; 10_000];
+        let mut reader = JsonProgressReader::new(EXTRACT_PHASE, content.len() as u64, content.as_slice());
+
+        let mut read = Vec::new();
+        reader.read_to_end(&mut read).unwrap();
+
+        assert_eq!(read, content);
+        assert_eq!(reader.done, 10_000);
+    }
+
+    #[test_case("2048" => Ok(2048); "bytes")]
+    #[test_case("500K" => Ok(500 * 1024); "kibibytes")]
+    #[test_case("2m" => Ok(2 * 1024 * 1024); "mebibytes")]
+    #[test_case("1G" => Ok(1024 * 1024 * 1024); "gibibytes")]
+    fn parse_rate_with_suffix(value: &str) -> Result<u64, String> {
+        parse_rate(value)
+    }
+
+    #[test_case(""; "empty")]
+    #[test_case("0"; "zero")]
+    #[test_case("K"; "suffix only")]
+    #[test_case("2MB"; "unknown suffix")]
+    #[test_case("-5K"; "negative")]
+    fn parse_rate_rejects_invalid_values(value: &str) {
+        assert!(parse_rate(value).is_err());
+    }
+
+    #[test]
+    fn throttled_reader_should_approximate_the_target_rate() {
+        let content = vec![7u8; 12_000];
+        let mut reader = ThrottledReader::new(40_000, content.as_slice());
+
+        let start = Instant::now();
+        let mut read = Vec::new();
+        reader.read_to_end(&mut read).unwrap();
+        let elapsed = start.elapsed();
+
+        assert_eq!(read, content);
+        // 12 000 bytes at 40 000 bytes per second take 300ms.
+        assert!(
+            elapsed >= Duration::from_millis(280),
+            "finished too fast: {:?}",
+            elapsed
+        );
+        assert!(elapsed < Duration::from_secs(2), "finished too slow: {:?}", elapsed);
+    }
+
+    #[test]
+    fn transfer_rate_is_only_updated_after_a_full_sample() {
+        let start = Instant::now();
+        let mut rate = TransferRate::new(start);
+
+        assert!(!rate.add(1024, start + Duration::from_millis(100)));
+        assert_eq!(rate.bytes_per_sec, None);
+
+        assert!(rate.add(1024, start + Duration::from_millis(500)));
+        assert_eq!(rate.bytes_per_sec, Some(4096.0));
+    }
+
+    #[test]
+    fn transfer_rate_is_smoothed_over_samples() {
+        let start = Instant::now();
+        let mut rate = TransferRate::new(start);
+
+        rate.add(1000, start + Duration::from_secs(1));
+        rate.add(2000, start + Duration::from_secs(2));
+
+        let expected = RATE_SMOOTHING * 2000.0 + (1.0 - RATE_SMOOTHING) * 1000.0;
+        assert!((rate.bytes_per_sec.unwrap() - expected).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn speed_and_eta_formats_minutes_and_seconds() {
+        assert_eq!(speed_and_eta(2.5 * MIB, 225 * 1024 * 1024), "2.5 MiB/s, ETA 01:30");
+        assert_eq!(speed_and_eta(0.0, 1024), "0.0 MiB/s, ETA --:--");
     }
 }
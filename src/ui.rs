@@ -1,22 +1,46 @@
-use std::io::Write;
+use std::cmp::Ordering;
+use std::fs;
+use std::io::{BufRead, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, bail, Context};
 use ge_man_lib::archive;
 use ge_man_lib::config::{LutrisConfig, SteamConfig};
-use ge_man_lib::download::response::DownloadedAssets;
-use ge_man_lib::download::{DownloadRequest, GeDownload};
+use ge_man_lib::download::response::{DownloadedArchive, DownloadedAssets, DownloadedChecksum};
+use ge_man_lib::download::{DownloadRequest, GeDownload, ReadProgressWrapper};
 use ge_man_lib::error::{GithubError, LutrisConfigError, SteamConfigError};
-use ge_man_lib::tag::TagKind;
+use ge_man_lib::tag::{Tag, TagKind};
 use itertools::Itertools;
+use similar::TextDiff;
 
+use crate::application::Application;
 use crate::args::{
-    AddArgs, ApplyArgs, CheckArgs, CopyUserSettingsArgs, ForgetArgs, ListArgs, MigrationArgs, RemoveArgs,
+    AddArgs, ApplyArgs, ApplyStep, CheckArgs, CompletionsArgs, CopyUserSettingsArgs, DiffUserSettingsArgs, ExportArgs,
+    ForgetArgs, ImportArgs, InfoArgs, ListArgs, ListSort, MigrateDirArgs, MigrationArgs, PinArgs, RemoveArgs, TagArg,
+    VerifyArgs,
 };
-use crate::data::{ManagedVersion, ManagedVersions};
-use crate::filesystem::FilesystemManager;
-use crate::path::{xdg_data_home, AppConfigPaths, PathConfiguration};
-use crate::progress::{DownloadProgressTracker, ExtractionProgressTracker};
-use crate::version::{Version, Versioned};
+use crate::cache::{ArchiveCache, LatestTagCache};
+use crate::checksum;
+use crate::checksum::ChecksumAlgorithm;
+use crate::clap::{setup_clap, tag_kind_flag};
+use crate::config::{GeManConfig, ManagedVersionsFormat};
+use crate::data::{CorruptManifestError, ManagedVersion, ManagedVersions};
+use crate::error::CommandError;
+use crate::filesystem::{AppConfigRestore, AppConfigUpdate, FilesystemManager, MigrationAction};
+use crate::hook;
+use crate::notification;
+use crate::path::{
+    steam_compat_tool_paths, steam_path, xdg_cache_home, xdg_config_home, xdg_data_home, AppConfigPaths,
+    PathConfiguration, STEAM_COMPAT_TOOL_PATHS_ENV,
+};
+use crate::progress::{
+    DownloadProgressTracker, ExtractionProgressTracker, JsonProgressTracker, ProgressFormat, RateLimitedTracker,
+    SilentProgressTracker,
+};
+use crate::version::{cmp_tags, is_valid_label, version_from_directory_name, Version, Versioned};
 
 const PROTON_APPLY_HINT: &str = "Successfully modified Steam config: If Steam is currently running, \
 any external change by GE-Man will not take effect and the new version can not be selected in the Steam settings!
@@ -25,9 +49,19 @@ any external change by GE-Man will not take effect and the new version can not b
  \t1. Restart Steam to select the new version in Steam (which then requires a second restart for Steam to register the change).
  \t2. Close Steam and run the apply command for your desired version. On the next start Steam will use the applied version.";
 
+const LUTRIS_MODIFIED_CONFIG_HINT: &str =
+    "Successfully modified Lutris config: Lutris should be restarted for the new settings to take effect.";
+const LUTRIS_CREATED_CONFIG_HINT: &str = "No Wine runner config existed for Lutris: Created an initial config. Lutris \
+should be restarted for the new settings to take effect.";
+
+const REINSTALL_SCRIPT_HEADER: &str = "#!/bin/sh
+# Generated by GE-Man. Run this script to recreate the managed versions on another machine.
+set -e
+";
+
 trait AppConfig {
     fn version_dir_name(&self) -> String;
-    fn kind(&self) -> String;
+    fn application(&self) -> Application;
 }
 
 impl AppConfig for SteamConfig {
@@ -35,8 +69,8 @@ impl AppConfig for SteamConfig {
         self.proton_version()
     }
 
-    fn kind(&self) -> String {
-        String::from("Steam")
+    fn application(&self) -> Application {
+        Application::Steam
     }
 }
 
@@ -45,550 +79,6347 @@ impl AppConfig for LutrisConfig {
         self.wine_version()
     }
 
-    fn kind(&self) -> String {
-        String::from("Lutris")
+    fn application(&self) -> Application {
+        Application::Lutris
     }
 }
 
 trait AppConfigError {
-    fn kind(&self) -> String;
+    fn application(&self) -> Application;
 }
 
 impl AppConfigError for SteamConfigError {
-    fn kind(&self) -> String {
-        String::from("Steam")
+    fn application(&self) -> Application {
+        Application::Steam
     }
 }
 
 impl AppConfigError for LutrisConfigError {
-    fn kind(&self) -> String {
-        String::from("Lutris")
+    fn application(&self) -> Application {
+        Application::Lutris
+    }
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Creates a shell script which adds every given version with `geman add`.
+fn create_reinstall_script(versions: &[ManagedVersion]) -> String {
+    let mut script = String::from(REINSTALL_SCRIPT_HEADER);
+    for version in versions {
+        let label = version
+            .label()
+            .map(|label| format!(" --label {}", shell_quote(label)))
+            .unwrap_or_default();
+        script.push_str(&format!(
+            "geman add --skip-existing {} {}{}\n",
+            tag_kind_flag(version.kind()),
+            shell_quote(version.tag().value()),
+            label
+        ));
+    }
+    script
+}
+
+/// Read a local release archive and its optional checksum file in the same form as downloaded release assets.
+fn read_local_assets(
+    archive_path: &Path,
+    checksum_path: Option<&Path>,
+) -> anyhow::Result<(DownloadedArchive, Option<DownloadedChecksum>)> {
+    let file_name = |path: &Path| {
+        path.file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    };
+
+    let archive = DownloadedArchive {
+        compressed_content: fs::read(archive_path)
+            .context(format!("Could not read archive '{}'", archive_path.display()))?,
+        file_name: file_name(archive_path),
+    };
+
+    let checksum = match checksum_path {
+        Some(path) => Some(DownloadedChecksum {
+            checksum: fs::read_to_string(path).context(format!("Could not read checksum '{}'", path.display()))?,
+            file_name: file_name(path),
+        }),
+        None => None,
+    };
+
+    Ok((archive, checksum))
+}
+
+/// Check whether a tag matches a list filter. A pattern with `*` or `?` wildcards has to match the whole tag, any other
+/// pattern only has to be contained in the tag.
+fn tag_matches(tag: &str, pattern: &str) -> bool {
+    fn glob_matches(text: &[char], pattern: &[char]) -> bool {
+        match pattern.split_first() {
+            None => text.is_empty(),
+            Some(('*', rest)) => (0..=text.len()).any(|skip| glob_matches(&text[skip..], rest)),
+            Some(('?', rest)) => !text.is_empty() && glob_matches(&text[1..], rest),
+            Some((c, rest)) => text.first() == Some(c) && glob_matches(&text[1..], rest),
+        }
+    }
+
+    if pattern.contains(['*', '?']) {
+        glob_matches(&tag.chars().collect_vec(), &pattern.chars().collect_vec())
+    } else {
+        tag.contains(pattern)
+    }
+}
+
+/// Warn when the given tag is managed for more than one kind, because without a kind flag the default kind is used and
+/// that may not be the version the user means.
+fn warn_if_ambiguous(
+    stdout: &mut impl Write,
+    managed_versions: &ManagedVersions,
+    tag: &Tag,
+    kind: &TagKind,
+) -> anyhow::Result<()> {
+    let kinds = managed_versions.kinds_of_tag(tag);
+    if kinds.len() > 1 {
+        writeln!(
+            stdout,
+            "Warning: {} is managed as {}. Using the default kind {}, pass a kind flag to choose another one.",
+            tag,
+            kinds.iter().map(TagKind::compatibility_tool_name).join(", "),
+            kind.compatibility_tool_name()
+        )?;
+    }
+    Ok(())
+}
+
+/// Ask the user a yes/no question. Everything except an explicit yes is treated as no.
+fn confirm(stdout: &mut impl Write, stdin: &mut impl BufRead, question: &str) -> anyhow::Result<bool> {
+    write!(stdout, "{} [y/N] ", question)?;
+    stdout.flush()?;
+
+    let mut answer = String::new();
+    stdin.read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Steam and Lutris silently fall back to a different version if the applied version directory was removed outside of
+/// GE-Man, so the user is warned about it.
+fn missing_in_use_warning(application: &Application, dir_name: &str) -> String {
+    format!(
+        "Warning: {} uses {}, but the directory does not exist anymore. Apply a different version to fix this.",
+        application, dir_name
+    )
+}
+
+/// Format a size in bytes with binary units, e.g. `412 MiB`.
+fn format_size(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes;
+    let mut unit = 0;
+    while size >= 1024 && unit < units.len() - 1 {
+        size /= 1024;
+        unit += 1;
+    }
+    format!("{} {}", size, units[unit])
+}
+
+/// Format the disk usage of a listed directory. A missing directory must not fail the whole listing.
+fn format_directory_size(size: anyhow::Result<u64>) -> String {
+    match size {
+        Ok(size) => format_size(size),
+        Err(_) => String::from("(missing)"),
+    }
+}
+
+/// Format a duration with minutes and seconds, e.g. `1m23s`.
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 60 {
+        format!("{}m{}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Delay before the given retry attempt (starting at 0). The delay doubles with every attempt and is capped at
+/// `MAX_RETRY_DELAY`.
+fn backoff_delay(base_delay: Duration, attempt: u32) -> Duration {
+    base_delay
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_RETRY_DELAY)
+}
+
+/// Run a Github request and retry it up to `retries` times with an exponential backoff. Errors for which `retryable`
+/// returns false can not be fixed by another request and are returned immediately.
+fn retry<T>(
+    retries: usize,
+    base_delay: Duration,
+    retryable: impl Fn(&GithubError) -> bool,
+    mut request: impl FnMut() -> Result<T, GithubError>,
+) -> anyhow::Result<T> {
+    let mut attempt = 0;
+    loop {
+        match request() {
+            Ok(value) => return Ok(value),
+            Err(err) if !retryable(&err) => return Err(anyhow!(err)),
+            Err(err) if attempt >= retries => {
+                let err = anyhow!(err);
+                if retries == 0 {
+                    return Err(err);
+                }
+                return Err(err.context(format!("Retries exhausted ({} attempts)", attempt + 1)));
+            }
+            Err(_) => {
+                thread::sleep(backoff_delay(base_delay, attempt as u32));
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// HTTP status codes of temporary GitHub failures: rate limits and server errors.
+const RETRYABLE_STATUS_CODES: [u16; 5] = [429, 500, 502, 503, 504];
+
+/// Only rate limits, server errors and connection problems are temporary. Every other error, e.g. a 404 for a mistyped
+/// tag or a release without assets, fails the same way on the next request.
+fn is_retryable(err: &GithubError) -> bool {
+    match err {
+        GithubError::ReqwestError { source } => match source.status() {
+            Some(status) => RETRYABLE_STATUS_CODES.contains(&status.as_u16()),
+            None => source.is_connect() || source.is_timeout(),
+        },
+        _ => false,
     }
 }
 
+const OFFLINE_ERROR: &str = "Offline mode: network operations are disabled";
+/// Directory in the GE-Man cache in which `add --keep-archive` saves archives.
+const KEPT_ARCHIVES_DIR: &str = "archives";
+
 /// Handles user interaction and user feedback. This struct basically ties everything together to provide the
 /// functionality of each terminal command.
 pub struct TerminalWriter<'a> {
-    ge_downloader: &'a dyn GeDownload,
+    ge_downloader: &'a (dyn GeDownload + Sync),
     fs_mng: &'a dyn FilesystemManager,
     path_cfg: &'a dyn PathConfiguration,
+    config: GeManConfig,
+    interactive: bool,
+    assume_yes: bool,
+    offline: bool,
+    latest_tag_cache: Option<LatestTagCache>,
 }
 
 impl<'a> TerminalWriter<'a> {
     pub fn new(
-        ge_downloader: &'a dyn GeDownload,
+        ge_downloader: &'a (dyn GeDownload + Sync),
+        fs_mng: &'a dyn FilesystemManager,
+        path_cfg: &'a dyn PathConfiguration,
+    ) -> Self {
+        TerminalWriter::with_config(ge_downloader, fs_mng, path_cfg, GeManConfig::default())
+    }
+
+    pub fn with_config(
+        ge_downloader: &'a (dyn GeDownload + Sync),
         fs_mng: &'a dyn FilesystemManager,
         path_cfg: &'a dyn PathConfiguration,
+        config: GeManConfig,
     ) -> Self {
         TerminalWriter {
             ge_downloader,
             fs_mng,
             path_cfg,
+            config,
+            interactive: false,
+            assume_yes: false,
+            offline: false,
+            latest_tag_cache: None,
         }
     }
 
-    fn create_list_line(
-        &self,
-        version: ManagedVersion,
-        wine_version_dir_name: Option<&String>,
-        proton_version_dir_name: Option<&String>,
-    ) -> String {
-        if let Some(dir) = wine_version_dir_name {
-            if dir.eq(version.directory_name()) {
-                return format!("{} - In use by Lutris", version.tag());
-            }
-        }
-
-        if let Some(dir) = proton_version_dir_name {
-            if dir.eq(version.directory_name()) {
-                return format!("{} - In use by Steam", version.tag());
-            }
-        }
+    /// Whether the user can answer confirmation prompts. Without a user, commands which need a confirmation fail.
+    pub fn set_interactive(&mut self, interactive: bool) {
+        self.interactive = interactive;
+    }
 
-        version.tag().value().clone()
+    /// Answer every confirmation prompt with yes, e.g. with the global `--assume-yes` flag.
+    pub fn set_assume_yes(&mut self, assume_yes: bool) {
+        self.assume_yes = assume_yes;
     }
 
-    fn read_managed_versions(&self) -> anyhow::Result<ManagedVersions> {
-        let path = self.path_cfg.managed_versions_config(xdg_data_home());
-        ManagedVersions::from_file(&path)
-            .context(format!("Could not read managed_versions.json from {}", path.display()))
+    /// Fail every operation which needs the network instead of contacting GitHub, e.g. with the global `--offline`
+    /// flag.
+    pub fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
     }
 
-    fn write_managed_versions(&self, managed_versions: ManagedVersions) -> anyhow::Result<()> {
-        let path = self.path_cfg.managed_versions_config(xdg_data_home());
-        managed_versions.write_to_file(&path)
+    fn ensure_online(&self) -> anyhow::Result<()> {
+        if self.offline {
+            bail!(CommandError::network(OFFLINE_ERROR));
+        }
+        Ok(())
     }
 
-    pub fn list(&self, stdout: &mut impl Write, args: ListArgs, config_paths: AppConfigPaths) -> anyhow::Result<()> {
-        let wine_dir_name = match LutrisConfig::create_copy(&config_paths.lutris) {
-            Ok(config) => Some(config.wine_version()),
-            Err(_) => None,
-        };
+    /// Cache which is consulted before fetching the latest tag of a kind from GitHub.
+    pub fn set_latest_tag_cache(&mut self, cache: LatestTagCache) {
+        self.latest_tag_cache = Some(cache);
+    }
 
-        let proton_dir_name = match SteamConfig::create_copy(&config_paths.steam) {
-            Ok(config) => Some(config.proton_version()),
-            Err(_) => None,
-        };
+    fn create_list_line(&self, version: ManagedVersion, in_use_dir_names: &[(Application, String)]) -> String {
+        let in_use_by = in_use_dir_names
+            .iter()
+            .filter(|(application, dir)| application.uses(version.kind()) && dir.eq(version.directory_name()))
+            .map(|(application, _)| application)
+            .join(", ");
 
-        let mut managed_versions: Vec<ManagedVersion> = if args.newest {
-            self.read_managed_versions()?.latest_versions()
+        let mut line = if in_use_by.is_empty() {
+            version.tag().value().clone()
         } else {
-            self.read_managed_versions()?.versions()
+            format!("{} - In use by {}", version.tag(), in_use_by)
         };
+        if version.pinned() {
+            line.push_str(" (pinned)");
+        }
+        line
+    }
 
-        if let Some(kind) = args.kind {
-            managed_versions.retain(|v| v.kind().eq(&kind));
+    /// Collect the directory names of the versions that are currently used by each supported application. Bottles can
+    /// use several versions at once, so every version linked into its runners directory is collected.
+    fn read_in_use_dir_names(&self, config_paths: &AppConfigPaths) -> Vec<(Application, String)> {
+        let mut in_use = Vec::new();
+
+        if let Ok(config) = SteamConfig::create_copy(&config_paths.steam) {
+            in_use.push((config.application(), config.version_dir_name()));
+        }
+        if let Ok(config) = LutrisConfig::create_copy(&config_paths.lutris) {
+            in_use.push((config.application(), config.version_dir_name()));
+        }
+        if let Ok(entries) = fs::read_dir(&config_paths.bottles_runners) {
+            let linked_dir_names = entries
+                .flatten()
+                .filter(|entry| entry.file_type().map_or(false, |file_type| file_type.is_symlink()))
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .sorted();
+            in_use.extend(linked_dir_names.map(|dir_name| (Application::Bottles, dir_name)));
         }
 
-        if !managed_versions.is_empty() {
-            // Allow clone of version.kind() due to lifetime not living long enough.
-            #[allow(clippy::clone_on_copy)]
-            let grouped_versions = managed_versions
-                .into_iter()
-                .sorted_unstable_by(|a, b| a.kind().cmp(b.kind()))
-                .group_by(|version| version.kind().clone());
+        in_use
+    }
 
-            for (kind, group) in &grouped_versions {
-                writeln!(stdout, "{}:", kind.compatibility_tool_name()).unwrap();
+    /// Run a Github request and retry it as often as configured.
+    fn with_retries<T>(&self, request: impl FnMut() -> Result<T, GithubError>) -> anyhow::Result<T> {
+        retry(self.config.retries, self.config.retry_delay(), is_retryable, request)
+    }
 
-                group
-                    .sorted_unstable_by(|a, b| a.tag().cmp_semver(b.tag()).reverse())
-                    .for_each(|version| {
-                        let line = self.create_list_line(version, wine_dir_name.as_ref(), proton_dir_name.as_ref());
-                        writeln!(stdout, "* {}", line).unwrap();
-                    });
+    fn cached_latest_tag(&self, kind: &TagKind, refresh: bool) -> Option<String> {
+        if refresh {
+            return None;
+        }
+        self.latest_tag_cache.as_ref()?.get(kind)
+    }
 
-                writeln!(stdout).unwrap();
-            }
-        } else {
-            writeln!(stdout, "No versions installed").unwrap();
+    fn cache_latest_tag(&self, kind: &TagKind, tag: &str) {
+        if let Some(cache) = &self.latest_tag_cache {
+            // The cache only saves requests, so a failed write must not fail the command.
+            let _ = cache.put(kind, tag);
         }
-        Ok(())
     }
 
-    pub fn add(&self, stdout: &mut impl Write, args: AddArgs) -> anyhow::Result<()> {
-        let tag = args.tag_arg.value();
-        let kind = args.tag_arg.kind;
-        let mut managed_versions = self.read_managed_versions()?;
+    /// Warn when an explicit tag is newer than the latest known release of its kind, because such a tag is most likely
+    /// a typo or was removed upstream. The check only runs with the latest tag cache, which is fetched once without
+    /// retries when it misses. A failed request is ignored, since the download reports a missing release anyway.
+    fn warn_if_newer_than_latest(&self, stdout: &mut impl Write, version: &Version) -> anyhow::Result<()> {
+        if self.latest_tag_cache.is_none() {
+            return Ok(());
+        }
 
-        let version = if tag.is_some() {
-            Version::new(tag.cloned(), kind)
-        } else {
-            match self.ge_downloader.fetch_release(tag.cloned(), kind) {
-                Ok(release) => Version::new(release.tag_name, kind),
+        let latest = match self.cached_latest_tag(version.kind(), false) {
+            Some(latest) => latest,
+            None if self.offline => return Ok(()),
+            None => match self.ge_downloader.fetch_release(None, *version.kind()) {
+                Ok(release) => {
+                    self.cache_latest_tag(version.kind(), &release.tag_name);
+                    release.tag_name
+                }
                 Err(err) => {
-                    return Err(anyhow!(err).context(r#"Could not get latest tag for tagless "add" operation."#))
+                    log::debug!("Could not fetch the latest tag to check {}: {:#}", version.tag(), err);
+                    return Ok(());
                 }
-            }
+            },
         };
 
-        if managed_versions.find_version(&version).is_some() {
-            writeln!(stdout, "Version {} is already managed", version)?;
-            return Ok(());
+        let latest = Tag::from(latest);
+        if cmp_tags(version.tag(), &latest) == Ordering::Greater {
+            writeln!(
+                stdout,
+                "Warning: {} is newer than the latest known release {}, it may not exist.",
+                version.tag(),
+                latest
+            )?;
         }
+        Ok(())
+    }
 
-        let download_tracker = Box::new(DownloadProgressTracker::default());
-        let request = DownloadRequest::new(
-            Some(version.tag().to_string()),
-            *version.kind(),
-            download_tracker,
-            args.skip_checksum,
-        );
-
-        let assets = match self.ge_downloader.download_release_assets(request) {
-            Ok(assets) => assets,
-            Err(err) => {
-                let res = if let GithubError::ReleaseHasNoAssets { tag, kind } = err {
-                    let err = GithubError::ReleaseHasNoAssets { tag: tag.clone(), kind };
+    /// Get the latest tag of the kind from the latest tag cache or fetch it from GitHub if it is not cached.
+    fn latest_tag(&self, kind: TagKind, refresh: bool) -> anyhow::Result<String> {
+        if let Some(tag) = self.cached_latest_tag(&kind, refresh) {
+            return Ok(tag);
+        }
 
-                    anyhow!(err).context(format!(
-                        "The given release has no assets for {} {}. It might be possible that the \
-                        release assets have been removed due to fixes in a newer version.",
-                        tag, kind
-                    ))
-                } else {
-                    anyhow!(err).context("Could not fetch release assets from Github")
-                };
+        let tag = self
+            .with_retries(|| self.ge_downloader.fetch_release(None, kind))?
+            .tag_name;
+        self.cache_latest_tag(&kind, &tag);
+        Ok(tag)
+    }
 
-                bail!(res);
-            }
-        };
+    fn managed_versions_path(&self, default_path: &Path) -> PathBuf {
+        default_path.with_extension(self.config.managed_versions_format.extension())
+    }
 
-        let DownloadedAssets {
-            compressed_archive: compressed_tar,
-            checksum,
-            ..
-        } = assets;
+    /// Managed versions file of the format which was configured before `managed_versions_format` was switched.
+    fn previous_managed_versions_path(&self, default_path: &Path) -> Option<PathBuf> {
+        [ManagedVersionsFormat::Json, ManagedVersionsFormat::Toml]
+            .iter()
+            .map(|format| default_path.with_extension(format.extension()))
+            .find(|path| path.ne(&self.managed_versions_path(default_path)) && path.exists())
+    }
 
-        if args.skip_checksum {
-            writeln!(stdout, "Skipping checksum comparison").unwrap();
-        } else {
-            write!(stdout, "Performing checksum comparison").unwrap();
-            let checksum = checksum.unwrap();
+    /// Read a manifest which was given on the command line. Unlike the managed versions file it has to exist.
+    fn read_manifest(&self, path: &Path) -> anyhow::Result<ManagedVersions> {
+        if !path.exists() {
+            bail!(CommandError::not_found(format!(
+                "Manifest {} does not exist",
+                path.display()
+            )));
+        }
+        ManagedVersions::from_file(path).context(format!("Could not read manifest {}", path.display()))
+    }
 
-            let result = archive::checksums_match(&compressed_tar.compressed_content, checksum.checksum.as_bytes());
+    fn read_managed_versions(&self) -> anyhow::Result<ManagedVersions> {
+        let default_path = self.path_cfg.managed_versions_config(xdg_data_home());
+        let mut path = self.managed_versions_path(&default_path);
 
-            if !result {
-                bail!("Checksum comparison failed: Checksum generated from downloaded archive does not match downloaded expected checksum");
-            } else {
-                writeln!(stdout, ": Checksums match").unwrap();
+        // Keep reading the previous file until the first write after switching the format.
+        if !path.exists() {
+            if let Some(previous_path) = self.previous_managed_versions_path(&default_path) {
+                path = previous_path;
             }
         }
+        log::debug!("Reading managed versions from {}", path.display());
 
-        let extraction_tracker = ExtractionProgressTracker::new(compressed_tar.compressed_content.len() as u64);
-        let compressed_tar_reader = extraction_tracker
-            .inner()
-            .wrap_read(std::io::Cursor::new(compressed_tar.compressed_content));
-
-        let version = self
-            .fs_mng
-            .setup_version(version, Box::new(compressed_tar_reader))
-            .context("Could not add version")?;
-        extraction_tracker.finish();
-
-        let version = managed_versions.add(version)?;
-        self.write_managed_versions(managed_versions)?;
+        ManagedVersions::from_file(&path).context(format!("Could not read managed versions from {}", path.display()))
+    }
 
-        writeln!(stdout, "Successfully added version").unwrap();
-        if args.apply {
-            self.do_apply_to_app_config(stdout, &version)?;
+    fn write_managed_versions(&self, managed_versions: ManagedVersions) -> anyhow::Result<()> {
+        let default_path = self.path_cfg.managed_versions_config(xdg_data_home());
+        let path = self.managed_versions_path(&default_path);
+        log::debug!("Writing managed versions to {}", path.display());
+        managed_versions.write_to_file(&path)?;
+
+        // The previous file would otherwise be read again after switching back and hide every later change.
+        if let Some(previous_path) = self.previous_managed_versions_path(&default_path) {
+            log::info!(
+                "Removing managed versions of the previous format {}",
+                previous_path.display()
+            );
+            fs::remove_file(&previous_path).context(format!(
+                "Could not remove managed versions of the previous format {}",
+                previous_path.display()
+            ))?;
         }
-
         Ok(())
     }
 
-    pub fn remove(
+    pub fn list(
         &self,
         stdout: &mut impl Write,
-        args: RemoveArgs,
+        stderr: &mut impl Write,
+        args: ListArgs,
         config_paths: AppConfigPaths,
     ) -> anyhow::Result<()> {
-        let version = args.tag_arg.version();
-        let mut managed_versions = self.read_managed_versions()?;
+        let in_use_dir_names = self.read_in_use_dir_names(&config_paths);
+        if args.merged {
+            return self.list_merged(stdout, stderr, args.kind, args.size, &in_use_dir_names);
+        }
 
-        let version = match managed_versions.find_version(&version) {
-            Some(v) => v,
-            None => bail!("Given version is not managed"),
+        let mut managed_versions = match &args.manifest {
+            Some(path) => self.read_manifest(path)?.versions(),
+            None => self.read_managed_versions()?.versions(),
         };
+        // The versions of a given manifest do not have to be installed on this machine.
+        if args.manifest.is_none() {
+            for (application, dir_name) in &in_use_dir_names {
+                let in_use_version = managed_versions
+                    .iter()
+                    .find(|v| application.uses(v.kind()) && v.directory_name().eq(dir_name));
+                if let Some(version) = in_use_version.filter(|v| !self.fs_mng.version_exists(v)) {
+                    writeln!(
+                        stderr,
+                        "{}",
+                        missing_in_use_warning(application, version.directory_name())
+                    )?;
+                }
+            }
+        }
 
-        match &version.kind() {
-            TagKind::Proton => {
-                let path = &config_paths.steam;
-                let config = SteamConfig::create_copy(path)
-                    .map_err(|err| anyhow!(err))
-                    .context(format!("Failed to read Steam config: {}", path.display()))?;
+        if let Some(filter) = &args.filter {
+            managed_versions.retain(|v| tag_matches(v.tag().value(), filter));
+        }
+        if args.newest {
+            managed_versions = ManagedVersions::new(managed_versions).latest_versions();
+        }
 
-                if self.check_if_version_in_use_by_config(&version, &config) {
-                    bail!("Proton version is in use by Steam. Select a different version to make removal possible.");
+        // Wine GE LoL versions are a kind of their own and only belong to the Wine GE listing when asked for.
+        if let Some(kind) = args.kind {
+            let include_lol = args.include_lol && kind.eq(&TagKind::wine());
+            managed_versions.retain(|v| v.kind().eq(&kind) || (include_lol && v.kind().eq(&TagKind::lol())));
+        }
+
+        // Allow clone of version.kind() due to lifetime not living long enough.
+        #[allow(clippy::clone_on_copy)]
+        // The sort has to be stable to keep the order in which versions were added within a kind.
+        let grouped_versions = managed_versions
+            .into_iter()
+            .sorted_by(|a, b| a.kind().cmp(b.kind()))
+            .group_by(|version| version.kind().clone());
+
+        let mut groups = Vec::new();
+        for (kind, group) in &grouped_versions {
+            let versions = match args.sort {
+                ListSort::Semver => group
+                    .sorted_unstable_by(|a, b| a.tag().cmp_semver(b.tag()).reverse())
+                    .collect_vec(),
+                ListSort::Name => group
+                    .sorted_unstable_by(|a, b| a.tag().value().cmp(b.tag().value()))
+                    .collect_vec(),
+                ListSort::Added => group.collect_vec(),
+            };
+            let total = versions.len();
+            let versions = match args.limit {
+                Some(limit) => versions
+                    .into_iter()
+                    .skip(limit * (args.page - 1))
+                    .take(limit)
+                    .collect_vec(),
+                None => versions,
+            };
+            groups.push((kind, versions, total));
+        }
+        if args.all {
+            for kind in [TagKind::Proton, TagKind::wine(), TagKind::lol()] {
+                if !groups.iter().any(|(group_kind, _, _)| group_kind.eq(&kind)) {
+                    groups.push((kind, Vec::new(), 0));
                 }
             }
-            TagKind::Wine { .. } => {
-                let path = &config_paths.lutris;
-                let config = LutrisConfig::create_copy(path);
-                match config {
-                    Ok(config) => {
-                        if self.check_if_version_in_use_by_config(&version, &config) {
-                            bail!(
-                                "Wine version is in use by Lutris. Select a different version to make removal \
-                            possible."
-                            );
-                        }
-                    }
-                    Err(err) => {
-                        if let LutrisConfigError::IoError { source } = &err {
-                            if source.raw_os_error().unwrap() != 2 {
-                                bail!(err);
-                            }
-                        }
-                    }
-                }
+            groups.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        }
+
+        if args.json {
+            let versions = groups.into_iter().flat_map(|(_, versions, _)| versions).collect_vec();
+            return self.list_json(stdout, versions, &in_use_dir_names);
+        }
+
+        if groups.is_empty() {
+            match &args.filter {
+                Some(filter) => writeln!(stdout, "No versions match {}", filter).unwrap(),
+                None => writeln!(stdout, "No versions installed").unwrap(),
             }
         }
 
-        self.fs_mng.remove_version(&version).unwrap();
-        managed_versions.remove(&version).unwrap();
+        for (kind, versions, total) in groups {
+            writeln!(stdout, "{}:", kind.compatibility_tool_name()).unwrap();
+            if total == 0 {
+                match &args.filter {
+                    Some(filter) => writeln!(stdout, "No versions match {}", filter).unwrap(),
+                    None => writeln!(stdout, "No versions installed").unwrap(),
+                }
+            }
 
-        self.write_managed_versions(managed_versions)?;
-        writeln!(stdout, "Successfully removed version {}.", version).unwrap();
+            let shown = versions.len();
+            versions.into_iter().for_each(|version| {
+                let size = args
+                    .size
+                    .then(|| format_directory_size(self.fs_mng.version_size(&version)));
+                let version_added_at = version.added_at().cloned();
+                let mut line = self.create_list_line(version, &in_use_dir_names);
+                if let Some(size) = size {
+                    line.push_str(&format!(" - {}", size));
+                }
+                if args.show_added {
+                    let added_at = version_added_at.as_deref().unwrap_or("unknown");
+                    line.push_str(&format!(" - added {}", added_at));
+                }
+                writeln!(stdout, "* {}", line).unwrap();
+            });
+
+            if shown < total {
+                writeln!(stdout, "(showing {} of {})", shown, total).unwrap();
+            }
+            writeln!(stdout).unwrap();
+        }
         Ok(())
     }
 
-    fn check_if_version_in_use_by_config<T>(&self, version: &ManagedVersion, app_config: &T) -> bool
-    where
-        T: AppConfig,
-    {
-        app_config.version_dir_name().eq(version.directory_name())
+    /// Print the managed versions as a JSON array. Every entry contains the application which uses the version kind,
+    /// whether the version is currently in use by that application and every application which currently uses it.
+    fn list_json(
+        &self,
+        stdout: &mut impl Write,
+        versions: Vec<ManagedVersion>,
+        in_use_dir_names: &[(Application, String)],
+    ) -> anyhow::Result<()> {
+        let entries = versions
+            .iter()
+            .map(|version| {
+                let application = Application::for_kind(version.kind());
+                let in_use_by = in_use_dir_names
+                    .iter()
+                    .filter(|(app, dir)| app.uses(version.kind()) && dir.eq(version.directory_name()))
+                    .map(|(app, _)| app.to_string())
+                    .collect_vec();
+
+                serde_json::json!({
+                    "tag": version.tag().value(),
+                    "kind": version.kind(),
+                    "label": version.label(),
+                    "directory_name": version.directory_name(),
+                    "in_use": in_use_by.contains(&application.to_string()),
+                    "in_use_by": in_use_by,
+                    "pinned": version.pinned(),
+                    "added_at": version.added_at(),
+                    "application": application.to_string(),
+                })
+            })
+            .collect_vec();
+
+        let json = serde_json::to_string_pretty(&entries).context("Could not serialize managed versions to JSON")?;
+        writeln!(stdout, "{}", json)?;
+        Ok(())
     }
 
-    pub fn check(&self, stdout: &mut impl Write, stderr: &mut impl Write, args: CheckArgs) {
-        match args.kind {
-            Some(kind) => match self.ge_downloader.fetch_release(None, kind) {
-                Ok(release) => {
-                    writeln!(
-                        stdout,
-                        "The latest version of {} is \"{}\"",
-                        kind.compatibility_tool_name(),
-                        release.tag_name
-                    )
-                    .unwrap();
-                }
-                Err(err) => {
-                    writeln!(stderr, "Could not fetch latest release from Github: {}", err).unwrap();
-                }
-            },
-            None => {
-                let proton = self.ge_downloader.fetch_release(None, TagKind::Proton);
-                let wine = self.ge_downloader.fetch_release(None, TagKind::wine());
-                let lol = self.ge_downloader.fetch_release(None, TagKind::lol());
+    /// List every directory of the Steam and Lutris version directories and show whether GE-Man manages it.
+    fn list_merged(
+        &self,
+        stdout: &mut impl Write,
+        stderr: &mut impl Write,
+        kind: Option<TagKind>,
+        size: bool,
+        in_use_dir_names: &[(Application, String)],
+    ) -> anyhow::Result<()> {
+        let managed_versions = self.read_managed_versions()?.versions();
 
-                writeln!(stdout, "These are the latest releases.").unwrap();
-                writeln!(stdout).unwrap();
-                match proton {
-                    Ok(release) => writeln!(stdout, "Proton GE: {}", release.tag_name).unwrap(),
-                    Err(err) => writeln!(
-                        stderr,
-                        "Proton GE: Could not fetch release information from GitHub: {}",
-                        err
-                    )
-                    .unwrap(),
+        for dir_kind in [TagKind::Proton, TagKind::wine()] {
+            let application = Application::for_kind(&dir_kind);
+            if let Some(kind) = &kind {
+                if Application::for_kind(kind) != application {
+                    continue;
                 }
+            }
 
-                match wine {
-                    Ok(release) => writeln!(stdout, "Wine GE: {}", release.tag_name).unwrap(),
-                    Err(err) => writeln!(
-                        stderr,
-                        "Wine GE: Could not fetch release information from GitHub: {}",
-                        err
-                    )
-                    .unwrap(),
-                }
+            writeln!(stdout, "{}:", application)?;
+            let dir_names = self.fs_mng.list_directory_names(&dir_kind)?;
+
+            let dir_path = match dir_kind {
+                _ if !size => None,
+                TagKind::Proton => Some(self.path_cfg.steam_compatibility_tools_dir(steam_path())),
+                TagKind::Wine { .. } => Some(self.path_cfg.lutris_runners_dir(xdg_data_home())),
+            };
+            let mut directories = dir_names.into_iter().map(|name| (name, None)).collect_vec();
+            if application == Application::Steam {
+                let compat_tool_dirs = self.fs_mng.list_compat_tool_path_directories(steam_compat_tool_paths());
+                directories.extend(compat_tool_dirs.into_iter().map(|(origin, name)| (name, Some(origin))));
+            }
 
-                match lol {
-                    Ok(release) => writeln!(stdout, "Wine GE - LoL: {}", release.tag_name).unwrap(),
-                    Err(err) => writeln!(
-                        stderr,
-                        "Wine GE - LoL: Could not fetch release information from GitHub: {}",
-                        err
-                    )
-                    .unwrap(),
+            if directories.is_empty() {
+                writeln!(stdout, "No directories found")?;
+            }
+
+            let in_use_dir_name = in_use_dir_names
+                .iter()
+                .find(|(app, _)| app == &application)
+                .map(|(_, dir_name)| dir_name);
+            if let Some(dir_name) = in_use_dir_name {
+                let managed = managed_versions
+                    .iter()
+                    .any(|version| version.directory_name().eq(dir_name) && application.uses(version.kind()));
+                if managed && !directories.iter().any(|(name, _)| name.eq(dir_name)) {
+                    writeln!(stderr, "{}", missing_in_use_warning(&application, dir_name))?;
                 }
             }
-        }
-    }
 
-    pub fn migrate(&self, stdout: &mut impl Write, args: MigrationArgs) -> anyhow::Result<()> {
-        let version = args.tag_arg.version();
-        let mut managed_versions = self.read_managed_versions()?;
+            for (dir_name, origin) in directories {
+                let managed_version = managed_versions
+                    .iter()
+                    .find(|version| version.directory_name().eq(&dir_name) && application.uses(version.kind()));
+                let mut line = match managed_version {
+                    Some(version) => format!(
+                        "* {} - {} ({})",
+                        dir_name,
+                        version.tag(),
+                        version.kind().compatibility_tool_name()
+                    ),
+                    None => format!("* {} - unmanaged", dir_name),
+                };
 
-        if managed_versions.find_version(&version).is_some() {
-            bail!("Given version to migrate already exists as a managed version");
-        }
+                let in_use_by = in_use_dir_names
+                    .iter()
+                    .filter(|(app, in_use_dir)| app.uses(&dir_kind) && in_use_dir.eq(&dir_name))
+                    .map(|(app, _)| app)
+                    .join(", ");
+                if !in_use_by.is_empty() {
+                    line.push_str(&format!(" - In use by {}", in_use_by));
+                }
+                if let Some(dir_path) = origin.as_ref().or(dir_path.as_ref()).filter(|_| size) {
+                    let dir_size = self.fs_mng.directory_size(&dir_path.join(&dir_name));
+                    line.push_str(&format!(" - {}", format_directory_size(dir_size)));
+                }
+                if let Some(origin) = origin {
+                    line.push_str(&format!(" [{}: {}]", STEAM_COMPAT_TOOL_PATHS_ENV, origin.display()));
+                }
 
-        let source_path = &args.source_path;
-        let version = self
-            .fs_mng
-            .migrate_folder(version, source_path)
-            .context("Could not migrate directory")?;
-        let version = managed_versions.add(version)?;
+                writeln!(stdout, "{}", line)?;
+            }
+            writeln!(stdout)?;
+        }
 
-        self.write_managed_versions(managed_versions)?;
-        writeln!(stdout, "Successfully migrated directory as {}", version).unwrap();
         Ok(())
     }
 
-    fn do_apply_to_app_config(&self, stdout: &mut impl Write, version: &ManagedVersion) -> anyhow::Result<()> {
-        let (modify_msg, success_msg) = match version.kind() {
-            TagKind::Proton => (
-                format!("Modifying Steam configuration to use {}", version),
-                PROTON_APPLY_HINT,
-            ),
-            TagKind::Wine { .. } => (
-                format!("Modifying Lutris configuration to use {}", version),
-                "Successfully modified Lutris config: Lutris should be restarted for the new settings to take effect.",
-            ),
+    fn download_tracker(&self) -> Box<dyn ReadProgressWrapper> {
+        let tracker: Box<dyn ReadProgressWrapper> = match self.config.progress_format {
+            _ if !self.config.show_progress => Box::new(SilentProgressTracker),
+            ProgressFormat::Bar => Box::new(DownloadProgressTracker::default()),
+            ProgressFormat::Json => Box::new(JsonProgressTracker::default()),
         };
 
-        writeln!(stdout, "{}", modify_msg).unwrap();
-
-        self.fs_mng
-            .apply_to_app_config(version)
-            .context("Could not modify app config")?;
-
-        writeln!(stdout, "{}", success_msg).unwrap();
-
-        Ok(())
+        match self.config.limit_rate {
+            Some(bytes_per_sec) => Box::new(RateLimitedTracker::new(tracker, bytes_per_sec)),
+            None => tracker,
+        }
     }
 
-    pub fn apply_to_app_config(&self, stdout: &mut impl Write, args: ApplyArgs) -> anyhow::Result<()> {
-        let managed_versions = self.read_managed_versions()?;
+    fn extraction_tracker(&self, len: u64) -> ExtractionProgressTracker {
+        match self.config.progress_format {
+            _ if !self.config.show_progress => ExtractionProgressTracker::hidden(len),
+            ProgressFormat::Bar => ExtractionProgressTracker::new(len),
+            ProgressFormat::Json => ExtractionProgressTracker::json(len),
+        }
+    }
 
-        let version = if args.tag_arg.tag.is_some() {
-            let version = args.tag_arg.version();
-            match managed_versions.find_version(&version) {
-                Some(v) => v,
-                None => bail!("Given version is not managed"),
+    fn download_assets(&self, version: &Version, skip_checksum: bool) -> anyhow::Result<DownloadedAssets> {
+        self.ensure_online()?;
+        log::info!(
+            "Downloading release assets of {} {}",
+            version.kind().compatibility_tool_name(),
+            version.tag()
+        );
+        let download = || {
+            let request = DownloadRequest::new(
+                Some(version.tag().to_string()),
+                *version.kind(),
+                self.download_tracker(),
+                skip_checksum,
+            );
+            self.ge_downloader.download_release_assets(request)
+        };
+
+        match self.with_retries(download) {
+            Ok(assets) => Ok(assets),
+            Err(err) => {
+                let context = match err.downcast_ref::<GithubError>() {
+                    Some(GithubError::ReleaseHasNoAssets { tag, kind }) => format!(
+                        "The given release has no assets for {} {}. It might be possible that the \
+                        release assets have been removed due to fixes in a newer version.",
+                        tag, kind
+                    ),
+                    _ => String::from("Could not fetch release assets from Github"),
+                };
+
+                Err(err.context(context))
             }
-        } else {
-            let kind = args.tag_arg.kind;
-            if let Some(version) = managed_versions.find_latest_by_kind(&kind) {
+        }
+    }
+
+    fn checksums_match(
+        algorithm: Option<ChecksumAlgorithm>,
+        archive: &DownloadedArchive,
+        checksum: &DownloadedChecksum,
+    ) -> anyhow::Result<bool> {
+        match algorithm {
+            Some(algorithm) => checksum::checksums_match(algorithm, &archive.compressed_content, &checksum.checksum)
+                .context(format!("Could not compare checksum with {}", algorithm.name())),
+            None => Ok(archive::checksums_match(
+                &archive.compressed_content,
+                checksum.checksum.as_bytes(),
+            )),
+        }
+    }
+
+    fn compare_checksums(
+        &self,
+        stdout: &mut impl Write,
+        args: &AddArgs,
+        version: &Version,
+        compressed_tar: &DownloadedArchive,
+        checksum: Option<&DownloadedChecksum>,
+    ) -> anyhow::Result<bool> {
+        if args.skip_checksum {
+            writeln!(stdout, "Skipping checksum comparison").unwrap();
+        } else if checksum.is_none() {
+            writeln!(
+                stdout,
+                "Warning: No checksum is available for {}. Skipping checksum comparison",
                 version
+            )
+            .unwrap();
+        } else {
+            write!(stdout, "Performing checksum comparison").unwrap();
+            let result = TerminalWriter::checksums_match(args.checksum_algorithm, compressed_tar, checksum.unwrap())?;
+
+            if !result {
+                bail!("Checksum comparison failed: Checksum generated from downloaded archive does not match downloaded expected checksum");
             } else {
-                bail!("No managed versions exist");
+                writeln!(stdout, ": Checksums match").unwrap();
+                return Ok(true);
             }
+        }
+
+        Ok(false)
+    }
+
+    /// Look up the archive of the version in the archive cache. A cached archive is only used if it still matches
+    /// its cached checksum, otherwise it is removed from the cache.
+    fn cached_archive(
+        &self,
+        stdout: &mut impl Write,
+        cache: &ArchiveCache,
+        version: &Version,
+        algorithm: Option<ChecksumAlgorithm>,
+    ) -> anyhow::Result<Option<DownloadedArchive>> {
+        let assets = match cache.get(version) {
+            Some(assets) => assets,
+            None => return Ok(None),
         };
 
-        self.do_apply_to_app_config(stdout, &version)
+        let checksum = assets.checksum.as_ref().unwrap();
+        if TerminalWriter::checksums_match(algorithm, &assets.compressed_archive, checksum).unwrap_or(false) {
+            writeln!(stdout, "Using cached archive for {}", version)?;
+            Ok(Some(assets.compressed_archive))
+        } else {
+            writeln!(
+                stdout,
+                "Cached archive for {} does not match its checksum: Downloading it again",
+                version
+            )?;
+            cache.remove(version)?;
+            Ok(None)
+        }
     }
 
-    pub fn copy_user_settings(&self, stdout: &mut impl Write, args: CopyUserSettingsArgs) -> anyhow::Result<()> {
-        let managed_versions = self.read_managed_versions()?;
-        let src_version = Version::new(args.src_tag, TagKind::Proton);
-        let dst_version = Version::new(args.dst_tag, TagKind::Proton);
+    /// Download the archive of the version or use the cached archive if `--keep-archive-cache` is set. Also returns
+    /// whether the archive was compared against a checksum.
+    fn download_or_cached_archive(
+        &self,
+        stdout: &mut impl Write,
+        args: &AddArgs,
+        version: &Version,
+    ) -> anyhow::Result<(DownloadedArchive, bool)> {
+        let cache = if args.keep_archive_cache {
+            let cache_dir = self.path_cfg.ge_man_cache_dir(xdg_cache_home());
+            Some(ArchiveCache::new(
+                cache_dir,
+                self.config.archive_cache_size_mib * 1024 * 1024,
+            ))
+        } else {
+            None
+        };
 
-        let src_version = match managed_versions.find_version(&src_version) {
-            Some(v) => v,
-            None => bail!("Given source Proton version does not exist"),
+        let cached_archive = match &cache {
+            Some(cache) => self.cached_archive(stdout, cache, version, args.checksum_algorithm)?,
+            None => None,
         };
-        let dst_version = match managed_versions.find_version(&dst_version) {
-            Some(v) => v,
-            None => bail!("Given destination Proton version does not exist"),
+
+        match cached_archive {
+            // A cached archive is only used if it matches its cached checksum.
+            Some(compressed_tar) => Ok((compressed_tar, true)),
+            None => {
+                let DownloadedAssets {
+                    compressed_archive: compressed_tar,
+                    checksum,
+                    ..
+                } = self.download_assets(version, args.skip_checksum)?;
+
+                let checksum_compared =
+                    self.compare_checksums(stdout, args, version, &compressed_tar, checksum.as_ref())?;
+                if let (Some(cache), Some(checksum)) = (&cache, &checksum) {
+                    if !args.skip_checksum {
+                        cache
+                            .put(version, &compressed_tar, checksum)
+                            .context("Could not store downloaded archive in the archive cache")?;
+                    }
+                }
+
+                Ok((compressed_tar, checksum_compared))
+            }
+        }
+    }
+
+    fn add_dry_run(
+        &self,
+        stdout: &mut impl Write,
+        args: &AddArgs,
+        managed_versions: &ManagedVersions,
+    ) -> anyhow::Result<()> {
+        let tag = args.tag_arg.value().cloned();
+        let kind = args.tag_arg.kind;
+
+        // Only a local archive with a tag can be planned without asking GitHub for the release.
+        let (mut version, source) = match (&args.archive, tag) {
+            (Some(archive_path), Some(tag)) => (Version::new(tag, kind), archive_path.display().to_string()),
+            (_, tag) => {
+                self.ensure_online()?;
+                let release = self
+                    .with_retries(|| self.ge_downloader.fetch_release(tag.clone(), kind))
+                    .context("Could not get release for dry run of \"add\" operation.")?;
+                let download_url = release
+                    .assets
+                    .iter()
+                    .find(|asset| asset.name.ends_with(".tar.gz") || asset.name.ends_with(".tar.xz"))
+                    .map(|asset| asset.browser_download_url.clone())
+                    .unwrap_or_else(|| String::from("No archive in release assets"));
+                (Version::new(release.tag_name, kind), download_url)
+            }
         };
+        version.set_label(args.label.clone());
 
-        self.fs_mng.copy_user_settings(&src_version, &dst_version)?;
+        let install_dir = match (kind, &args.steam_root) {
+            (TagKind::Proton, Some(steam_root)) => steam_root.join("compatibilitytools.d"),
+            (TagKind::Proton, None) => self.path_cfg.steam_compatibility_tools_dir(steam_path()),
+            (TagKind::Wine { .. }, _) => self.path_cfg.lutris_runners_dir(xdg_data_home()),
+        };
+        let already_managed = if managed_versions.find_version(&version).is_some() {
+            "yes"
+        } else {
+            "no"
+        };
+
+        writeln!(stdout, "Dry run - nothing will be changed")?;
+        writeln!(stdout, "Version: {}", version)?;
+        writeln!(stdout, "Already managed: {}", already_managed)?;
+        writeln!(stdout, "Source: {}", source)?;
+        writeln!(stdout, "Install directory: {}", install_dir.display())?;
+        Ok(())
+    }
+
+    /// Save the archive in the given directory or in the archives directory of the GE-Man cache.
+    fn keep_archive(&self, archive: &DownloadedArchive, dir: Option<&Path>) -> anyhow::Result<PathBuf> {
+        let dir = match dir {
+            Some(dir) => dir.to_path_buf(),
+            None => self.path_cfg.ge_man_cache_dir(xdg_cache_home()).join(KEPT_ARCHIVES_DIR),
+        };
+        fs::create_dir_all(&dir).context(format!("Could not create archive directory '{}'", dir.display()))?;
+
+        let archive_path = dir.join(&archive.file_name);
+        fs::write(&archive_path, &archive.compressed_content)
+            .context(format!("Could not save archive to '{}'", archive_path.display()))?;
+        Ok(archive_path)
+    }
+
+    /// Download the archive and checksum of a version, or read the given local files, and only compare the checksums.
+    /// Nothing is extracted and the managed versions are not changed.
+    fn add_verify_only(&self, stdout: &mut impl Write, args: &AddArgs, version: &Version) -> anyhow::Result<()> {
+        let (compressed_tar, checksum) = match &args.archive {
+            Some(archive_path) => read_local_assets(archive_path, args.checksum_file.as_deref())?,
+            None => {
+                let DownloadedAssets {
+                    compressed_archive,
+                    checksum,
+                    ..
+                } = self.download_assets(version, false)?;
+                (compressed_archive, checksum)
+            }
+        };
+
+        let checksum = match checksum {
+            Some(checksum) => checksum,
+            None => bail!(
+                "No checksum is available for {}. The archive could not be verified",
+                version
+            ),
+        };
+        if !TerminalWriter::checksums_match(args.checksum_algorithm, &compressed_tar, &checksum)? {
+            bail!(
+                "Checksum comparison failed: Checksum of {} does not match {}",
+                compressed_tar.file_name,
+                checksum.file_name
+            );
+        }
 
         writeln!(
             stdout,
-            "Copied user_settings.py from {} to {}",
-            src_version, dst_version
-        )
-        .unwrap();
+            "Checksums match: {} matches {}",
+            compressed_tar.file_name, checksum.file_name
+        )?;
+        writeln!(stdout, "Version {} was verified but not installed", version)?;
         Ok(())
     }
 
-    pub fn forget(&self, stdout: &mut impl Write, args: ForgetArgs) -> anyhow::Result<()> {
-        let version = args.tag_arg.version();
+    pub fn add(&self, stdout: &mut impl Write, mut args: AddArgs) -> anyhow::Result<()> {
+        let start = Instant::now();
+        let tag = args.tag_arg.value().cloned();
+        let kind = args.tag_arg.kind;
+        if args.archive.is_some() && tag.is_none() {
+            bail!("A tag must be provided when adding a local archive");
+        }
+
         let mut managed_versions = self.read_managed_versions()?;
-        if managed_versions.remove(&version).is_none() {
-            bail!("Failed to forget version: Version is not managed");
+        if args.dry_run {
+            return self.add_dry_run(stdout, &args, &managed_versions);
         }
 
-        self.write_managed_versions(managed_versions)?;
-        writeln!(stdout, "{} is now not managed by GE Helper", version).unwrap();
-        Ok(())
-    }
-}
+        let mut version = if tag.is_some() {
+            Version::new(tag, kind)
+        } else {
+            if self.cached_latest_tag(&kind, args.refresh).is_none() {
+                self.ensure_online()?;
+            }
+            match self.latest_tag(kind, args.refresh) {
+                Ok(tag) => Version::new(tag, kind),
+                Err(err) => return Err(err.context(r#"Could not get latest tag for tagless "add" operation."#)),
+            }
+        };
+        version.set_label(args.label.clone());
+        // A managed version is known to exist, so there is nothing to warn about.
+        if tag.is_some() && args.archive.is_none() && managed_versions.find_version(&version).is_none() {
+            self.warn_if_newer_than_latest(stdout, &version)?;
+        }
+        if args.verify_only {
+            return self.add_verify_only(stdout, &args, &version);
+        }
 
-#[cfg(test)]
-mod tests {
-    use std::path::{Path, PathBuf};
-    use std::{fs, io};
+        let reinstall = match managed_versions.find_version(&version) {
+            Some(existing) if args.force => {
+                self.ensure_not_in_use(&existing)?;
+                Some(existing)
+            }
+            Some(_) => {
+                if !args.skip_existing {
+                    writeln!(stdout, "Version {} is already managed", version)?;
+                }
+                return Ok(());
+            }
+            None => None,
+        };
 
-    use anyhow::bail;
-    use assert_fs::TempDir;
-    use ge_man_lib::download::response::{DownloadedArchive, DownloadedChecksum, GeRelease};
-    use ge_man_lib::tag::Tag;
-    use mockall::mock;
+        let (compressed_tar, checksum_compared) = match args.archive.take() {
+            Some(archive_path) => {
+                let (compressed_tar, checksum) = read_local_assets(&archive_path, args.checksum_file.as_deref())?;
+                // Without an expected checksum there is nothing to compare the local archive against.
+                args.skip_checksum = checksum.is_none();
+                let checksum_compared =
+                    self.compare_checksums(stdout, &args, &version, &compressed_tar, checksum.as_ref())?;
 
-    use crate::args::TagArg;
-    use crate::filesystem::MockFilesystemManager;
-    use crate::path::MockPathConfiguration;
+                (compressed_tar, checksum_compared)
+            }
+            None => self.download_or_cached_archive(stdout, &args, &version)?,
+        };
+        if args.keep_archive {
+            let archive_path = self.keep_archive(&compressed_tar, args.keep_archive_dir.as_deref())?;
+            writeln!(stdout, "Saved archive to {}", archive_path.display())?;
+        }
 
-    use super::*;
+        // A reinstalled version stays in the Steam root it was added to.
+        let steam_root = match &reinstall {
+            Some(existing) => existing.steam_root().map(Path::to_path_buf),
+            None => args.steam_root.clone(),
+        };
+        self.fs_mng.ensure_free_space(
+            version.kind(),
+            steam_root.as_deref(),
+            compressed_tar.compressed_content.len() as u64,
+        )?;
+
+        let archive_len = compressed_tar.compressed_content.len() as u64;
+        let extraction_tracker = self.extraction_tracker(archive_len);
+        let compressed_tar_reader = extraction_tracker.wrap(std::io::Cursor::new(compressed_tar.compressed_content));
+
+        let version = match reinstall {
+            Some(existing) => {
+                let version = self
+                    .fs_mng
+                    .reinstall_version(&existing, compressed_tar_reader)
+                    .context("Could not reinstall version")?;
+                extraction_tracker.finish();
+
+                writeln!(stdout, "Successfully reinstalled version").unwrap();
+                version
+            }
+            None => {
+                let mut version = self
+                    .fs_mng
+                    .setup_version(version, compressed_tar_reader, steam_root.as_deref())
+                    .context("Could not add version")?;
+                extraction_tracker.finish();
 
-    mock! {
-        Downloader {}
-        impl GeDownload for Downloader {
-            fn fetch_release(&self, tag: Option<String>, kind: TagKind) -> Result<GeRelease, GithubError>;
-            fn download_release_assets(&self, request: DownloadRequest) -> Result<DownloadedAssets, GithubError>;
+                version.set_checksum_skipped(!checksum_compared);
+                let version = managed_versions.add(version)?;
+                self.write_managed_versions(managed_versions)?;
+
+                writeln!(stdout, "Successfully added version").unwrap();
+                version
+            }
+        };
+        self.run_post_add_hook(stdout, &version)?;
+        match self.fs_mng.version_size(&version) {
+            Ok(size) => writeln!(
+                stdout,
+                "Installed {}, {}, in {}.",
+                version,
+                format_size(size),
+                format_duration(start.elapsed())
+            )
+            .unwrap(),
+            Err(_) => writeln!(stdout, "Installed {} in {}.", version, format_duration(start.elapsed())).unwrap(),
+        }
+        if args.apply {
+            self.do_apply_to_app_config(stdout, &version, self.config.apply_backups)?;
         }
-    }
 
-    struct AssertLines {
-        latest_line: String,
-        pub lines: Vec<String>,
+        Ok(())
     }
 
-    impl AssertLines {
-        pub fn new() -> Self {
-            AssertLines {
-                latest_line: String::new(),
-                lines: Vec::new(),
+    /// Run the post add hook of the GE-Man config, if one is configured. A failing hook only prints a warning unless
+    /// the config makes it fatal.
+    fn run_post_add_hook(&self, stdout: &mut impl Write, version: &ManagedVersion) -> anyhow::Result<()> {
+        let hook = match &self.config.post_add_hook {
+            Some(hook) => hook,
+            None => return Ok(()),
+        };
+
+        let version_dir = self.fs_mng.version_path(version);
+        if let Err(err) = hook::run_post_add_hook(hook, &version_dir, version) {
+            if self.config.post_add_hook_fatal {
+                return Err(err.context(format!("{} was added, but its post add hook failed", version)));
             }
+            writeln!(stdout, "Warning: {:#}", err)?;
         }
+        Ok(())
+    }
 
-        pub fn assert_line(&self, line: usize, expected: &str) {
-            let line = self.lines.get(line).unwrap();
-            assert!(line.contains("\n"));
-            assert_eq!(line.trim(), expected);
+    /// Refuse to replace the directory of a version which Steam, Lutris or Bottles currently uses.
+    fn ensure_not_in_use(&self, version: &ManagedVersion) -> anyhow::Result<()> {
+        let config_paths = AppConfigPaths::new(
+            self.path_cfg.steam_config(steam_path()),
+            self.path_cfg.lutris_wine_runner_config(xdg_config_home()),
+            self.path_cfg.bottles_runners_dir(xdg_data_home()),
+        );
+        let in_use_by = self
+            .read_in_use_dir_names(&config_paths)
+            .into_iter()
+            .find(|(app, dir_name)| app.uses(version.kind()) && dir_name.eq(version.directory_name()));
+        if let Some((application, _)) = in_use_by {
+            bail!(CommandError::in_use(format!(
+                "Version {} is in use by {}. Apply a different version before reinstalling it.",
+                version, application
+            )));
         }
 
-        pub fn assert_empty(&self) {
-            assert!(self.lines.is_empty())
-        }
+        Ok(())
     }
 
-    impl io::Write for AssertLines {
-        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-            let len = buf.len();
-            let str = String::from_utf8_lossy(buf);
-            self.latest_line.push_str(&str);
+    pub fn remove(
+        &self,
+        stdout: &mut impl Write,
+        stdin: &mut impl BufRead,
+        args: RemoveArgs,
+        config_paths: AppConfigPaths,
+    ) -> anyhow::Result<()> {
+        let mut managed_versions = self.read_managed_versions()?;
+        let single_version = args.versions.len() == 1;
 
-            if self.latest_line.contains("\n") {
-                self.lines.push(self.latest_line.clone());
-                self.latest_line = String::new();
+        if !single_version && !args.yes && !self.assume_yes {
+            writeln!(stdout, "The following versions will be removed:")?;
+            for version in &args.versions {
+                writeln!(stdout, "* {}", version)?;
+            }
+            if !self.interactive {
+                bail!(
+                    "Refusing to remove {} versions without confirmation. Use --yes to remove them.",
+                    args.versions.len()
+                );
             }
+            if !confirm(stdout, stdin, &format!("Remove {} versions?", args.versions.len()))? {
+                writeln!(stdout, "No versions were removed.")?;
+                return Ok(());
+            }
+        }
 
-            Ok(len)
+        let mut removed_versions = Vec::new();
+        let mut errors = Vec::new();
+        for version in args.versions {
+            match self.remove_version(&mut managed_versions, &version, &config_paths, args.force) {
+                Ok(removed) => removed_versions.push(removed),
+                Err(err) => errors.push((version, err)),
+            }
         }
 
-        fn flush(&mut self) -> io::Result<()> {
-            Ok(())
+        if !removed_versions.is_empty() {
+            self.write_managed_versions(managed_versions)?;
+        }
+        for version in removed_versions {
+            writeln!(stdout, "Successfully removed version {}.", version).unwrap();
+        }
+
+        if single_version {
+            if let Some((_, err)) = errors.pop() {
+                return Err(err);
+            }
+        } else if !errors.is_empty() {
+            let messages = errors
+                .iter()
+                .map(|(version, err)| format!("{}: {:#}", version, err))
+                .join("\n");
+            bail!("Failed to remove {} versions:\n{}", errors.len(), messages);
         }
+
+        Ok(())
     }
 
-    fn proton_6_20_1() -> ManagedVersion {
-        ManagedVersion::from(Version::proton("6.20-GE-1"))
+    fn remove_version(
+        &self,
+        managed_versions: &mut ManagedVersions,
+        version: &Version,
+        config_paths: &AppConfigPaths,
+        force: bool,
+    ) -> anyhow::Result<ManagedVersion> {
+        let version = match managed_versions.find_version(version) {
+            Some(v) => v,
+            None => bail!(CommandError::not_found("Given version is not managed")),
+        };
+
+        if version.pinned() && !force {
+            bail!("Version is pinned. Unpin it or use --force to remove it.");
+        }
+
+        match &version.kind() {
+            TagKind::Proton => {
+                let path = &config_paths.steam;
+                let config = SteamConfig::create_copy(path).map_err(|err| {
+                    let application = err.application();
+                    anyhow!(err).context(format!("Failed to read {} config: {}", application, path.display()))
+                })?;
+
+                if self.check_if_version_in_use_by_config(&version, &config) {
+                    bail!(CommandError::in_use(format!(
+                        "Proton version is in use by {}. Select a different version to make removal possible.",
+                        config.application()
+                    )));
+                }
+            }
+            TagKind::Wine { .. } => {
+                let path = &config_paths.lutris;
+                let config = LutrisConfig::create_copy(path);
+                match config {
+                    Ok(config) => {
+                        if self.check_if_version_in_use_by_config(&version, &config) {
+                            bail!(CommandError::in_use(format!(
+                                "Wine version is in use by {}. Select a different version to make removal \
+                            possible.",
+                                config.application()
+                            )));
+                        }
+                    }
+                    Err(err) => {
+                        if let LutrisConfigError::IoError { source } = &err {
+                            if source.raw_os_error().unwrap() != 2 {
+                                bail!(err);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        self.fs_mng.remove_version(&version)?;
+        managed_versions.remove(&version).unwrap();
+        Ok(version)
     }
 
-    fn setup_managed_versions(json_path: &Path, versions: Vec<ManagedVersion>) {
-        fs::create_dir_all(json_path.parent().unwrap()).unwrap();
-        let managed_versions = ManagedVersions::new(versions);
-        managed_versions.write_to_file(json_path).unwrap();
+    fn check_if_version_in_use_by_config<T>(&self, version: &ManagedVersion, app_config: &T) -> bool
+    where
+        T: AppConfig,
+    {
+        app_config.version_dir_name().eq(version.directory_name())
     }
 
-    #[test]
-    fn forget_should_print_success_message() {
-        let args = ForgetArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
-        let fs_mng = MockFilesystemManager::new();
-        let ge_downloader = MockDownloader::new();
+    pub fn check(&self, stdout: &mut impl Write, stderr: &mut impl Write, args: CheckArgs) -> anyhow::Result<()> {
+        self.ensure_online()?;
+
+        let mut latest_versions = Vec::new();
+        let mut failed_kinds = Vec::new();
+
+        match args.kind {
+            Some(kind) => {
+                let tag = self
+                    .latest_tag(kind, args.refresh)
+                    .context("Could not fetch latest release from Github")?;
+                writeln!(
+                    stdout,
+                    "The latest version of {} is \"{}\"",
+                    kind.compatibility_tool_name(),
+                    tag
+                )
+                .unwrap();
+                latest_versions.push(Version::new(tag, kind));
+            }
+            None => {
+                // Fetch every kind on its own thread, so a slow request does not delay the others. The results are
+                // still printed in a fixed order.
+                let downloader = self.ge_downloader;
+                let retries = self.config.retries;
+                let retry_delay = self.config.retry_delay();
+                let (proton, wine, lol) = thread::scope(|scope| {
+                    let fetch = |kind: TagKind| {
+                        let cached_tag = self.cached_latest_tag(&kind, args.refresh);
+                        scope.spawn(move || match cached_tag {
+                            Some(tag) => Ok((tag, true)),
+                            None => retry(retries, retry_delay, is_retryable, || {
+                                downloader.fetch_release(None, kind)
+                            })
+                            .map(|release| (release.tag_name, false)),
+                        })
+                    };
+                    let proton = fetch(TagKind::Proton);
+                    let wine = fetch(TagKind::wine());
+                    let lol = fetch(TagKind::lol());
+
+                    (
+                        proton.join().expect("Proton GE release thread panicked"),
+                        wine.join().expect("Wine GE release thread panicked"),
+                        lol.join().expect("Wine GE LoL release thread panicked"),
+                    )
+                });
+
+                writeln!(stdout, "These are the latest releases.").unwrap();
+                writeln!(stdout).unwrap();
+                match proton {
+                    Ok((tag, cached)) => {
+                        if !cached {
+                            self.cache_latest_tag(&TagKind::Proton, &tag);
+                        }
+                        writeln!(stdout, "Proton GE: {}", tag).unwrap();
+                        latest_versions.push(Version::new(tag, TagKind::Proton));
+                    }
+                    Err(err) => {
+                        writeln!(
+                            stderr,
+                            "Proton GE: Could not fetch release information from GitHub: {:#}",
+                            err
+                        )
+                        .unwrap();
+                        failed_kinds.push(TagKind::Proton);
+                    }
+                }
+
+                match wine {
+                    Ok((tag, cached)) => {
+                        if !cached {
+                            self.cache_latest_tag(&TagKind::wine(), &tag);
+                        }
+                        writeln!(stdout, "Wine GE: {}", tag).unwrap();
+                        latest_versions.push(Version::new(tag, TagKind::wine()));
+                    }
+                    Err(err) => {
+                        writeln!(
+                            stderr,
+                            "Wine GE: Could not fetch release information from GitHub: {:#}",
+                            err
+                        )
+                        .unwrap();
+                        failed_kinds.push(TagKind::wine());
+                    }
+                }
+
+                match lol {
+                    Ok((tag, cached)) => {
+                        if !cached {
+                            self.cache_latest_tag(&TagKind::lol(), &tag);
+                        }
+                        writeln!(stdout, "Wine GE - LoL: {}", tag).unwrap();
+                        latest_versions.push(Version::new(tag, TagKind::lol()));
+                    }
+                    Err(err) => {
+                        writeln!(
+                            stderr,
+                            "Wine GE - LoL: Could not fetch release information from GitHub: {:#}",
+                            err
+                        )
+                        .unwrap();
+                        failed_kinds.push(TagKind::lol());
+                    }
+                }
+            }
+        }
+
+        if args.notify {
+            if let Err(err) = self.notify_updates(stdout, latest_versions) {
+                writeln!(stderr, "Could not check for updates: {:#}", err).unwrap();
+            }
+        }
+
+        if !failed_kinds.is_empty() {
+            bail!(CommandError::network(format!(
+                "Could not fetch the latest release of {}",
+                failed_kinds.iter().map(TagKind::compatibility_tool_name).join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the latest versions which are not managed yet.
+    fn find_updates(&self, latest_versions: Vec<Version>) -> anyhow::Result<Vec<Version>> {
+        let managed_versions = self.read_managed_versions()?;
+        let updates = latest_versions
+            .into_iter()
+            .filter(|version| managed_versions.find_version(version).is_none())
+            .collect();
+
+        Ok(updates)
+    }
+
+    fn notify_updates(&self, stdout: &mut impl Write, latest_versions: Vec<Version>) -> anyhow::Result<()> {
+        let updates = self.find_updates(latest_versions)?;
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        let body = updates
+            .iter()
+            .map(|version| format!("{}: {}", version.kind().compatibility_tool_name(), version.tag()))
+            .join("\n");
+
+        if let Err(err) = notification::send("GE-Man: Updates available", &body) {
+            if err.kind() == std::io::ErrorKind::NotFound {
+                writeln!(stdout, "notify-send is not available. Updates are available for:").unwrap();
+            } else {
+                writeln!(
+                    stdout,
+                    "Could not send a desktop notification ({}). Updates are available for:",
+                    err
+                )
+                .unwrap();
+            }
+            writeln!(stdout, "{}", body).unwrap();
+        }
+
+        Ok(())
+    }
+
+    pub fn migrate(&self, stdout: &mut impl Write, args: MigrationArgs) -> anyhow::Result<()> {
+        if args.tag_arg.value().is_none() {
+            bail!("A tag is required to migrate a single directory");
+        }
+        let version = args.tag_arg.version();
+        let mut managed_versions = self.read_managed_versions()?;
+
+        if managed_versions.find_version(&version).is_some() {
+            bail!(CommandError::already_exists(
+                "Given version to migrate already exists as a managed version"
+            ));
+        }
+
+        let source_path = &args.source_path;
+        if args.dry_run {
+            let plan = self
+                .fs_mng
+                .plan_migration(version, source_path, args.dir_name.as_deref())
+                .context("Could not migrate directory")?;
+            let action = match plan.action {
+                MigrationAction::InPlace => "register the directory in place",
+                MigrationAction::Move => "move the directory",
+                MigrationAction::Copy => "copy the directory to a different device",
+            };
+
+            writeln!(stdout, "Dry run - nothing will be changed").unwrap();
+            writeln!(stdout, "Version: {}", plan.version).unwrap();
+            writeln!(stdout, "Source: {}", source_path.display()).unwrap();
+            writeln!(stdout, "Destination: {}", plan.destination.display()).unwrap();
+            writeln!(stdout, "Action: {}", action).unwrap();
+            return Ok(());
+        }
+
+        let version = self
+            .fs_mng
+            .migrate_folder(version, source_path, args.dir_name.as_deref())
+            .context("Could not migrate directory")?;
+        let version = managed_versions.add(version)?;
+
+        self.write_managed_versions(managed_versions)?;
+        writeln!(stdout, "Successfully migrated directory as {}", version).unwrap();
+        Ok(())
+    }
+
+    /// Migrate every directory in the source directory. Directories which are already managed, whose version can not
+    /// be inferred or which can not be migrated are skipped, so one bad directory does not stop the others.
+    pub fn migrate_dir(&self, stdout: &mut impl Write, args: MigrateDirArgs) -> anyhow::Result<()> {
+        let source_dir = &args.source_dir;
+        let mut names = Vec::new();
+        for entry in fs::read_dir(source_dir).context(format!("Could not read directory '{}'", source_dir.display()))? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if entry.path().is_dir() && !name.starts_with('.') {
+                names.push(name);
+            }
+        }
+        names.sort_unstable();
+
+        let mut managed_versions = self.read_managed_versions()?;
+        if args.dry_run {
+            writeln!(stdout, "Dry run - nothing will be changed").unwrap();
+        }
+
+        let mut migrated = 0;
+        let mut skipped = 0;
+        for name in names {
+            let version = match (version_from_directory_name(&name), args.kind) {
+                (Some(version), None) => version,
+                (Some(version), Some(kind)) if version.kind().eq(&kind) => version,
+                (_, Some(kind)) => Version::new(name.clone(), kind),
+                (None, None) => {
+                    writeln!(
+                        stdout,
+                        "Skipping {}: Could not infer the version from the directory name",
+                        name
+                    )
+                    .unwrap();
+                    skipped += 1;
+                    continue;
+                }
+            };
+
+            if managed_versions.find_version(&version).is_some() {
+                writeln!(stdout, "Skipping {}: {} is already managed", name, version).unwrap();
+                skipped += 1;
+                continue;
+            }
+
+            let source_path = source_dir.join(&name);
+            let result = if args.dry_run {
+                self.fs_mng
+                    .plan_migration(version, &source_path, None)
+                    .map(|plan| plan.version)
+            } else {
+                self.fs_mng
+                    .migrate_folder(version, &source_path, None)
+                    .and_then(|version| managed_versions.add(version))
+            };
+
+            match result {
+                Ok(version) if args.dry_run => {
+                    writeln!(stdout, "Would migrate {} as {}", name, version).unwrap();
+                    migrated += 1;
+                }
+                Ok(version) => {
+                    writeln!(stdout, "Migrated {} as {}", name, version).unwrap();
+                    migrated += 1;
+                }
+                Err(err) => {
+                    writeln!(stdout, "Skipping {}: {:#}", name, err).unwrap();
+                    skipped += 1;
+                }
+            }
+        }
+
+        if migrated > 0 && !args.dry_run {
+            self.write_managed_versions(managed_versions)?;
+        }
+        writeln!(stdout, "{} migrated, {} skipped", migrated, skipped).unwrap();
+        Ok(())
+    }
+
+    fn do_apply_to_app_config(
+        &self,
+        stdout: &mut impl Write,
+        version: &ManagedVersion,
+        backups: usize,
+    ) -> anyhow::Result<()> {
+        let application = Application::for_kind(version.kind());
+        writeln!(stdout, "Modifying {} configuration to use {}", application, version).unwrap();
+
+        let update = self
+            .fs_mng
+            .apply_to_app_config(version, backups)
+            .context("Could not modify app config")?;
+
+        match version.kind() {
+            TagKind::Proton => writeln!(stdout, "{}", PROTON_APPLY_HINT).unwrap(),
+            TagKind::Wine { .. } => {
+                let success_msg = match update {
+                    AppConfigUpdate::Created => LUTRIS_CREATED_CONFIG_HINT,
+                    AppConfigUpdate::Modified => LUTRIS_MODIFIED_CONFIG_HINT,
+                };
+                writeln!(stdout, "{}", success_msg).unwrap();
+                writeln!(
+                    stdout,
+                    "Lutris wine runner set to {} - you may need to select it per game in Lutris.",
+                    version.directory_name()
+                )
+                .unwrap();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Find the managed version which is next to the currently applied version of the same kind.
+    fn find_step_version(
+        &self,
+        managed_versions: &ManagedVersions,
+        kind: &TagKind,
+        step: ApplyStep,
+        config_paths: &AppConfigPaths,
+    ) -> anyhow::Result<Option<(ManagedVersion, ManagedVersion)>> {
+        let application = Application::for_kind(kind);
+        let in_use_dir_name = match self
+            .read_in_use_dir_names(config_paths)
+            .into_iter()
+            .find(|(app, _)| app == &application)
+        {
+            Some((_, dir_name)) => dir_name,
+            None => bail!("Could not read the currently applied version of {}", application),
+        };
+
+        let versions = managed_versions
+            .versions()
+            .into_iter()
+            .filter(|version| version.kind().eq(kind))
+            .sorted_unstable_by(|a, b| a.tag().cmp_semver(b.tag()))
+            .collect_vec();
+
+        let position = match versions
+            .iter()
+            .position(|version| version.directory_name().eq(&in_use_dir_name))
+        {
+            Some(position) => position,
+            None => bail!(
+                "The currently applied version {} is not a managed {} version",
+                in_use_dir_name,
+                kind.compatibility_tool_name()
+            ),
+        };
+
+        let step_position = match step {
+            ApplyStep::Next => position.checked_add(1).filter(|p| *p < versions.len()),
+            ApplyStep::Prev => position.checked_sub(1),
+        };
+
+        Ok(step_position.map(|p| (versions[position].clone(), versions[p].clone())))
+    }
+
+    fn restore_app_config(&self, stdout: &mut impl Write, kind: &TagKind, backup: usize) -> anyhow::Result<()> {
+        let application = Application::for_kind(kind);
+        let restore = self
+            .fs_mng
+            .restore_app_config(kind, backup.saturating_sub(1))
+            .context(format!("Could not restore {} configuration", application))?;
+
+        match restore {
+            AppConfigRestore::Restored => writeln!(stdout, "Restored the previous {} configuration", application)?,
+            AppConfigRestore::Removed => {
+                writeln!(stdout, "Removed the {} configuration created by GE-Man", application)?
+            }
+        }
+        Ok(())
+    }
+
+    pub fn apply_to_app_config(
+        &self,
+        stdout: &mut impl Write,
+        stdin: &mut impl BufRead,
+        args: ApplyArgs,
+        config_paths: AppConfigPaths,
+    ) -> anyhow::Result<()> {
+        if args.reset {
+            if args.tag_arg.tag.is_some() {
+                bail!("A tag can not be combined with --reset");
+            }
+            return self.restore_app_config(stdout, &args.tag_arg.kind, args.backup);
+        }
+
+        let managed_versions = self.read_managed_versions()?;
+        let backups = if args.no_backup { 0 } else { self.config.apply_backups };
+
+        if let Some(step) = args.step {
+            if args.tag_arg.tag.is_some() {
+                bail!("A tag can not be combined with --next or --prev");
+            }
+
+            let kind = args.tag_arg.kind;
+            return match self.find_step_version(&managed_versions, &kind, step, &config_paths)? {
+                Some((current, version)) => {
+                    writeln!(stdout, "Switching from {} to {}", current.tag(), version.tag()).unwrap();
+                    self.do_apply(stdout, &version, args.symlink.as_deref(), backups)
+                }
+                None => {
+                    let edge = match step {
+                        ApplyStep::Next => "newest",
+                        ApplyStep::Prev => "oldest",
+                    };
+                    writeln!(
+                        stdout,
+                        "The currently applied version is already the {} managed {} version",
+                        edge,
+                        kind.compatibility_tool_name()
+                    )
+                    .unwrap();
+                    Ok(())
+                }
+            };
+        }
+
+        let version = if let Some(tag) = &args.tag_arg.tag {
+            if args.tag_arg.kind_from_default {
+                warn_if_ambiguous(stdout, &managed_versions, tag, &args.tag_arg.kind)?;
+            }
+
+            let version = args.tag_arg.version();
+            match managed_versions.find_version(&version) {
+                Some(v) => v,
+                None => bail!(CommandError::not_found("Given version is not managed")),
+            }
+        } else {
+            let kind = args.tag_arg.kind;
+            if let Some(version) = managed_versions.find_latest_by_kind(&kind) {
+                version
+            } else {
+                bail!(CommandError::not_found("No managed versions exist"));
+            }
+        };
+
+        let application = Application::for_target(version.kind(), args.bottles);
+        if application == Application::Bottles {
+            return self.apply_to_bottles(stdout, &version);
+        }
+
+        if args.symlink.is_none() && !self.confirm_apply(stdout, stdin, &version, &config_paths)? {
+            writeln!(stdout, "The {} config was not modified.", application)?;
+            return Ok(());
+        }
+
+        self.do_apply(stdout, &version, args.symlink.as_deref(), backups)
+    }
+
+    /// Ask before a different version in the config of the application is replaced. Without a user to ask or with
+    /// `--assume-yes`, the version is applied without asking, so scripts keep working.
+    fn confirm_apply(
+        &self,
+        stdout: &mut impl Write,
+        stdin: &mut impl BufRead,
+        version: &ManagedVersion,
+        config_paths: &AppConfigPaths,
+    ) -> anyhow::Result<bool> {
+        if !self.interactive || self.assume_yes {
+            return Ok(true);
+        }
+
+        let application = Application::for_kind(version.kind());
+        let current_dir_name = self
+            .read_in_use_dir_names(config_paths)
+            .into_iter()
+            .find(|(app, _)| app.eq(&application))
+            .map(|(_, dir_name)| dir_name);
+
+        match current_dir_name {
+            Some(dir_name) if !dir_name.is_empty() && !dir_name.eq(version.directory_name()) => {
+                writeln!(stdout, "{} currently uses {}", application, dir_name)?;
+                confirm(stdout, stdin, &format!("Replace it with {}?", version.directory_name()))
+            }
+            _ => Ok(true),
+        }
+    }
+
+    fn do_apply(
+        &self,
+        stdout: &mut impl Write,
+        version: &ManagedVersion,
+        symlink: Option<&str>,
+        backups: usize,
+    ) -> anyhow::Result<()> {
+        let link_name = match symlink {
+            Some(link_name) => link_name,
+            None => return self.do_apply_to_app_config(stdout, version, backups),
+        };
+        if version.kind() != &TagKind::Proton {
+            bail!("Only GE Proton versions can be applied with a symlink");
+        }
+
+        let link_path = self
+            .fs_mng
+            .apply_symlink(version, link_name)
+            .context("Could not apply version with a symlink")?;
+        writeln!(stdout, "{} now points to {}", link_path.display(), version)?;
+        writeln!(
+            stdout,
+            "Select {} in Steam to use it. The Steam config was not modified.",
+            link_name
+        )?;
+
+        Ok(())
+    }
+
+    fn apply_to_bottles(&self, stdout: &mut impl Write, version: &ManagedVersion) -> anyhow::Result<()> {
+        if !Application::Bottles.uses(version.kind()) {
+            bail!("Only Wine GE versions can be used by {}", Application::Bottles);
+        }
+
+        let link_path = self
+            .fs_mng
+            .link_to_bottles(version)
+            .context("Could not make version available to Bottles")?;
+        writeln!(stdout, "{} now points to {}", link_path.display(), version)?;
+        writeln!(
+            stdout,
+            "Select {} as runner of a bottle in Bottles. The Lutris config was not modified.",
+            version.directory_name()
+        )?;
+
+        Ok(())
+    }
+
+    pub fn copy_user_settings(&self, stdout: &mut impl Write, args: CopyUserSettingsArgs) -> anyhow::Result<()> {
+        let managed_versions = self.read_managed_versions()?;
+        let dst_version = Version::new(args.dst_tag, TagKind::Proton);
+        let dst_version = match managed_versions.find_version(&dst_version) {
+            Some(v) => v,
+            None => bail!(CommandError::not_found(
+                "Given destination Proton version does not exist"
+            )),
+        };
+
+        if let Some(src_file) = args.src_file {
+            if !src_file.is_file() {
+                bail!(CommandError::not_found(format!(
+                    "Given source file {} does not exist or is not a file",
+                    src_file.display()
+                )));
+            }
+            fs::File::open(&src_file).context(format!("Could not read {}", src_file.display()))?;
+
+            self.fs_mng.copy_user_settings_from_file(&src_file, &dst_version)?;
+            writeln!(
+                stdout,
+                "Copied user_settings.py from {} to {}",
+                src_file.display(),
+                dst_version
+            )?;
+            return Ok(());
+        }
+
+        let src_tag = match args.src_tag {
+            Some(tag) => tag,
+            None => bail!("Either a source Proton version or a source file is required"),
+        };
+        let src_version = Version::new(src_tag, TagKind::Proton);
+        let src_version = match managed_versions.find_version(&src_version) {
+            Some(v) => v,
+            None => bail!(CommandError::not_found("Given source Proton version does not exist")),
+        };
+
+        self.fs_mng.copy_user_settings(&src_version, &dst_version)?;
+
+        writeln!(
+            stdout,
+            "Copied user_settings.py from {} to {}",
+            src_version, dst_version
+        )
+        .unwrap();
+        Ok(())
+    }
+
+    pub fn diff_user_settings(&self, stdout: &mut impl Write, args: DiffUserSettingsArgs) -> anyhow::Result<()> {
+        let managed_versions = self.read_managed_versions()?;
+        let src_version = Version::new(args.src_tag, TagKind::Proton);
+        let dst_version = Version::new(args.dst_tag, TagKind::Proton);
+
+        let src_version = match managed_versions.find_version(&src_version) {
+            Some(v) => v,
+            None => bail!(CommandError::not_found("Given source Proton version does not exist")),
+        };
+        let dst_version = match managed_versions.find_version(&dst_version) {
+            Some(v) => v,
+            None => bail!(CommandError::not_found(
+                "Given destination Proton version does not exist"
+            )),
+        };
+
+        let src_path = self.fs_mng.user_settings_path(&src_version);
+        let dst_path = self.fs_mng.user_settings_path(&dst_version);
+
+        let missing: Vec<String> = [(&src_version, &src_path), (&dst_version, &dst_path)]
+            .into_iter()
+            .filter(|(_, path)| !path.exists())
+            .map(|(version, path)| format!("{} has no user_settings.py at {}", version, path.display()))
+            .collect();
+        if !missing.is_empty() {
+            bail!(missing.join("\n"));
+        }
+
+        let src_content = fs::read_to_string(&src_path).context(format!("Could not read {}", src_path.display()))?;
+        let dst_content = fs::read_to_string(&dst_path).context(format!("Could not read {}", dst_path.display()))?;
+
+        if src_content == dst_content {
+            writeln!(
+                stdout,
+                "user_settings.py of {} and {} are identical",
+                src_version, dst_version
+            )?;
+            return Ok(());
+        }
+
+        let diff = TextDiff::from_lines(&src_content, &dst_content);
+        let unified_diff = diff
+            .unified_diff()
+            .context_radius(3)
+            .header(&src_path.display().to_string(), &dst_path.display().to_string())
+            .to_string();
+        for line in unified_diff.lines() {
+            writeln!(stdout, "{}", line)?;
+        }
+        Ok(())
+    }
+
+    pub fn export(&self, stdout: &mut impl Write, args: ExportArgs) -> anyhow::Result<()> {
+        let managed_versions = self.read_managed_versions()?;
+        if let Some(path) = &args.manifest_path {
+            managed_versions
+                .write_to_file(path)
+                .context(format!("Could not write manifest to {}", path.display()))?;
+            writeln!(
+                stdout,
+                "Exported {} versions to {}",
+                managed_versions.versions().len(),
+                path.display()
+            )?;
+            return Ok(());
+        }
+
+        let versions = managed_versions
+            .versions()
+            .into_iter()
+            .sorted_unstable_by(|a, b| {
+                a.kind()
+                    .cmp(b.kind())
+                    .then(a.tag().cmp_semver(b.tag()))
+                    .then(a.label().cmp(&b.label()))
+            })
+            .collect_vec();
+
+        let path = args
+            .script_path
+            .as_ref()
+            .expect("Export requires a script or manifest path");
+        fs::write(path, create_reinstall_script(&versions))
+            .context(format!("Could not write reinstall script to {}", path.display()))?;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+            .context(format!("Could not make {} executable", path.display()))?;
+
+        writeln!(stdout, "Exported {} versions to {}", versions.len(), path.display()).unwrap();
+        Ok(())
+    }
+
+    /// Merge the versions of an exported manifest into the managed versions. Versions which are already installed on
+    /// this machine are only registered, missing versions are downloaded if requested.
+    pub fn import(&self, stdout: &mut impl Write, args: ImportArgs) -> anyhow::Result<()> {
+        let manifest = self.read_manifest(&args.manifest_path)?;
+
+        let mut managed_versions = self.read_managed_versions()?;
+        let mut imported = 0;
+        let mut missing = Vec::new();
+        for version in manifest.versions() {
+            if let Some(label) = version.label().filter(|label| !is_valid_label(label)) {
+                writeln!(stdout, "Skipping {}: Invalid label '{}'", version.tag(), label)?;
+            } else if managed_versions.find_version(&version).is_some() {
+                writeln!(stdout, "Skipping {}: Already managed", version)?;
+            } else if self.fs_mng.version_exists(&version) {
+                let version = managed_versions.add(version)?;
+                writeln!(stdout, "Imported {}", version)?;
+                imported += 1;
+            } else if args.download {
+                missing.push(version);
+            } else {
+                writeln!(
+                    stdout,
+                    "Skipping {}: Not installed. Use --download to download it.",
+                    version
+                )?;
+            }
+        }
+
+        if imported > 0 {
+            self.write_managed_versions(managed_versions)?;
+        }
+
+        // A failed download must not keep the remaining versions from being downloaded.
+        let missing_count = missing.len();
+        let mut errors = Vec::new();
+        for version in missing {
+            let kind = *version.kind();
+            let tag_arg = TagArg::new(Some(version.tag().clone()), kind);
+            let mut add_args = AddArgs::new(tag_arg, self.config.defaults_for(&kind).skip_checksum, false);
+            add_args.label = version.label().cloned();
+            add_args.skip_existing = true;
+            if let Err(err) = self.add(stdout, add_args) {
+                errors.push((version, err));
+            }
+        }
+
+        if missing_count > 0 {
+            writeln!(
+                stdout,
+                "Downloaded {} of {} missing versions",
+                missing_count - errors.len(),
+                missing_count
+            )?;
+        }
+        if !errors.is_empty() {
+            let messages = errors
+                .iter()
+                .map(|(version, err)| format!("{}: {:#}", version, err))
+                .join("\n");
+            bail!("Failed to download {} versions:\n{}", errors.len(), messages);
+        }
+
+        Ok(())
+    }
+
+    /// Register every directory of the Steam and Lutris version directories whose name follows a known naming scheme
+    /// but which is not managed yet. Already managed versions are kept as they are. A corrupt managed versions file,
+    /// which was backed up while reading it, is replaced by the rebuilt versions.
+    pub fn rebuild(&self, stdout: &mut impl Write) -> anyhow::Result<()> {
+        let (mut managed_versions, corrupt) = match self.read_managed_versions() {
+            Ok(managed_versions) => (managed_versions, false),
+            Err(err) => match err.downcast_ref::<CorruptManifestError>() {
+                Some(corrupt) => {
+                    writeln!(
+                        stdout,
+                        "Replacing the corrupt managed versions file, a copy was saved to {}",
+                        corrupt.backup_path.display()
+                    )?;
+                    (ManagedVersions::default(), true)
+                }
+                None => return Err(err),
+            },
+        };
+
+        let mut registered = 0;
+        let mut skipped = 0;
+        for dir_kind in [TagKind::Proton, TagKind::wine()] {
+            let (application, dir_path) = match dir_kind {
+                TagKind::Proton => (
+                    Application::Steam,
+                    self.path_cfg.steam_compatibility_tools_dir(steam_path()),
+                ),
+                TagKind::Wine { .. } => (Application::Lutris, self.path_cfg.lutris_runners_dir(xdg_data_home())),
+            };
+            let mut dir_names = self.fs_mng.list_directory_names(&dir_kind)?;
+            dir_names.sort_unstable();
+
+            for name in dir_names {
+                // Symlinks created by `apply --symlink` and unfinished extractions are not versions of their own.
+                let is_symlink = fs::symlink_metadata(dir_path.join(&name))
+                    .map(|metadata| metadata.file_type().is_symlink())
+                    .unwrap_or(false);
+                if is_symlink || name.starts_with('.') {
+                    continue;
+                }
+
+                let (base_name, label) = match name.split_once('_') {
+                    Some((base_name, label)) if is_valid_label(label) => (base_name, Some(label)),
+                    _ => (name.as_str(), None),
+                };
+                let mut version = match version_from_directory_name(base_name) {
+                    Some(version) if application.uses(version.kind()) => version,
+                    _ => {
+                        writeln!(
+                            stdout,
+                            "Skipping {}: Does not follow the naming scheme of {} versions",
+                            name,
+                            dir_kind.compatibility_tool_name()
+                        )?;
+                        skipped += 1;
+                        continue;
+                    }
+                };
+                version.set_label(label.map(String::from));
+
+                if let Some(managed) = managed_versions.find_version(&version) {
+                    if !managed.directory_name().eq(&name) {
+                        writeln!(stdout, "Skipping {}: {} is already managed", name, managed)?;
+                        skipped += 1;
+                    }
+                    continue;
+                }
+
+                let mut managed_version = ManagedVersion::from(&version);
+                managed_version.set_directory_name(name.as_str());
+                let managed_version = managed_versions.add(managed_version)?;
+                writeln!(stdout, "Registered {} as {}", name, managed_version)?;
+                registered += 1;
+            }
+        }
+
+        if registered > 0 || corrupt {
+            self.write_managed_versions(managed_versions)?;
+        }
+        writeln!(stdout, "{} registered, {} skipped", registered, skipped)?;
+        Ok(())
+    }
+
+    /// Print the version every application currently uses. Directories which are not managed by GE-Man are printed as
+    /// they are, an unreadable application config is reported instead of failing the command. Bottles is only printed
+    /// when versions are linked into its runners directory.
+    pub fn status(&self, stdout: &mut impl Write, config_paths: AppConfigPaths) -> anyhow::Result<()> {
+        let in_use_dir_names = self.read_in_use_dir_names(&config_paths);
+        let managed_versions = self.read_managed_versions()?.versions();
+        let describe = |application: &Application, dir_name: &str| match managed_versions
+            .iter()
+            .find(|v| application.uses(v.kind()) && v.directory_name().eq(dir_name))
+        {
+            Some(version) => version.to_string(),
+            None => format!("{} (not managed by GE-Man)", dir_name),
+        };
+
+        for application in [Application::Steam, Application::Lutris] {
+            let in_use_dir_name = in_use_dir_names
+                .iter()
+                .find(|(app, _)| *app == application)
+                .map(|(_, dir_name)| dir_name);
+            let status = match in_use_dir_name {
+                None => String::from("Could not read the configuration"),
+                Some(dir_name) if dir_name.is_empty() => String::from("No version applied"),
+                Some(dir_name) => describe(&application, dir_name),
+            };
+            writeln!(stdout, "{}: {}", application, status)?;
+        }
+
+        let bottles_versions = in_use_dir_names
+            .iter()
+            .filter(|(app, _)| *app == Application::Bottles)
+            .map(|(app, dir_name)| describe(app, dir_name))
+            .join(", ");
+        if !bottles_versions.is_empty() {
+            writeln!(stdout, "{}: {}", Application::Bottles, bottles_versions)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn dirs(&self, stdout: &mut impl Write) -> anyhow::Result<()> {
+        let managed_versions_default = self.path_cfg.managed_versions_config(xdg_data_home());
+        let paths = [
+            (
+                "GE-Man config directory",
+                self.path_cfg.ge_man_config_dir(xdg_config_home()),
+            ),
+            (
+                "GE-Man config file",
+                self.path_cfg.ge_man_config_file(xdg_config_home()),
+            ),
+            ("GE-Man data directory", self.path_cfg.ge_man_data_dir(xdg_data_home())),
+            (
+                "GE-Man cache directory",
+                self.path_cfg.ge_man_cache_dir(xdg_cache_home()),
+            ),
+            (
+                "Managed versions file",
+                self.managed_versions_path(&managed_versions_default),
+            ),
+            (
+                "Steam config backup",
+                self.path_cfg
+                    .app_config_backup_file(xdg_config_home(), &TagKind::Proton),
+            ),
+            (
+                "Lutris config backup",
+                self.path_cfg
+                    .app_config_backup_file(xdg_config_home(), &TagKind::wine()),
+            ),
+            ("Steam config", self.path_cfg.steam_config(steam_path())),
+            (
+                "Steam compatibility tools directory",
+                self.path_cfg.steam_compatibility_tools_dir(steam_path()),
+            ),
+            (
+                "Lutris wine runner config",
+                self.path_cfg.lutris_wine_runner_config(xdg_config_home()),
+            ),
+            (
+                "Lutris wine runners directory",
+                self.path_cfg.lutris_runners_dir(xdg_data_home()),
+            ),
+        ];
+
+        for (label, path) in paths {
+            writeln!(stdout, "{}: {}", label, path.display()).unwrap();
+        }
+        Ok(())
+    }
+
+    pub fn forget(&self, stdout: &mut impl Write, args: ForgetArgs) -> anyhow::Result<()> {
+        let version = args.tag_arg.version();
+        let mut managed_versions = self.read_managed_versions()?;
+        if managed_versions.remove(&version).is_none() {
+            bail!(CommandError::not_found(
+                "Failed to forget version: Version is not managed"
+            ));
+        }
+
+        self.write_managed_versions(managed_versions)?;
+        writeln!(stdout, "{} is now not managed by GE Helper", version).unwrap();
+        Ok(())
+    }
+
+    pub fn info(&self, stdout: &mut impl Write, args: InfoArgs, config_paths: AppConfigPaths) -> anyhow::Result<()> {
+        let managed_versions = self.read_managed_versions()?;
+        let version = match managed_versions.find_version(&args.tag_arg.version()) {
+            Some(version) => version,
+            None => bail!(CommandError::not_found("Given version is not managed")),
+        };
+
+        let in_use_dir_names = self.read_in_use_dir_names(&config_paths);
+        let path = self.fs_mng.version_path(&version);
+        let yes_no = |value: bool| if value { "yes" } else { "no" };
+
+        writeln!(stdout, "Tag: {}", version.tag())?;
+        writeln!(stdout, "Kind: {}", version.kind().compatibility_tool_name())?;
+        writeln!(stdout, "Label: {}", version.label().map_or("none", String::as_str))?;
+        writeln!(stdout, "Directory name: {}", version.directory_name())?;
+        writeln!(stdout, "Path: {}", path.display())?;
+        writeln!(
+            stdout,
+            "Size: {}",
+            format_directory_size(self.fs_mng.version_size(&version))
+        )?;
+        for application in [Application::Steam, Application::Lutris, Application::Bottles] {
+            if !application.uses(version.kind()) {
+                continue;
+            }
+            let in_use = in_use_dir_names
+                .iter()
+                .any(|(app, dir_name)| *app == application && dir_name.eq(version.directory_name()));
+            writeln!(stdout, "In use by {}: {}", application, yes_no(in_use))?;
+        }
+        writeln!(stdout, "Pinned: {}", yes_no(version.pinned()))?;
+        if version.kind() == &TagKind::Proton {
+            let user_settings = path.join("user_settings.py").exists();
+            writeln!(stdout, "User settings: {}", yes_no(user_settings))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn completions(&self, stdout: &mut impl Write, args: CompletionsArgs) -> anyhow::Result<()> {
+        clap_complete::generate(args.shell, &mut setup_clap(), "geman", stdout);
+        Ok(())
+    }
+
+    /// Check the files of the given or every managed version. Extracted files can not be compared against the checksum
+    /// of the release archive, so only the presence of the files every version contains is checked.
+    pub fn verify(&self, stdout: &mut impl Write, args: VerifyArgs) -> anyhow::Result<()> {
+        let managed_versions = self.read_managed_versions()?;
+        let versions = match &args.version {
+            Some(version) => match managed_versions.find_version(version) {
+                Some(version) => vec![version],
+                None => bail!(CommandError::not_found(
+                    "Failed to verify version: Version is not managed"
+                )),
+            },
+            None => managed_versions.versions().into_iter().sorted().collect_vec(),
+        };
+
+        if versions.is_empty() {
+            writeln!(stdout, "No versions installed").unwrap();
+            return Ok(());
+        }
+
+        let mut failed = 0;
+        for version in versions {
+            match self.fs_mng.missing_version_files(&version) {
+                Ok(missing) if missing.is_empty() => writeln!(stdout, "{}: OK", version).unwrap(),
+                Ok(missing) => {
+                    failed += 1;
+                    writeln!(stdout, "{}: FAILED - Missing or empty: {}", version, missing.join(", ")).unwrap();
+                }
+                Err(err) => {
+                    failed += 1;
+                    writeln!(stdout, "{}: FAILED - {:#}", version, err).unwrap();
+                }
+            }
+            if version.checksum_skipped() {
+                writeln!(stdout, "{}: Note - No checksum baseline exists", version).unwrap();
+            }
+        }
+
+        if failed > 0 {
+            bail!("Verification failed for {} version(s)", failed);
+        }
+        Ok(())
+    }
+
+    pub fn pin(&self, stdout: &mut impl Write, args: PinArgs) -> anyhow::Result<()> {
+        let version = args.tag_arg.version();
+        let mut managed_versions = self.read_managed_versions()?;
+        let version = match managed_versions.set_pinned(&version, args.pinned) {
+            Some(version) => version,
+            None => bail!(CommandError::not_found(format!(
+                "Failed to {} version: Version is not managed",
+                if args.pinned { "pin" } else { "unpin" }
+            ))),
+        };
+
+        self.write_managed_versions(managed_versions)?;
+        if args.pinned {
+            writeln!(stdout, "{} is now pinned", version).unwrap();
+        } else {
+            writeln!(stdout, "{} is no longer pinned", version).unwrap();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use anyhow::bail;
+    use assert_fs::TempDir;
+    use clap_complete::Shell;
+    use ge_man_lib::download::response::{DownloadedArchive, DownloadedChecksum, GeRelease};
+    use ge_man_lib::tag::Tag;
+    use mockall::mock;
+    use mockall::predicate::{always, eq};
+    use test_case::test_case;
+
+    use crate::error::ErrorCategory;
+    use crate::filesystem::{MigrationPlan, MockFilesystemManager};
+    use crate::path::MockPathConfiguration;
+
+    use super::*;
+
+    mock! {
+        Downloader {}
+        impl GeDownload for Downloader {
+            fn fetch_release(&self, tag: Option<String>, kind: TagKind) -> Result<GeRelease, GithubError>;
+            fn download_release_assets(&self, request: DownloadRequest) -> Result<DownloadedAssets, GithubError>;
+        }
+    }
+
+    struct AssertLines {
+        latest_line: String,
+        pub lines: Vec<String>,
+    }
+
+    impl AssertLines {
+        pub fn new() -> Self {
+            AssertLines {
+                latest_line: String::new(),
+                lines: Vec::new(),
+            }
+        }
+
+        pub fn assert_line(&self, line: usize, expected: &str) {
+            let line = self.lines.get(line).unwrap();
+            assert!(line.contains("\n"));
+            assert_eq!(line.trim(), expected);
+        }
+
+        pub fn assert_empty(&self) {
+            assert!(self.lines.is_empty())
+        }
+    }
+
+    impl io::Write for AssertLines {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let len = buf.len();
+            let str = String::from_utf8_lossy(buf);
+            self.latest_line.push_str(&str);
+
+            if self.latest_line.contains("\n") {
+                self.lines.push(self.latest_line.clone());
+                self.latest_line = String::new();
+            }
+
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn proton_6_20_1() -> ManagedVersion {
+        ManagedVersion::from(Version::proton("6.20-GE-1"))
+    }
+
+    /// With the latest tag cache, adding a specific tag fetches the latest release to warn about tags newer than it.
+    fn expect_latest_release(ge_downloader: &mut MockDownloader, latest: &'static str) {
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, _| tag.is_none())
+            .returning(move |_, _| Ok(GeRelease::new(String::from(latest), Vec::new())));
+    }
+
+    fn setup_managed_versions(json_path: &Path, versions: Vec<ManagedVersion>) {
+        fs::create_dir_all(json_path.parent().unwrap()).unwrap();
+        let managed_versions = ManagedVersions::new(versions);
+        managed_versions.write_to_file(json_path).unwrap();
+    }
+
+    #[test]
+    fn toml_format_should_read_existing_json_and_write_toml() {
+        let args = ForgetArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![proton_6_20_1(), ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        let default_path = json_path.clone();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| default_path.clone());
+
+        let mut config = GeManConfig::default();
+        config.managed_versions_format = ManagedVersionsFormat::Toml;
+        let writer = TerminalWriter::with_config(&ge_downloader, &fs_mng, &path_cfg, config);
+
+        let mut stdout = AssertLines::new();
+        writer.forget(&mut stdout, args).unwrap();
+
+        let toml_path = tmp_dir.join("ge_man/managed_versions.toml");
+        let managed_versions = ManagedVersions::from_file(&toml_path).unwrap();
+        assert_eq!(
+            managed_versions.versions(),
+            vec![ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")]
+        );
+        assert!(!tmp_dir.join("ge_man/managed_versions.json").exists());
+    }
+
+    #[test]
+    fn json_format_should_read_existing_toml_after_switching_back() {
+        let args = ForgetArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        let toml_path = tmp_dir.join("ge_man/managed_versions.toml");
+        setup_managed_versions(
+            &toml_path,
+            vec![proton_6_20_1(), ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        let default_path = json_path.clone();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| default_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.forget(&mut stdout, args).unwrap();
+
+        let managed_versions = ManagedVersions::from_file(&json_path).unwrap();
+        assert_eq!(
+            managed_versions.versions(),
+            vec![ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")]
+        );
+        assert!(!toml_path.exists());
+    }
+
+    #[test]
+    fn forget_should_print_success_message() {
+        let args = ForgetArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.forget(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, "6.20-GE-1 (Proton) is now not managed by GE Helper");
+    }
+
+    #[test]
+    fn info_should_print_version_details() {
+        let args = InfoArgs::new(TagArg::new(Some(Tag::from("6.21-GE-2")), TagKind::Proton));
+        let tmp_dir = TempDir::new().unwrap();
+        let version_dir = tmp_dir.join("Proton-6.21-GE-2");
+        fs::create_dir_all(&version_dir).unwrap();
+        fs::write(version_dir.join("user_settings.py"), "").unwrap();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        let path = version_dir.clone();
+        fs_mng
+            .expect_version_path()
+            .with(eq(ManagedVersion::new(
+                "6.21-GE-2",
+                TagKind::Proton,
+                "Proton-6.21-GE-2",
+            )))
+            .once()
+            .returning(move |_| path.clone());
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+        let ge_downloader = MockDownloader::new();
+
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.info(&mut stdout, args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Tag: 6.21-GE-2");
+        stdout.assert_line(1, "Kind: Proton GE");
+        stdout.assert_line(2, "Label: none");
+        stdout.assert_line(3, "Directory name: Proton-6.21-GE-2");
+        stdout.assert_line(4, &format!("Path: {}", version_dir.display()));
+        stdout.assert_line(5, "Size: 412 MiB");
+        stdout.assert_line(6, "In use by Steam: yes");
+        stdout.assert_line(7, "Pinned: no");
+        stdout.assert_line(8, "User settings: yes");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn info_for_unmanaged_version_should_fail() {
+        let args = InfoArgs::new(TagArg::new(Some(Tag::from("6.21-GE-2")), TagKind::Proton));
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let result = writer.info(&mut stdout, args, config_paths);
+
+        assert_eq!(result.unwrap_err().to_string(), "Given version is not managed");
+        stdout.assert_empty();
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test_case(true, "6.20-GE-1 (Proton) is now pinned"; "Pin version")]
+    #[test_case(false, "6.20-GE-1 (Proton) is no longer pinned"; "Unpin version")]
+    fn pin_should_update_managed_version(pinned: bool, expected: &str) {
+        let args = PinArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), pinned);
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![pinned_proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        let path = json_path.clone();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.pin(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, expected);
+        let managed_versions = ManagedVersions::from_file(&json_path).unwrap();
+        let version = managed_versions
+            .find_version(&Version::new("6.20-GE-1", TagKind::Proton))
+            .unwrap();
+        assert_eq!(version.pinned(), pinned);
+    }
+
+    #[test]
+    fn pin_not_managed_version_should_fail() {
+        let args = PinArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), true);
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.pin(&mut stdout, args);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Failed to pin version: Version is not managed"
+        );
+        stdout.assert_empty();
+    }
+
+    #[test_case(Shell::Bash; "Bash completions")]
+    #[test_case(Shell::Zsh; "Zsh completions")]
+    #[test_case(Shell::Fish; "Fish completions")]
+    #[test_case(Shell::PowerShell; "PowerShell completions")]
+    fn completions_should_print_script(shell: Shell) {
+        let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
+        let path_cfg = MockPathConfiguration::new();
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = Vec::new();
+        writer.completions(&mut stdout, CompletionsArgs::new(shell)).unwrap();
+
+        let script = String::from_utf8(stdout).unwrap();
+        assert!(script.contains("geman"));
+        assert!(script.contains("verify"));
+    }
+
+    #[test]
+    fn verify_should_report_every_managed_version() {
+        let args = VerifyArgs::new(None);
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_missing_version_files()
+            .with(eq(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")))
+            .once()
+            .returning(|_| Ok(vec![]));
+        fs_mng
+            .expect_missing_version_files()
+            .with(eq(ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")))
+            .once()
+            .returning(|_| Ok(vec![String::from("proton"), String::from("toolmanifest.vdf")]));
+        fs_mng
+            .expect_missing_version_files()
+            .with(eq(ManagedVersion::new("6.19-GE-1", TagKind::Proton, "")))
+            .once()
+            .returning(|_| Err(anyhow!("Version directory 'Proton-6.19-GE-1' does not exist")));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.21-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.19-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.verify(&mut stdout, args);
+
+        assert_eq!(result.unwrap_err().to_string(), "Verification failed for 2 version(s)");
+        stdout.assert_line(
+            0,
+            "6.19-GE-1 (Proton): FAILED - Version directory 'Proton-6.19-GE-1' does not exist",
+        );
+        stdout.assert_line(1, "6.20-GE-1 (Proton): OK");
+        stdout.assert_line(
+            2,
+            "6.21-GE-1 (Proton): FAILED - Missing or empty: proton, toolmanifest.vdf",
+        );
+    }
+
+    #[test]
+    fn verify_should_note_versions_without_checksum_baseline() {
+        let args = VerifyArgs::new(None);
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_missing_version_files().once().returning(|_| Ok(vec![]));
+
+        let mut version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "");
+        version.set_checksum_skipped(true);
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![version]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.verify(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, "6.20-GE-1 (Proton): OK");
+        stdout.assert_line(1, "6.20-GE-1 (Proton): Note - No checksum baseline exists");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn verify_not_managed_version_should_fail() {
+        let args = VerifyArgs::new(Some(Version::new("6.20-GE-1", TagKind::Proton)));
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_missing_version_files().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.verify(&mut stdout, args);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Failed to verify version: Version is not managed"
+        );
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn forget_should_print_error_message() {
+        let args = ForgetArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.forget(&mut stdout, args);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Failed to forget version: Version is not managed");
+        assert_eq!(ErrorCategory::of(&err), ErrorCategory::NotFound);
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn list_newest_output() {
+        let args = ListArgs::new(None, true);
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
+                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let mut stdout = AssertLines::new();
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.20-GE-1");
+        stdout.assert_line(2, "");
+        stdout.assert_line(3, "Wine GE:");
+        stdout.assert_line(4, "* 6.20-GE-1");
+        stdout.assert_line(5, "");
+        stdout.assert_line(6, "Wine GE (LoL):");
+        stdout.assert_line(7, "* 6.16-GE-3-LoL");
+        stdout.assert_line(8, "");
+    }
+
+    #[test]
+    fn list_with_manifest_should_list_the_versions_of_the_manifest() {
+        let tmp_dir = TempDir::new().unwrap();
+        let manifest_path = tmp_dir.join("backup/managed_versions.json.bak");
+        setup_managed_versions(
+            &manifest_path,
+            vec![
+                ManagedVersion::new("GE-Proton7-8", TagKind::Proton, "GE-Proton7-8"),
+                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), "lutris-ge-6.16-3-lol-x86_64"),
+            ],
+        );
+        let mut args = ListArgs::new(None, false);
+        args.manifest = Some(manifest_path);
+
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg.expect_managed_versions_config().never();
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let mut stdout = AssertLines::new();
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* GE-Proton7-8");
+        stdout.assert_line(2, "");
+        stdout.assert_line(3, "Wine GE (LoL):");
+        stdout.assert_line(4, "* 6.16-GE-3-LoL");
+        stdout.assert_line(5, "");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn list_with_missing_manifest_should_fail() {
+        let mut args = ListArgs::new(None, false);
+        args.manifest = Some(PathBuf::from("/tmp/does-not-exist/versions.json"));
+
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+        let path_cfg = MockPathConfiguration::new();
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let mut stdout = AssertLines::new();
+        let err = writer
+            .list(&mut stdout, &mut io::sink(), args, config_paths)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Manifest /tmp/does-not-exist/versions.json does not exist"
+        );
+        assert_eq!(ErrorCategory::of(&err), ErrorCategory::NotFound);
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn list_newest_with_filter_should_use_newest_matching_version() {
+        let mut args = ListArgs::new(Some(TagKind::Proton), true);
+        args.filter = Some(String::from("6.20-*"));
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.21-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-2", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-3", TagKind::wine(), ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let mut stdout = AssertLines::new();
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.20-GE-2");
+        stdout.assert_line(2, "");
+    }
+
+    #[test]
+    fn list_with_filter_without_matches() {
+        let mut args = ListArgs::new(None, false);
+        args.filter = Some(String::from("7.0"));
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let mut stdout = AssertLines::new();
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "No versions match 7.0");
+    }
+
+    #[test]
+    fn list_all_should_print_every_kind() {
+        let mut args = ListArgs::new(None, false);
+        args.all = true;
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let mut stdout = AssertLines::new();
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.20-GE-1");
+        stdout.assert_line(2, "");
+        stdout.assert_line(3, "Wine GE:");
+        stdout.assert_line(4, "No versions installed");
+        stdout.assert_line(5, "");
+        stdout.assert_line(6, "Wine GE (LoL):");
+        stdout.assert_line(7, "No versions installed");
+        stdout.assert_line(8, "");
+    }
+
+    #[test_case(false, &["Wine GE:", "* 6.21-GE-1", ""]; "Without LoL")]
+    #[test_case(true, &["Wine GE:", "* 6.21-GE-1", "", "Wine GE (LoL):", "* 6.16-GE-3-LoL", ""]; "With LoL")]
+    fn list_wine_should_only_include_lol_when_requested(include_lol: bool, expected: &[&str]) {
+        let mut args = ListArgs::new(Some(TagKind::wine()), false);
+        args.include_lol = include_lol;
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                proton_6_20_1(),
+                ManagedVersion::new("6.21-GE-1", TagKind::wine(), ""),
+                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let mut stdout = AssertLines::new();
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        assert_eq!(stdout.lines.len(), expected.len());
+        for (i, line) in expected.iter().enumerate() {
+            stdout.assert_line(i, line);
+        }
+    }
+
+    #[test_case("6.21-GE-1", "21", true; "substring")]
+    #[test_case("6.21-GE-1", "6.21-*", true; "star wildcard")]
+    #[test_case("6.21-GE-1", "6.2?-GE-1", true; "question mark wildcard")]
+    #[test_case("6.21-GE-1", "6.20-*", false; "wildcard without match")]
+    #[test_case("6.21-GE-1", "GE-*", false; "wildcard has to match the whole tag")]
+    #[test_case("6.21-GE-1", "7.0", false; "substring without match")]
+    fn tag_matches_filter(tag: &str, pattern: &str, expected: bool) {
+        assert_eq!(tag_matches(tag, pattern), expected);
+    }
+
+    #[test]
+    fn list_newest_output_with_in_use_version() {
+        let args = ListArgs::new(None, true);
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_version_exists().times(2).returning(|_| true);
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
+                ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64"),
+                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let mut stdout = AssertLines::new();
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.21-GE-2 - In use by Steam");
+        stdout.assert_line(2, "");
+        stdout.assert_line(3, "Wine GE:");
+        stdout.assert_line(4, "* 6.21-GE-1 - In use by Lutris");
+        stdout.assert_line(5, "");
+        stdout.assert_line(6, "Wine GE (LoL):");
+        stdout.assert_line(7, "* 6.16-GE-3-LoL");
+        stdout.assert_line(8, "");
+    }
+
+    #[test]
+    fn list_should_show_versions_linked_into_bottles() {
+        let args = ListArgs::new(Some(TagKind::wine()), false);
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_version_exists().times(4).returning(|_| true);
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
+                ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64"),
+                ManagedVersion::new("6.20-GE-1", TagKind::wine(), "lutris-ge-6.20-1-x86_64"),
+            ],
+        );
+
+        let bottles_runners = tmp_dir.join("bottles/runners");
+        fs::create_dir_all(&bottles_runners).unwrap();
+        fs::create_dir(bottles_runners.join("Proton-6.21-GE-2")).unwrap();
+        for dir_name in ["lutris-ge-6.21-1-x86_64", "lutris-ge-6.20-1-x86_64"] {
+            std::os::unix::fs::symlink(tmp_dir.join(dir_name), bottles_runners.join(dir_name)).unwrap();
+        }
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let config_paths = AppConfigPaths::new(
+            PathBuf::from("test_resources/assets/config.vdf"),
+            PathBuf::from("test_resources/assets/wine.yml"),
+            bottles_runners,
+        );
+        let mut stdout = AssertLines::new();
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Wine GE:");
+        stdout.assert_line(1, "* 6.21-GE-1 - In use by Lutris, Bottles");
+        stdout.assert_line(2, "* 6.20-GE-1 - In use by Bottles");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn list_all() {
+        let args = ListArgs::new(None, false);
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.20-GE-2", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.19-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-2", TagKind::wine(), ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
+                ManagedVersion::new("6.19-GE-1", TagKind::wine(), ""),
+                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
+                ManagedVersion::new("6.16-2-GE-LoL", TagKind::lol(), ""),
+                ManagedVersion::new("6.16-1-GE-LoL", TagKind::lol(), ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.20-GE-2");
+        stdout.assert_line(2, "* 6.20-GE-1");
+        stdout.assert_line(3, "* 6.19-GE-1");
+        stdout.assert_line(4, "");
+        stdout.assert_line(5, "Wine GE:");
+        stdout.assert_line(6, "* 6.20-GE-2");
+        stdout.assert_line(7, "* 6.20-GE-1");
+        stdout.assert_line(8, "* 6.19-GE-1");
+        stdout.assert_line(9, "");
+        stdout.assert_line(10, "Wine GE (LoL):");
+        stdout.assert_line(11, "* 6.16-GE-3-LoL");
+        stdout.assert_line(12, "* 6.16-2-GE-LoL");
+        stdout.assert_line(13, "* 6.16-1-GE-LoL");
+        stdout.assert_line(14, "");
+    }
+
+    #[test]
+    fn list_all_with_in_use_version() {
+        let args = ListArgs::new(None, false);
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_version_exists().times(2).returning(|_| true);
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.19-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64"),
+                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
+                ManagedVersion::new("6.19-GE-1", TagKind::wine(), ""),
+                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
+                ManagedVersion::new("6.16-2-GE-LoL", TagKind::lol(), ""),
+                ManagedVersion::new("6.16-1-GE-LoL", TagKind::lol(), ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.21-GE-2 - In use by Steam");
+        stdout.assert_line(2, "* 6.20-GE-1");
+        stdout.assert_line(3, "* 6.19-GE-1");
+        stdout.assert_line(4, "");
+        stdout.assert_line(5, "Wine GE:");
+        stdout.assert_line(6, "* 6.21-GE-1 - In use by Lutris");
+        stdout.assert_line(7, "* 6.20-GE-1");
+        stdout.assert_line(8, "* 6.19-GE-1");
+        stdout.assert_line(9, "");
+        stdout.assert_line(10, "Wine GE (LoL):");
+        stdout.assert_line(11, "* 6.16-GE-3-LoL");
+        stdout.assert_line(12, "* 6.16-2-GE-LoL");
+        stdout.assert_line(13, "* 6.16-1-GE-LoL");
+        stdout.assert_line(14, "");
+    }
+
+    #[test]
+    fn list_with_size_should_mark_missing_directories() {
+        let mut args = ListArgs::new(Some(TagKind::Proton), false);
+        args.size = true;
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_version_exists().once().returning(|_| true);
+        fs_mng
+            .expect_version_size()
+            .with(eq(ManagedVersion::new(
+                "6.21-GE-2",
+                TagKind::Proton,
+                "Proton-6.21-GE-2",
+            )))
+            .once()
+            .returning(|_| Ok(412 * 1024 * 1024));
+        fs_mng
+            .expect_version_size()
+            .with(eq(ManagedVersion::new(
+                "6.20-GE-1",
+                TagKind::Proton,
+                "Proton-6.20-GE-1",
+            )))
+            .once()
+            .returning(|_| bail!("Directory does not exist"));
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1"),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.21-GE-2 - In use by Steam - 412 MiB");
+        stdout.assert_line(2, "* 6.20-GE-1 - (missing)");
+    }
+
+    #[test]
+    fn list_should_warn_about_missing_in_use_version_directory() {
+        let args = ListArgs::new(Some(TagKind::Proton), false);
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_version_exists()
+            .with(eq(ManagedVersion::new(
+                "6.21-GE-2",
+                TagKind::Proton,
+                "Proton-6.21-GE-2",
+            )))
+            .once()
+            .returning(|_| false);
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let mut stderr = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut stderr, args, config_paths).unwrap();
+
+        stderr.assert_line(
+            0,
+            "Warning: Steam uses Proton-6.21-GE-2, but the directory does not exist anymore. Apply a different version \
+             to fix this.",
+        );
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.21-GE-2 - In use by Steam");
+    }
+
+    #[test]
+    fn list_json_with_in_use_version() {
+        let mut args = ListArgs::new(None, false);
+        args.json = true;
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_version_exists().times(2).returning(|_| true);
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1"),
+                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
+                ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64"),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = Vec::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        let entries: serde_json::Value = serde_json::from_slice(&stdout).unwrap();
+        let entries = entries.as_array().unwrap();
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0]["tag"], "6.21-GE-2");
+        assert_eq!(entries[0]["directory_name"], "Proton-6.21-GE-2");
+        assert_eq!(entries[0]["in_use"], true);
+        assert_eq!(entries[0]["in_use_by"], serde_json::json!(["Steam"]));
+        assert_eq!(entries[0]["application"], "Steam");
+        assert_eq!(entries[1]["tag"], "6.20-GE-1");
+        assert_eq!(entries[1]["in_use"], false);
+        assert_eq!(entries[1]["in_use_by"], serde_json::json!([]));
+        assert_eq!(entries[1]["application"], "Steam");
+        assert_eq!(entries[2]["tag"], "6.21-GE-1");
+        assert_eq!(entries[2]["in_use"], true);
+        assert_eq!(entries[2]["application"], "Lutris");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn list_json_without_versions_prints_empty_array() {
+        let mut args = ListArgs::new(None, false);
+        args.json = true;
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "[]");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test_case(ListSort::Semver, ["7.1-GE-1", "6.20-GE-1", "6.9-GE-1"]; "Semver")]
+    #[test_case(ListSort::Name, ["6.20-GE-1", "6.9-GE-1", "7.1-GE-1"]; "Name")]
+    #[test_case(ListSort::Added, ["6.9-GE-1", "7.1-GE-1", "6.20-GE-1"]; "Added")]
+    fn list_with_sort(sort: ListSort, expected: [&str; 3]) {
+        let mut args = ListArgs::new(Some(TagKind::Proton), false);
+        args.sort = sort;
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.9-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
+                ManagedVersion::new("7.1-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        for (i, tag) in expected.iter().enumerate() {
+            stdout.assert_line(i + 1, &format!("* {}", tag));
+        }
+    }
+
+    #[test]
+    fn list_show_added_should_show_when_versions_were_added() {
+        let mut args = ListArgs::new(Some(TagKind::Proton), false);
+        args.show_added = true;
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let mut added = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "");
+        added.set_added_at(Some(String::from("2022-08-01T12:30:00Z")));
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![added, ManagedVersion::new("6.19-GE-1", TagKind::Proton, "")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.20-GE-1 - added 2022-08-01T12:30:00Z");
+        stdout.assert_line(2, "* 6.19-GE-1 - added unknown");
+    }
+
+    #[test]
+    fn list_with_limit_and_page() {
+        let mut args = ListArgs::new(Some(TagKind::Proton), false);
+        args.limit = Some(2);
+        args.page = 2;
+        let fs_mng = MockFilesystemManager::new();
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.20-GE-2", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.19-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Proton GE:");
+        stdout.assert_line(1, "* 6.19-GE-1");
+        stdout.assert_line(2, "(showing 1 of 3)");
+        stdout.assert_line(3, "");
+    }
+
+    #[test]
+    fn list_merged_shows_managed_and_unmanaged_directories() {
+        let mut args = ListArgs::new(None, false);
+        args.merged = true;
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_list_directory_names()
+            .with(eq(TagKind::Proton))
+            .once()
+            .returning(|_| Ok(vec![String::from("Proton-6.20-GE-1"), String::from("Proton-6.21-GE-2")]));
+        fs_mng
+            .expect_list_directory_names()
+            .with(eq(TagKind::wine()))
+            .once()
+            .returning(|_| {
+                Ok(vec![
+                    String::from("lutris-ge-6.21-1-x86_64"),
+                    String::from("lutris-ge-lol"),
+                ])
+            });
+        fs_mng
+            .expect_list_compat_tool_path_directories()
+            .once()
+            .returning(|_| vec![(PathBuf::from("/opt/tools"), String::from("GE-Proton7-8"))]);
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
+                ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64"),
+                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), "lutris-ge-lol"),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Steam:");
+        stdout.assert_line(1, "* Proton-6.20-GE-1 - unmanaged");
+        stdout.assert_line(2, "* Proton-6.21-GE-2 - 6.21-GE-2 (Proton GE) - In use by Steam");
+        stdout.assert_line(3, "* GE-Proton7-8 - unmanaged [STEAM_COMPAT_TOOL_PATHS: /opt/tools]");
+        stdout.assert_line(4, "");
+        stdout.assert_line(5, "Lutris:");
+        stdout.assert_line(6, "* lutris-ge-6.21-1-x86_64 - 6.21-GE-1 (Wine GE) - In use by Lutris");
+        stdout.assert_line(7, "* lutris-ge-lol - 6.16-GE-3-LoL (Wine GE (LoL))");
+        stdout.assert_line(8, "");
+    }
+
+    #[test]
+    fn list_merged_with_size_should_size_every_directory() {
+        let mut args = ListArgs::new(Some(TagKind::Proton), false);
+        args.merged = true;
+        args.size = true;
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_list_directory_names()
+            .with(eq(TagKind::Proton))
+            .once()
+            .returning(|_| Ok(vec![String::from("Proton-6.20-GE-1")]));
+        fs_mng
+            .expect_list_compat_tool_path_directories()
+            .once()
+            .returning(|_| vec![(PathBuf::from("/opt/tools"), String::from("GE-Proton7-8"))]);
+        fs_mng
+            .expect_directory_size()
+            .with(eq(PathBuf::from("/steam/compatibilitytools.d/Proton-6.20-GE-1")))
+            .once()
+            .returning(|_| Ok(2048));
+        fs_mng
+            .expect_directory_size()
+            .with(eq(PathBuf::from("/opt/tools/GE-Proton7-8")))
+            .once()
+            .returning(|_| bail!("Directory does not exist"));
+        let ge_downloader = MockDownloader::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+        path_cfg
+            .expect_steam_compatibility_tools_dir()
+            .once()
+            .returning(|_| PathBuf::from("/steam/compatibilitytools.d"));
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.list(&mut stdout, &mut io::sink(), args, config_paths).unwrap();
+
+        stdout.assert_line(0, "Steam:");
+        stdout.assert_line(1, "* Proton-6.20-GE-1 - unmanaged - 2 KiB");
+        stdout.assert_line(
+            2,
+            "* GE-Proton7-8 - unmanaged - (missing) [STEAM_COMPAT_TOOL_PATHS: /opt/tools]",
+        );
+    }
+
+    #[test_case(512, "512 B"; "Bytes")]
+    #[test_case(2048, "2 KiB"; "Kibibytes")]
+    #[test_case(412 * 1024 * 1024, "412 MiB"; "Mebibytes")]
+    #[test_case(3 * 1024 * 1024 * 1024, "3 GiB"; "Gibibytes")]
+    fn format_size_output(bytes: u64, expected: &str) {
+        assert_eq!(format_size(bytes), expected);
+    }
+
+    #[test_case(Duration::from_millis(500), "0s"; "Less than a second")]
+    #[test_case(Duration::from_secs(42), "42s"; "Seconds")]
+    #[test_case(Duration::from_secs(83), "1m23s"; "Minutes and seconds")]
+    fn format_duration_output(duration: Duration, expected: &str) {
+        assert_eq!(format_duration(duration), expected);
+    }
+
+    #[test_case(0, Duration::from_millis(500); "First retry uses the base delay")]
+    #[test_case(1, Duration::from_secs(1); "Second retry doubles the delay")]
+    #[test_case(3, Duration::from_secs(4); "Fourth retry")]
+    #[test_case(10, MAX_RETRY_DELAY; "Delay is capped")]
+    fn backoff_delay_doubles_per_attempt(attempt: u32, expected: Duration) {
+        assert_eq!(backoff_delay(Duration::from_millis(500), attempt), expected);
+    }
+
+    #[test]
+    fn retry_should_report_exhausted_retries_with_last_error() {
+        let mut attempts = 0;
+        let result: anyhow::Result<()> = retry(
+            2,
+            Duration::ZERO,
+            |_| true,
+            || {
+                attempts += 1;
+                Err(GithubError::NoTags)
+            },
+        );
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "Retries exhausted (3 attempts): No tags could be found"
+        );
+        assert!(matches!(err.downcast_ref::<GithubError>(), Some(GithubError::NoTags)));
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn retry_should_fail_immediately_on_permanent_errors() {
+        let mut attempts = 0;
+        let result: anyhow::Result<()> = retry(2, Duration::ZERO, is_retryable, || {
+            attempts += 1;
+            Err(GithubError::NoTags)
+        });
+
+        assert_eq!(format!("{:#}", result.unwrap_err()), "No tags could be found");
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn retry_should_return_result_of_successful_retry() {
+        let mut attempts = 0;
+        let result = retry(
+            2,
+            Duration::ZERO,
+            |_| true,
+            || {
+                attempts += 1;
+                if attempts < 2 {
+                    Err(GithubError::NoTags)
+                } else {
+                    Ok(attempts)
+                }
+            },
+        );
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn add_successful_output() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = AddArgs::new(tag_arg, true, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().once().returning(|_| {
+            Ok(DownloadedAssets {
+                tag: "".to_string(),
+                compressed_archive: DownloadedArchive {
+                    compressed_content: vec![],
+                    file_name: "".to_string(),
+                },
+                checksum: None,
+            })
+        });
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, "Skipping checksum comparison");
+        stdout.assert_line(1, "Successfully added version");
+        stdout.assert_line(2, "Installed 6.20-GE-1 (Proton), 412 MiB, in 0s.");
+
+        let managed_versions = ManagedVersions::from_file(&tmp_dir.join("ge_man/managed_versions.json")).unwrap();
+        assert!(managed_versions.versions()[0].checksum_skipped());
+    }
+
+    #[test]
+    fn add_with_steam_root_should_install_into_steam_root() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, true, false);
+        args.steam_root = Some(PathBuf::from("/mnt/games/steam"));
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().once().returning(|_| {
+            Ok(DownloadedAssets {
+                tag: "".to_string(),
+                compressed_archive: DownloadedArchive {
+                    compressed_content: vec![],
+                    file_name: "".to_string(),
+                },
+                checksum: None,
+            })
+        });
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_ensure_free_space()
+            .withf(|_, steam_root, _| steam_root.eq(&Some(Path::new("/mnt/games/steam"))))
+            .once()
+            .returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .withf(|_, _, steam_root| steam_root.eq(&Some(Path::new("/mnt/games/steam"))))
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+        fs_mng.expect_apply_to_app_config().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(1, "Successfully added version");
+    }
+
+    #[test]
+    fn add_with_checksum_comparison_successful_output() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = AddArgs::new(tag_arg, false, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().once().returning(|_| {
+            let tar = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
+            let checksum = fs::read_to_string("test_resources/assets/Proton-6.20-GE-1.sha512sum").unwrap();
+
+            Ok(DownloadedAssets {
+                tag: "6.20-GE-1".to_string(),
+                compressed_archive: DownloadedArchive {
+                    compressed_content: tar,
+                    file_name: "Proton-6.20-GE-1.tar.gz".to_string(),
+                },
+                checksum: Some(DownloadedChecksum {
+                    checksum,
+                    file_name: "Proton-6.20-GE-1.sha512sum".to_string(),
+                }),
+            })
+        });
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, "Performing checksum comparison: Checksums match");
+        stdout.assert_line(1, "Successfully added version");
+        stdout.assert_line(2, "Installed 6.20-GE-1 (Proton), 412 MiB, in 0s.");
+    }
+
+    fn proton_assets(tag: &str, checksum_tag: &str) -> DownloadedAssets {
+        let tar = fs::read(format!("test_resources/assets/Proton-{}.tar.gz", tag)).unwrap();
+        let checksum = fs::read_to_string(format!("test_resources/assets/Proton-{}.sha512sum", checksum_tag)).unwrap();
+
+        DownloadedAssets {
+            tag: tag.to_string(),
+            compressed_archive: DownloadedArchive {
+                compressed_content: tar,
+                file_name: format!("Proton-{}.tar.gz", tag),
+            },
+            checksum: Some(DownloadedChecksum {
+                checksum,
+                file_name: format!("Proton-{}.sha512sum", tag),
+            }),
+        }
+    }
+
+    fn setup_cached_add(
+        tmp_dir: &TempDir,
+        fs_mng: &mut MockFilesystemManager,
+        path_cfg: &mut MockPathConfiguration,
+    ) -> PathBuf {
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let cache_dir = tmp_dir.join("cache");
+        let dir = cache_dir.clone();
+        path_cfg
+            .expect_ge_man_cache_dir()
+            .once()
+            .returning(move |_| dir.clone());
+
+        cache_dir
+    }
+
+    #[test]
+    fn add_with_keep_archive_cache_should_use_cached_archive() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.keep_archive_cache = true;
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let mut fs_mng = MockFilesystemManager::new();
+        let mut path_cfg = MockPathConfiguration::new();
+        let cache_dir = setup_cached_add(&tmp_dir, &mut fs_mng, &mut path_cfg);
+
+        let version = Version::new(Tag::from("6.20-GE-1"), TagKind::Proton);
+        let assets = proton_assets("6.20-GE-1", "6.20-GE-1");
+        ArchiveCache::new(cache_dir, u64::MAX)
+            .put(&version, &assets.compressed_archive, assets.checksum.as_ref().unwrap())
+            .unwrap();
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, "Using cached archive for 6.20-GE-1 (Proton)");
+        stdout.assert_line(1, "Successfully added version");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_with_keep_archive_cache_should_cache_downloaded_archive() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.keep_archive_cache = true;
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_download_release_assets()
+            .once()
+            .returning(|_| Ok(proton_assets("6.20-GE-1", "6.20-GE-1")));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let mut fs_mng = MockFilesystemManager::new();
+        let mut path_cfg = MockPathConfiguration::new();
+        let cache_dir = setup_cached_add(&tmp_dir, &mut fs_mng, &mut path_cfg);
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, "Performing checksum comparison: Checksums match");
+        stdout.assert_line(1, "Successfully added version");
+
+        let version = Version::new(Tag::from("6.20-GE-1"), TagKind::Proton);
+        assert!(ArchiveCache::new(cache_dir, u64::MAX).get(&version).is_some());
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_with_keep_archive_cache_and_mismatching_cached_archive_should_download_again() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.keep_archive_cache = true;
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_download_release_assets()
+            .once()
+            .returning(|_| Ok(proton_assets("6.20-GE-1", "6.20-GE-1")));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let mut fs_mng = MockFilesystemManager::new();
+        let mut path_cfg = MockPathConfiguration::new();
+        let cache_dir = setup_cached_add(&tmp_dir, &mut fs_mng, &mut path_cfg);
+
+        let version = Version::new(Tag::from("6.20-GE-1"), TagKind::Proton);
+        let assets = proton_assets("6.20-GE-1", "6.20-GE-2");
+        ArchiveCache::new(cache_dir, u64::MAX)
+            .put(&version, &assets.compressed_archive, assets.checksum.as_ref().unwrap())
+            .unwrap();
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(
+            0,
+            "Cached archive for 6.20-GE-1 (Proton) does not match its checksum: Downloading it again",
+        );
+        stdout.assert_line(1, "Performing checksum comparison: Checksums match");
+        stdout.assert_line(2, "Successfully added version");
+
+        tmp_dir.close().unwrap();
+    }
+
+    fn setup_verify_only_add(tmp_dir: &TempDir) -> (MockFilesystemManager, MockPathConfiguration) {
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().never();
+        fs_mng.expect_setup_version().never();
+
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        (fs_mng, path_cfg)
+    }
+
+    #[test]
+    fn add_with_verify_only_should_compare_checksums_without_installing() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, true, true);
+        args.verify_only = true;
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_download_release_assets()
+            .once()
+            .returning(|_| Ok(proton_assets("6.20-GE-1", "6.20-GE-1")));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let (fs_mng, path_cfg) = setup_verify_only_add(&tmp_dir);
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(
+            0,
+            "Checksums match: Proton-6.20-GE-1.tar.gz matches Proton-6.20-GE-1.sha512sum",
+        );
+        stdout.assert_line(1, "Version 6.20-GE-1 (Proton) was verified but not installed");
+        let managed_versions = ManagedVersions::from_file(&tmp_dir.join("ge_man/managed_versions.json")).unwrap();
+        assert_eq!(managed_versions.versions(), vec![proton_6_20_1()]);
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_with_verify_only_and_mismatching_checksum_should_fail() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.verify_only = true;
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_download_release_assets()
+            .once()
+            .returning(|_| Ok(proton_assets("6.20-GE-1", "6.20-GE-2")));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let (fs_mng, path_cfg) = setup_verify_only_add(&tmp_dir);
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Checksum comparison failed: Checksum of Proton-6.20-GE-1.tar.gz does not match Proton-6.20-GE-1.sha512sum"
+        );
+        stdout.assert_empty();
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_with_keep_archive_should_save_archive_in_given_directory() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        let tmp_dir = TempDir::new().unwrap();
+        args.keep_archive = true;
+        args.keep_archive_dir = Some(tmp_dir.join("archives"));
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_download_release_assets()
+            .once()
+            .returning(|_| Ok(proton_assets("6.20-GE-1", "6.20-GE-1")));
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        let archive_path = tmp_dir.join("archives/Proton-6.20-GE-1.tar.gz");
+        stdout.assert_line(0, "Performing checksum comparison: Checksums match");
+        stdout.assert_line(1, &format!("Saved archive to {}", archive_path.display()));
+        stdout.assert_line(2, "Successfully added version");
+        assert_eq!(
+            fs::read(&archive_path).unwrap(),
+            fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap()
+        );
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_with_force_should_reinstall_managed_version() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.archive = Some(PathBuf::from("test_resources/assets/Proton-6.20-GE-1.tar.gz"));
+        args.force = true;
+
+        let version = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1");
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng.expect_setup_version().never();
+        let reinstalled = version.clone();
+        fs_mng
+            .expect_reinstall_version()
+            .with(eq(version.clone()), always())
+            .once()
+            .returning(move |_, _| Ok(reinstalled.clone()));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![version]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+        path_cfg
+            .expect_steam_config()
+            .once()
+            .returning(|_| PathBuf::from("test_resources/assets/config.vdf"));
+        path_cfg
+            .expect_lutris_wine_runner_config()
+            .once()
+            .returning(|_| PathBuf::from("test_resources/assets/wine.yml"));
+        path_cfg
+            .expect_bottles_runners_dir()
+            .once()
+            .returning(|_| PathBuf::from("invalid-path"));
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, "Skipping checksum comparison");
+        stdout.assert_line(1, "Successfully reinstalled version");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_with_force_should_not_reinstall_version_in_use() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.21-GE-2")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.force = true;
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_reinstall_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+        path_cfg
+            .expect_steam_config()
+            .once()
+            .returning(|_| PathBuf::from("test_resources/assets/config.vdf"));
+        path_cfg
+            .expect_lutris_wine_runner_config()
+            .once()
+            .returning(|_| PathBuf::from("test_resources/assets/wine.yml"));
+        path_cfg
+            .expect_bottles_runners_dir()
+            .once()
+            .returning(|_| PathBuf::from("invalid-path"));
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let err = writer.add(&mut stdout, args).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Version 6.21-GE-2 (Proton) is in use by Steam. Apply a different version before reinstalling it."
+        );
+        stdout.assert_empty();
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_with_force_should_not_reinstall_version_linked_into_bottles() {
+        let tag_arg = TagArg::new(Some(Tag::from("GE-Proton7-20")), TagKind::wine());
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.force = true;
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_reinstall_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new(
+                "GE-Proton7-20",
+                TagKind::wine(),
+                "lutris-GE-Proton7-20-x86_64",
+            )],
+        );
+        let bottles_runners = tmp_dir.join("bottles/runners");
+        fs::create_dir_all(&bottles_runners).unwrap();
+        std::os::unix::fs::symlink(
+            tmp_dir.join("lutris-GE-Proton7-20-x86_64"),
+            bottles_runners.join("lutris-GE-Proton7-20-x86_64"),
+        )
+        .unwrap();
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+        path_cfg
+            .expect_steam_config()
+            .once()
+            .returning(|_| PathBuf::from("test_resources/assets/config.vdf"));
+        path_cfg
+            .expect_lutris_wine_runner_config()
+            .once()
+            .returning(|_| PathBuf::from("test_resources/assets/wine.yml"));
+        path_cfg
+            .expect_bottles_runners_dir()
+            .once()
+            .returning(move |_| bottles_runners.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let err = writer.add(&mut stdout, args).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Version GE-Proton7-20 (Wine GE) is in use by Bottles. Apply a different version before reinstalling it."
+        );
+        stdout.assert_empty();
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test_case(
+        Some("test_resources/assets/Proton-6.20-GE-1.sha512sum"),
+        "Performing checksum comparison: Checksums match";
+        "With checksum file"
+    )]
+    #[test_case(None, "Skipping checksum comparison"; "Without checksum file")]
+    fn add_local_archive_should_not_download(checksum_file: Option<&str>, checksum_line: &str) {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.archive = Some(PathBuf::from("test_resources/assets/Proton-6.20-GE-1.tar.gz"));
+        args.checksum_file = checksum_file.map(PathBuf::from);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().never();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, checksum_line);
+        stdout.assert_line(1, "Successfully added version");
+
+        let managed_versions = ManagedVersions::from_file(&tmp_dir.join("ge_man/managed_versions.json")).unwrap();
+        assert_eq!(
+            managed_versions.versions()[0].checksum_skipped(),
+            checksum_file.is_none()
+        );
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test_case(false; "Warning")]
+    #[test_case(true; "Fatal")]
+    fn add_with_failing_post_add_hook(fatal: bool) {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.archive = Some(PathBuf::from("test_resources/assets/Proton-6.20-GE-1.tar.gz"));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let hook = tmp_dir.join("hook.sh");
+        fs::write(
+            &hook,
+            "#!/bin/sh
+exit 1
+",
+        )
+        .unwrap();
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")));
+        let version_dir = tmp_dir.join("Proton-6.20-GE-1");
+        fs_mng
+            .expect_version_path()
+            .once()
+            .returning(move |_| version_dir.clone());
+        fs_mng
+            .expect_version_size()
+            .times(if fatal { 0 } else { 1 })
+            .returning(|_| Ok(1024));
+
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let mut config = GeManConfig::default();
+        config.post_add_hook = Some(hook.clone());
+        config.post_add_hook_fatal = fatal;
+        let writer = TerminalWriter::with_config(&ge_downloader, &fs_mng, &path_cfg, config);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert_eq!(result.is_err(), fatal);
+
+        let hook_error = format!("Post add hook {} exited with exit status: 1", hook.display());
+        stdout.assert_line(1, "Successfully added version");
+        match &result {
+            Ok(_) => stdout.assert_line(2, &format!("Warning: {}", hook_error)),
+            Err(err) => assert_eq!(
+                format!("{:#}", err),
+                format!(
+                    "6.20-GE-1 (Proton) was added, but its post add hook failed: {}",
+                    hook_error
+                )
+            ),
+        }
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_local_archive_with_mismatching_checksum_file_should_fail() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.archive = Some(PathBuf::from("test_resources/assets/Proton-6.20-GE-1.tar.gz"));
+        args.checksum_file = Some(PathBuf::from("test_resources/assets/Proton-6.20-GE-2.sha512sum"));
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert!(result.is_err());
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_local_archive_without_tag_should_fail() {
+        let tag_arg = TagArg::new(None, TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.archive = Some(PathBuf::from("test_resources/assets/Proton-6.20-GE-1.tar.gz"));
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_fetch_release().never();
+        let fs_mng = MockFilesystemManager::new();
+        let path_cfg = MockPathConfiguration::new();
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "A tag must be provided when adding a local archive"
+        );
+    }
+
+    #[test]
+    fn add_without_checksum_should_warn_and_skip_comparison() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = AddArgs::new(tag_arg, false, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().once().returning(|_| {
+            let tar = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
+
+            Ok(DownloadedAssets {
+                tag: "6.20-GE-1".to_string(),
+                compressed_archive: DownloadedArchive {
+                    compressed_content: tar,
+                    file_name: "Proton-6.20-GE-1.tar.gz".to_string(),
+                },
+                checksum: None,
+            })
+        });
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(
+            0,
+            "Warning: No checksum is available for 6.20-GE-1 (Proton). Skipping checksum comparison",
+        );
+        stdout.assert_line(1, "Successfully added version");
+        stdout.assert_line(2, "Installed 6.20-GE-1 (Proton), 412 MiB, in 0s.");
+
+        let managed_versions = ManagedVersions::from_file(&tmp_dir.join("ge_man/managed_versions.json")).unwrap();
+        assert!(managed_versions.versions()[0].checksum_skipped());
+    }
+
+    #[test]
+    fn add_already_managed_version_with_skip_existing_expect_no_output() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.skip_existing = true;
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert!(result.is_ok());
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn add_specific_version_which_is_already_managed_again_expect_message_about_already_being_managed() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = AddArgs::new(tag_arg, false, false);
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert!(result.is_ok());
+        stdout.assert_line(0, "Version 6.20-GE-1 (Proton) is already managed");
+    }
+
+    #[test]
+    fn add_latest_version_in_offline_mode_should_fail_without_fetching_release() {
+        let args = AddArgs::new(TagArg::new(None, TagKind::Proton), false, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_fetch_release().never();
+        ge_downloader.expect_download_release_assets().never();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, Vec::new());
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_offline(true);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert_eq!(result.unwrap_err().to_string(), OFFLINE_ERROR);
+        stdout.assert_empty();
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_specific_version_in_offline_mode_should_fail_without_downloading() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = AddArgs::new(tag_arg, false, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().never();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, Vec::new());
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_offline(true);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert!(format!("{:#}", result.unwrap_err()).contains(OFFLINE_ERROR));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test_case(Some("6.19-GE-2") => vec![
+        String::from("Warning: 6.20-GE-1 is newer than the latest known release 6.19-GE-2, it may not exist."),
+    ]; "Newer than cached latest tag")]
+    #[test_case(Some("GE-Proton7-8") => Vec::<String>::new(); "Older than cached latest tag")]
+    #[test_case(None => vec![
+        String::from("Warning: 6.20-GE-1 is newer than the latest known release 6.19-GE-2, it may not exist."),
+    ]; "Newer than fetched latest tag")]
+    fn add_specific_version_should_warn_if_newer_than_latest_tag(cached_latest: Option<&str>) -> Vec<String> {
+        let args = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        if cached_latest.is_some() {
+            ge_downloader.expect_fetch_release().never();
+        } else {
+            expect_latest_release(&mut ge_downloader, "6.19-GE-2");
+        }
+        ge_downloader
+            .expect_download_release_assets()
+            .once()
+            .returning(|_| Err(GithubError::NoTags));
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+        if let Some(latest) = cached_latest {
+            cache.put(&TagKind::Proton, latest).unwrap();
+        }
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_latest_tag_cache(cache);
+
+        let mut stdout = Vec::new();
+        writer.add(&mut stdout, args).unwrap_err();
+
+        tmp_dir.close().unwrap();
+        String::from_utf8(stdout).unwrap().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn add_specific_version_should_ignore_failed_latest_tag_request() {
+        let args = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, _| tag.is_none())
+            .returning(|_, _| Err(GithubError::NoTags));
+        ge_downloader
+            .expect_download_release_assets()
+            .once()
+            .returning(|_| Err(GithubError::NoTags));
+        let fs_mng = MockFilesystemManager::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut config = GeManConfig::default();
+        config.retries = 3;
+        config.retry_delay_ms = 0;
+        let mut writer = TerminalWriter::with_config(&ge_downloader, &fs_mng, &path_cfg, config);
+        writer.set_latest_tag_cache(cache);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap_err();
+
+        stdout.assert_empty();
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_specific_version_without_latest_tag_cache_should_not_fetch_latest_tag() {
+        let args = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_fetch_release().never();
+        ge_downloader
+            .expect_download_release_assets()
+            .once()
+            .returning(|_| Err(GithubError::NoTags));
+        let fs_mng = MockFilesystemManager::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap_err();
+
+        stdout.assert_empty();
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_latest_version_with_cached_latest_tag_should_not_fetch_release() {
+        let args = AddArgs::new(TagArg::new(None, TagKind::Proton), false, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_fetch_release().never();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")]);
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+        cache.put(&TagKind::Proton, "6.20-GE-1").unwrap();
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_latest_tag_cache(cache);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert!(result.is_ok());
+        stdout.assert_line(0, "Version 6.20-GE-1 (Proton) is already managed");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn add_labeled_version_which_is_already_managed_again_expect_message_about_already_being_managed() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = AddArgs::new(tag_arg, false, false);
+        args.label = Some(String::from("patched"));
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        let mut labeled = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1_patched");
+        labeled.set_label(Some(String::from("patched")));
+        setup_managed_versions(&json_path, vec![proton_6_20_1(), labeled]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert!(result.is_ok());
+        stdout.assert_line(0, "Version 6.20-GE-1 [patched] (Proton) is already managed");
+    }
+
+    #[test]
+    fn add_latest_version_with_dry_run_should_only_print_the_plan() {
+        let mut args = AddArgs::new(TagArg::new(None, TagKind::Proton), false, false);
+        args.dry_run = true;
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .with(eq(None), eq(TagKind::Proton))
+            .once()
+            .returning(|_, _| Ok(GeRelease::new(String::from("6.21-GE-1"), Vec::new())));
+        ge_downloader.expect_download_release_assets().never();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+        path_cfg
+            .expect_steam_compatibility_tools_dir()
+            .once()
+            .returning(|_| PathBuf::from("/steam/compatibilitytools.d"));
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+        stdout.assert_line(0, "Dry run - nothing will be changed");
+        stdout.assert_line(1, "Version: 6.21-GE-1 (Proton)");
+        stdout.assert_line(2, "Already managed: no");
+        stdout.assert_line(3, "Source: No archive in release assets");
+        stdout.assert_line(4, "Install directory: /steam/compatibilitytools.d");
+    }
+
+    #[test]
+    fn add_local_archive_with_dry_run_should_not_fetch_release() {
+        let mut args = AddArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton), false, false);
+        args.archive = Some(PathBuf::from("/tmp/Proton-6.20-GE-1.tar.gz"));
+        args.dry_run = true;
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_fetch_release().never();
+        ge_downloader.expect_download_release_assets().never();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+        path_cfg
+            .expect_steam_compatibility_tools_dir()
+            .once()
+            .returning(|_| PathBuf::from("/steam/compatibilitytools.d"));
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+        stdout.assert_line(1, "Version: 6.20-GE-1 (Proton)");
+        stdout.assert_line(2, "Already managed: yes");
+        stdout.assert_line(3, "Source: /tmp/Proton-6.20-GE-1.tar.gz");
+    }
+
+    #[test]
+    fn add_latest_version_which_is_already_managed_again_expect_message_about_already_being_managed() {
+        let tag_arg = TagArg::new(None, TagKind::Proton);
+        let args = AddArgs::new(tag_arg, false, false);
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .returning(move |_, _| Ok(GeRelease::new(String::from("6.20-GE-1"), Vec::new())));
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert!(result.is_ok());
+        stdout.assert_line(0, "Version 6.20-GE-1 (Proton) is already managed");
+    }
+
+    #[test]
+    fn add_with_apply_should_modify_app_config() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = AddArgs::new(tag_arg, false, true);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().once().returning(|_| {
+            let tar = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
+            let checksum = fs::read_to_string("test_resources/assets/Proton-6.20-GE-1.sha512sum").unwrap();
+
+            Ok(DownloadedAssets {
+                tag: "6.20-GE-1".to_string(),
+                compressed_archive: DownloadedArchive {
+                    compressed_content: tar,
+                    file_name: "Proton-6.20-GE-1.tar.gz".to_string(),
+                },
+                checksum: Some(DownloadedChecksum {
+                    checksum,
+                    file_name: "Proton-6.20-GE-1.sha512sum".to_string(),
+                }),
+            })
+        });
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_ensure_free_space().once().returning(|_, _, _| Ok(()));
+        fs_mng
+            .expect_setup_version()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+        fs_mng.expect_version_size().once().returning(|_| Ok(412 * 1024 * 1024));
+        fs_mng
+            .expect_apply_to_app_config()
+            .once()
+            .returning(|_, _| Ok(AppConfigUpdate::Modified));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.add(&mut stdout, args).unwrap();
+
+        stdout.assert_line(0, "Performing checksum comparison: Checksums match");
+        stdout.assert_line(1, "Successfully added version");
+        stdout.assert_line(2, "Installed 6.20-GE-1 (Proton), 412 MiB, in 0s.");
+        stdout.assert_line(3, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
+        stdout.assert_line(4, PROTON_APPLY_HINT);
+    }
+
+    #[test]
+    fn remove_not_managed_version() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = RemoveArgs::new(tag_arg);
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_remove_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new("invalid-path", "invalid-path", "invalid-path");
+        let result = writer.remove(&mut stdout, &mut io::empty(), args, config_paths);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Given version is not managed");
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn remove_existing_version() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = RemoveArgs::new(tag_arg);
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_remove_version().once().returning(|_| Ok(()));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new(Tag::from("6.20-GE-1"), TagKind::Proton, "")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        let mut stdout = AssertLines::new();
+
+        let config_path = PathBuf::from("test_resources/assets/config.vdf");
+        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"), PathBuf::from("ignored"));
+        writer
+            .remove(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(0, "Successfully removed version 6.20-GE-1 (Proton).")
+    }
+
+    fn pinned_proton_6_20_1() -> ManagedVersion {
+        let mut version = ManagedVersion::new(Tag::from("6.20-GE-1"), TagKind::Proton, "");
+        version.set_pinned(true);
+        version
+    }
+
+    #[test]
+    fn remove_pinned_version_without_force_should_fail() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = RemoveArgs::new(tag_arg);
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_remove_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![pinned_proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        let mut stdout = AssertLines::new();
+
+        let config_path = PathBuf::from("test_resources/assets/config.vdf");
+        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"), PathBuf::from("ignored"));
+        let result = writer.remove(&mut stdout, &mut io::empty(), args, config_paths);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Version is pinned. Unpin it or use --force to remove it."
+        );
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn remove_pinned_version_with_force() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = RemoveArgs::new(tag_arg);
+        args.force = true;
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_remove_version().once().returning(|_| Ok(()));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![pinned_proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        let mut stdout = AssertLines::new();
+
+        let config_path = PathBuf::from("test_resources/assets/config.vdf");
+        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"), PathBuf::from("ignored"));
+        writer
+            .remove(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(0, "Successfully removed version 6.20-GE-1 (Proton).")
+    }
+
+    #[test]
+    fn remove_multiple_versions_continues_past_failures() {
+        let mut args = RemoveArgs::new(TagArg::new(Some(Tag::from("6.21-GE-2")), TagKind::Proton));
+        args.versions.push(Version::proton("6.20-GE-1"));
+        args.versions.push(Version::proton("6.19-GE-1"));
+        args.yes = true;
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_remove_version().once().returning(|_| Ok(()));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new(Tag::from("6.21-GE-2"), TagKind::Proton, "Proton-6.21-GE-2"),
+                ManagedVersion::new(Tag::from("6.20-GE-1"), TagKind::Proton, "Proton-6.20-GE-1"),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        let json_path_clone = json_path.clone();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path_clone.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        let mut stdout = AssertLines::new();
+
+        let config_path = PathBuf::from("test_resources/assets/config.vdf");
+        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"), PathBuf::from("ignored"));
+        let result = writer.remove(&mut stdout, &mut io::empty(), args, config_paths);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to remove 2 versions:\n\
+            6.21-GE-2 (Proton): Proton version is in use by Steam. Select a different version to make removal \
+            possible.\n\
+            6.19-GE-1 (Proton): Given version is not managed"
+        );
+        stdout.assert_line(0, "Successfully removed version 6.20-GE-1 (Proton).");
+
+        let managed_versions = ManagedVersions::from_file(&json_path).unwrap();
+        assert_eq!(
+            managed_versions.versions(),
+            vec![ManagedVersion::new(
+                Tag::from("6.21-GE-2"),
+                TagKind::Proton,
+                "Proton-6.21-GE-2"
+            )]
+        );
+    }
+
+    #[test_case("y\n", true; "Confirmed removal")]
+    #[test_case("\n", false; "Declined removal")]
+    fn remove_multiple_versions_should_ask_for_confirmation(answer: &str, confirmed: bool) {
+        let mut args = RemoveArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        args.versions.push(Version::proton("6.19-GE-1"));
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_remove_version()
+            .times(if confirmed { 2 } else { 0 })
+            .returning(|_| Ok(()));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new(Tag::from("6.20-GE-1"), TagKind::Proton, "Proton-6.20-GE-1"),
+                ManagedVersion::new(Tag::from("6.19-GE-1"), TagKind::Proton, "Proton-6.19-GE-1"),
+            ],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(if confirmed { 2 } else { 1 })
+            .returning(move |_| json_path.clone());
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_interactive(true);
+        let mut stdout = AssertLines::new();
+
+        let config_path = PathBuf::from("test_resources/assets/config.vdf");
+        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"), PathBuf::from("ignored"));
+        writer
+            .remove(&mut stdout, &mut answer.as_bytes(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(0, "The following versions will be removed:");
+        stdout.assert_line(1, "* 6.20-GE-1 (Proton)");
+        stdout.assert_line(2, "* 6.19-GE-1 (Proton)");
+        if confirmed {
+            stdout.assert_line(
+                3,
+                "Remove 2 versions? [y/N] Successfully removed version 6.20-GE-1 (Proton).",
+            );
+            stdout.assert_line(4, "Successfully removed version 6.19-GE-1 (Proton).");
+        } else {
+            stdout.assert_line(3, "Remove 2 versions? [y/N] No versions were removed.");
+        }
+    }
+
+    #[test]
+    fn remove_multiple_versions_without_user_should_require_yes() {
+        let mut args = RemoveArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        args.versions.push(Version::proton("6.19-GE-1"));
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_remove_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        let mut stdout = AssertLines::new();
+
+        let config_path = PathBuf::from("test_resources/assets/config.vdf");
+        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"), PathBuf::from("ignored"));
+        let result = writer.remove(&mut stdout, &mut io::empty(), args, config_paths);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Refusing to remove 2 versions without confirmation. Use --yes to remove them."
+        );
+    }
+
+    #[test]
+    fn remove_version_used_by_app_config() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.21-GE-2")), TagKind::Proton);
+        let args = RemoveArgs::new(tag_arg);
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_remove_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new(
+                Tag::from("6.21-GE-2"),
+                TagKind::Proton,
+                "Proton-6.21-GE-2",
+            )],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        let mut stdout = AssertLines::new();
+
+        let config_path = PathBuf::from("test_resources/assets/config.vdf");
+        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"), PathBuf::from("ignored"));
+        let result = writer.remove(&mut stdout, &mut io::empty(), args, config_paths);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Proton version is in use by Steam. Select a different version to make removal possible."
+        );
+        assert_eq!(ErrorCategory::of(&err), ErrorCategory::InUse);
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn check_with_successful_requests() {
+        let args = CheckArgs::new(None);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::Proton))
+            .returning(|_, _| Ok(GeRelease::new(String::from("6.20-GE-1"), vec![])));
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::wine()))
+            .returning(|_, _| Ok(GeRelease::new(String::from("6.20-GE-1"), vec![])));
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::lol()))
+            .returning(|_, _| Ok(GeRelease::new(String::from("6.16-GE-3-LoL"), vec![])));
+
+        let path_cfg = MockPathConfiguration::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let mut stderr = AssertLines::new();
+        writer.check(&mut stdout, &mut stderr, args).unwrap();
+
+        stdout.assert_line(0, "These are the latest releases.");
+        stdout.assert_line(1, "");
+        stdout.assert_line(2, "Proton GE: 6.20-GE-1");
+        stdout.assert_line(3, "Wine GE: 6.20-GE-1");
+        stdout.assert_line(4, "Wine GE - LoL: 6.16-GE-3-LoL");
+    }
+
+    #[test]
+    fn check_should_only_fetch_kinds_without_cached_latest_tag() {
+        let args = CheckArgs::new(None);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::wine()))
+            .returning(|_, _| Ok(GeRelease::new(String::from("6.20-GE-1"), vec![])));
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::lol()))
+            .returning(|_, _| Ok(GeRelease::new(String::from("6.16-GE-3-LoL"), vec![])));
+
+        let path_cfg = MockPathConfiguration::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+        cache.put(&TagKind::Proton, "6.21-GE-2").unwrap();
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_latest_tag_cache(cache);
+
+        let mut stdout = AssertLines::new();
+        let mut stderr = AssertLines::new();
+        writer.check(&mut stdout, &mut stderr, args).unwrap();
+
+        stdout.assert_line(2, "Proton GE: 6.21-GE-2");
+        stdout.assert_line(3, "Wine GE: 6.20-GE-1");
+        stdout.assert_line(4, "Wine GE - LoL: 6.16-GE-3-LoL");
+
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+        assert_eq!(cache.get(&TagKind::wine()), Some(String::from("6.20-GE-1")));
+        assert_eq!(cache.get(&TagKind::lol()), Some(String::from("6.16-GE-3-LoL")));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn check_with_refresh_should_ignore_and_update_cached_latest_tag() {
+        let mut args = CheckArgs::new(Some(TagKind::Proton));
+        args.refresh = true;
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::Proton))
+            .returning(|_, _| Ok(GeRelease::new(String::from("6.21-GE-2"), vec![])));
+
+        let path_cfg = MockPathConfiguration::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+        cache.put(&TagKind::Proton, "6.20-GE-1").unwrap();
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_latest_tag_cache(cache);
+
+        let mut stdout = AssertLines::new();
+        let mut stderr = AssertLines::new();
+        writer.check(&mut stdout, &mut stderr, args).unwrap();
+
+        stdout.assert_line(0, r#"The latest version of Proton GE is "6.21-GE-2""#);
+        let cache = LatestTagCache::new(tmp_dir.join("cache"), Duration::from_secs(3600));
+        assert_eq!(cache.get(&TagKind::Proton), Some(String::from("6.21-GE-2")));
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn check_should_not_retry_permanent_errors() {
+        let args = CheckArgs::new(Some(TagKind::Proton));
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .returning(|_, _| Err(GithubError::NoTags));
+
+        let path_cfg = MockPathConfiguration::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let mut config = GeManConfig::default();
+        config.retries = 3;
+        config.retry_delay_ms = 0;
+        let writer = TerminalWriter::with_config(&ge_downloader, &fs_mng, &path_cfg, config);
+
+        let mut stdout = AssertLines::new();
+        let mut stderr = AssertLines::new();
+        let err = writer.check(&mut stdout, &mut stderr, args).unwrap_err();
+
+        assert_eq!(
+            format!("{:#}", err),
+            "Could not fetch latest release from Github: No tags could be found"
+        );
+        assert_eq!(ErrorCategory::of(&err), ErrorCategory::Network);
+        stdout.assert_empty();
+        stderr.assert_empty();
+    }
+
+    #[test]
+    fn add_should_not_retry_release_without_assets() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = AddArgs::new(tag_arg, true, false);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_download_release_assets().once().returning(|_| {
+            Err(GithubError::ReleaseHasNoAssets {
+                tag: String::from("6.20-GE-1"),
+                kind: TagKind::Proton,
+            })
+        });
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_setup_version().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut config = GeManConfig::default();
+        config.retries = 3;
+        let writer = TerminalWriter::with_config(&ge_downloader, &fs_mng, &path_cfg, config);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.add(&mut stdout, args);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn find_updates_should_only_return_versions_that_are_not_managed() {
+        let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let updates = writer
+            .find_updates(vec![Version::proton("6.20-GE-1"), Version::wine("6.21-GE-1")])
+            .unwrap();
+        assert_eq!(updates, vec![Version::wine("6.21-GE-1")]);
+    }
+
+    #[test]
+    fn check_in_offline_mode_should_not_fetch_releases() {
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader.expect_fetch_release().never();
+        let path_cfg = MockPathConfiguration::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_offline(true);
+
+        let mut stdout = AssertLines::new();
+        let mut stderr = AssertLines::new();
+        let err = writer
+            .check(&mut stdout, &mut stderr, CheckArgs::new(None))
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), OFFLINE_ERROR);
+        assert_eq!(ErrorCategory::of(&err), ErrorCategory::Network);
+        stdout.assert_empty();
+        stderr.assert_empty();
+    }
+
+    #[test]
+    fn check_with_only_errors() {
+        let args = CheckArgs::new(None);
+
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::Proton))
+            .returning(|_, _| Err(GithubError::NoTags));
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::wine()))
+            .returning(|_, _| Err(GithubError::NoTags));
+        ge_downloader
+            .expect_fetch_release()
+            .once()
+            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::lol()))
+            .returning(|_, _| Err(GithubError::NoTags));
+
+        let path_cfg = MockPathConfiguration::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let mut stderr = AssertLines::new();
+        let err = writer.check(&mut stdout, &mut stderr, args).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Could not fetch the latest release of Proton GE, Wine GE, Wine GE (LoL)"
+        );
+        assert_eq!(ErrorCategory::of(&err), ErrorCategory::Network);
+        stdout.assert_line(0, "These are the latest releases.");
+        stderr.assert_line(
+            0,
+            "Proton GE: Could not fetch release information from GitHub: No tags could be found",
+        );
+        stderr.assert_line(
+            1,
+            "Wine GE: Could not fetch release information from GitHub: No tags could be found",
+        );
+        stderr.assert_line(
+            2,
+            "Wine GE - LoL: Could not fetch release information from GitHub: No tags could be found",
+        );
+    }
+
+    #[test]
+    fn migrate_already_managed_version() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = MigrationArgs::new(tag_arg, "invalid-path");
+
+        let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new(
+                Tag::from("6.20-GE-1"),
+                TagKind::Proton,
+                "Proton-6.20-GE-1",
+            )],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.migrate(&mut stdout, args);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Given version to migrate already exists as a managed version"
+        );
+        assert_eq!(ErrorCategory::of(&err), ErrorCategory::AlreadyExists);
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn migrate_not_present_version() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = MigrationArgs::new(tag_arg, "migration-source");
+
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_migrate_folder()
+            .once()
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.migrate(&mut stdout, args).unwrap();
+        stdout.assert_line(0, "Successfully migrated directory as 6.20-GE-1 (Proton)");
+    }
+
+    #[test]
+    fn migrate_with_dir_name_should_pass_it_to_the_migration() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = MigrationArgs::new(tag_arg, "migration-source");
+        args.dir_name = Some(String::from("shared-proton-ge"));
+
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_migrate_folder()
+            .once()
+            .withf(|_, _, dir_name| dir_name.eq(&Some("shared-proton-ge")))
+            .returning(|_, _, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "shared-proton-ge")));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        let read_path = json_path.clone();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| read_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.migrate(&mut stdout, args).unwrap();
+        stdout.assert_line(0, "Successfully migrated directory as 6.20-GE-1 (Proton)");
+
+        let managed_versions = ManagedVersions::from_file(&json_path).unwrap();
+        assert_eq!(
+            managed_versions.versions()[0].directory_name(),
+            &String::from("shared-proton-ge")
+        );
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn migrate_dry_run_should_only_print_the_plan() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let mut args = MigrationArgs::new(tag_arg, "/other/drive/Proton-6.20-GE-1");
+        args.dry_run = true;
+
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng.expect_plan_migration().once().returning(|_, _, _| {
+            Ok(MigrationPlan {
+                version: ManagedVersion::new("6.20-GE-1", TagKind::Proton, "GEH_PROTON_6.20-GE-1"),
+                destination: PathBuf::from("/steam/compatibilitytools.d/GEH_PROTON_6.20-GE-1"),
+                action: MigrationAction::Copy,
+            })
+        });
+        fs_mng.expect_migrate_folder().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.migrate(&mut stdout, args).unwrap();
+        stdout.assert_line(0, "Dry run - nothing will be changed");
+        stdout.assert_line(1, "Version: 6.20-GE-1 (Proton)");
+        stdout.assert_line(2, "Source: /other/drive/Proton-6.20-GE-1");
+        stdout.assert_line(3, "Destination: /steam/compatibilitytools.d/GEH_PROTON_6.20-GE-1");
+        stdout.assert_line(4, "Action: copy the directory to a different device");
+    }
+
+    #[test]
+    fn migrate_fails_due_to_filesystem_error() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = MigrationArgs::new(tag_arg, "migration-source");
+
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_migrate_folder()
+            .once()
+            .returning(|_, _, _| bail!("Mocked error"));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let result = writer.migrate(&mut stdout, args);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Could not migrate directory");
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn migrate_without_tag_should_fail() {
+        let args = MigrationArgs::new(TagArg::new(None, TagKind::Proton), "migration-source");
+
+        let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
+        let path_cfg = MockPathConfiguration::new();
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let err = writer.migrate(&mut stdout, args).unwrap_err();
+        assert_eq!(err.to_string(), "A tag is required to migrate a single directory");
+    }
+
+    #[test]
+    fn migrate_dir_should_skip_managed_and_unknown_directories() {
+        let tmp_dir = TempDir::new().unwrap();
+        let source_dir = tmp_dir.join("source");
+        for name in ["GE-Proton7-8", "Proton-6.20-GE-1", "custom-build", ".hidden"] {
+            fs::create_dir_all(source_dir.join(name)).unwrap();
+        }
+        fs::write(source_dir.join("notes.txt"), "").unwrap();
+        let args = MigrateDirArgs::new(None, &source_dir);
+
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_migrate_folder()
+            .once()
+            .withf(|version, _, _| version.eq(&Version::proton("GE-Proton7-8")))
+            .returning(|_, _, _| Ok(ManagedVersion::new("GE-Proton7-8", TagKind::Proton, "GE-Proton7-8")));
+
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        let path = json_path.clone();
+        path_cfg
+            .expect_managed_versions_config()
+            .times(2)
+            .returning(move |_| path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.migrate_dir(&mut stdout, args).unwrap();
+        stdout.assert_line(0, "Migrated GE-Proton7-8 as GE-Proton7-8 (Proton)");
+        stdout.assert_line(1, "Skipping Proton-6.20-GE-1: 6.20-GE-1 (Proton) is already managed");
+        stdout.assert_line(
+            2,
+            "Skipping custom-build: Could not infer the version from the directory name",
+        );
+        stdout.assert_line(3, "1 migrated, 2 skipped");
+
+        let managed_versions = ManagedVersions::from_file(&json_path).unwrap();
+        assert!(managed_versions
+            .find_version(&Version::proton("GE-Proton7-8"))
+            .is_some());
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn migrate_dir_with_kind_should_use_directory_name_as_tag_and_continue_after_errors() {
+        let tmp_dir = TempDir::new().unwrap();
+        let source_dir = tmp_dir.join("source");
+        fs::create_dir_all(source_dir.join("custom-build")).unwrap();
+        let args = MigrateDirArgs::new(Some(TagKind::wine()), &source_dir);
+
+        let ge_downloader = MockDownloader::new();
+
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_migrate_folder()
+            .once()
+            .withf(|version, _, _| version.eq(&Version::wine("custom-build")))
+            .returning(|_, _, _| bail!("Mocked error"));
+
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        writer.migrate_dir(&mut stdout, args).unwrap();
+        stdout.assert_line(0, "Skipping custom-build: Mocked error");
+        stdout.assert_line(1, "0 migrated, 1 skipped");
+
+        tmp_dir.close().unwrap();
+    }
+
+    #[test_case(AppConfigRestore::Restored, "Restored the previous Lutris configuration"; "restored")]
+    #[test_case(AppConfigRestore::Removed, "Removed the Lutris configuration created by GE-Man"; "removed")]
+    fn apply_reset_should_restore_app_config(restore: AppConfigRestore, expected: &str) {
+        let mut args = ApplyArgs::new(TagArg::new(None, TagKind::wine()));
+        args.reset = true;
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_restore_app_config()
+            .with(eq(TagKind::wine()), eq(0))
+            .once()
+            .returning(move |_, _| Ok(restore));
+        fs_mng.expect_apply_to_app_config().never();
+        let path_cfg = MockPathConfiguration::new();
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+        stdout.assert_line(0, expected);
+    }
+
+    #[test]
+    fn apply_reset_without_backup_should_fail() {
+        let mut args = ApplyArgs::new(TagArg::new(None, TagKind::Proton));
+        args.reset = true;
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_restore_app_config()
+            .once()
+            .returning(|_, _| bail!("No backup exists"));
+        let path_cfg = MockPathConfiguration::new();
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let err = writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "Could not restore Steam configuration: No backup exists"
+        );
+        stdout.assert_empty();
+    }
+
+    #[test]
+    fn apply_with_symlink_should_not_modify_app_config() {
+        let mut args = ApplyArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+        args.symlink = Some(String::from("GE-Proton-latest"));
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_symlink()
+            .with(eq(proton_6_20_1()), eq("GE-Proton-latest"))
+            .once()
+            .returning(|_, _| Ok(PathBuf::from("/steam/compatibilitytools.d/GE-Proton-latest")));
+        fs_mng.expect_apply_to_app_config().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(
+            0,
+            "/steam/compatibilitytools.d/GE-Proton-latest now points to 6.20-GE-1 (Proton)",
+        );
+        stdout.assert_line(
+            1,
+            "Select GE-Proton-latest in Steam to use it. The Steam config was not modified.",
+        );
+    }
+
+    #[test]
+    fn apply_with_bottles_should_not_modify_app_config() {
+        let version = ManagedVersion::new("GE-Proton7-20", TagKind::wine(), "lutris-GE-Proton7-20-x86_64");
+        let mut args = ApplyArgs::new(TagArg::new(Some(Tag::from("GE-Proton7-20")), TagKind::wine()));
+        args.bottles = true;
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_link_to_bottles()
+            .with(eq(version.clone()))
+            .once()
+            .returning(|_| Ok(PathBuf::from("/bottles/runners/lutris-GE-Proton7-20-x86_64")));
+        fs_mng.expect_apply_to_app_config().never();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![version.clone()]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(
+            0,
+            &format!("/bottles/runners/lutris-GE-Proton7-20-x86_64 now points to {}", version),
+        );
+        stdout.assert_line(
+            1,
+            "Select lutris-GE-Proton7-20-x86_64 as runner of a bottle in Bottles. The Lutris config was not modified.",
+        );
+    }
+
+    #[test]
+    fn apply_to_app_config_for_non_existent_version() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = ApplyArgs::new(tag_arg);
+
+        let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(&json_path, vec![]);
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let result = writer.apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Given version is not managed");
+        stdout.assert_empty();
+    }
+
+    #[test_case("y\n", true; "confirmed")]
+    #[test_case("n\n", false; "declined")]
+    fn apply_to_app_config_should_ask_before_replacing_applied_version(answer: &str, confirmed: bool) {
+        let args = ApplyArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_to_app_config()
+            .times(if confirmed { 1 } else { 0 })
+            .returning(|_, _| Ok(AppConfigUpdate::Modified));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_interactive(true);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut answer.as_bytes(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(0, "Steam currently uses Proton-6.21-GE-2");
+        if confirmed {
+            stdout.assert_line(
+                1,
+                "Replace it with Proton-6.20-GE-1? [y/N] Modifying Steam configuration to use 6.20-GE-1 (Proton)",
+            );
+        } else {
+            stdout.assert_line(
+                1,
+                "Replace it with Proton-6.20-GE-1? [y/N] The Steam config was not modified.",
+            );
+        }
+    }
+
+    #[test]
+    fn apply_to_app_config_with_assume_yes_should_not_ask() {
+        let args = ApplyArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_to_app_config()
+            .once()
+            .returning(|_, _| Ok(AppConfigUpdate::Modified));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+        writer.set_interactive(true);
+        writer.set_assume_yes(true);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(0, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
+    }
+
+    #[test]
+    fn apply_to_app_config_for_latest_version() {
+        let tag_arg = TagArg::new(None, TagKind::Proton);
+        let args = ApplyArgs::new(tag_arg);
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_to_app_config()
+            .once()
+            .returning(|_, _| Ok(AppConfigUpdate::Modified));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(0, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
+        stdout.assert_line(1, PROTON_APPLY_HINT);
+    }
+
+    #[test_case(false, 5; "with the configured backups")]
+    #[test_case(true, 0; "without backup")]
+    fn apply_to_app_config_should_pass_backups(no_backup: bool, expected_backups: usize) {
+        let mut args = ApplyArgs::new(TagArg::new(None, TagKind::Proton));
+        args.no_backup = no_backup;
+
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_to_app_config()
+            .withf(move |_, backups| *backups == expected_backups)
+            .once()
+            .returning(|_, _| Ok(AppConfigUpdate::Modified));
+
+        let tmp_dir = TempDir::new().unwrap();
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+        );
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        let mut config = GeManConfig::default();
+        config.apply_backups = 5;
+        let writer = TerminalWriter::with_config(&ge_downloader, &fs_mng, &path_cfg, config);
+
+        let mut stdout = AssertLines::new();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+
+        stdout.assert_line(0, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
+        tmp_dir.close().unwrap();
+    }
 
-        let tmp_dir = TempDir::new().unwrap();
-        let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+    #[test]
+    fn apply_reset_should_restore_the_given_backup() {
+        let mut args = ApplyArgs::new(TagArg::new(None, TagKind::Proton));
+        args.reset = true;
+        args.backup = 2;
 
-        let mut path_cfg = MockPathConfiguration::new();
-        path_cfg
-            .expect_managed_versions_config()
-            .times(2)
-            .returning(move |_| json_path.clone());
+        let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_restore_app_config()
+            .with(eq(TagKind::Proton), eq(1))
+            .once()
+            .returning(|_, _| Ok(AppConfigRestore::Restored));
+        let path_cfg = MockPathConfiguration::new();
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        writer.forget(&mut stdout, args).unwrap();
-
-        stdout.assert_line(0, "6.20-GE-1 (Proton) is now not managed by GE Helper");
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+        stdout.assert_line(0, "Restored the previous Steam configuration");
     }
 
     #[test]
-    fn forget_should_print_error_message() {
-        let args = ForgetArgs::new(TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton));
-        let fs_mng = MockFilesystemManager::new();
+    fn apply_to_app_config_for_existent_version() {
+        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        let args = ApplyArgs::new(tag_arg);
+
         let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_to_app_config()
+            .once()
+            .returning(|_, _| Ok(AppConfigUpdate::Modified));
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![]);
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+        );
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
@@ -599,28 +6430,44 @@ mod tests {
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let result = writer.forget(&mut stdout, args);
-        assert!(result.is_err());
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
 
-        let err = result.unwrap_err();
-        assert_eq!(err.to_string(), "Failed to forget version: Version is not managed");
-        stdout.assert_empty();
+        stdout.assert_line(0, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
+        stdout.assert_line(1, PROTON_APPLY_HINT);
     }
 
-    #[test]
-    fn list_newest_output() {
-        let args = ListArgs::new(None, true);
-        let fs_mng = MockFilesystemManager::new();
+    #[test_case(true => true; "default kind")]
+    #[test_case(false => false; "kind flag")]
+    fn apply_to_app_config_should_warn_about_ambiguous_tag(kind_from_default: bool) -> bool {
+        let mut tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
+        tag_arg.kind_from_default = kind_from_default;
+        let args = ApplyArgs::new(tag_arg);
+
         let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_to_app_config()
+            .with(
+                eq(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")),
+                always(),
+            )
+            .once()
+            .returning(|_, _| Ok(AppConfigUpdate::Modified));
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
         setup_managed_versions(
             &json_path,
             vec![
-                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
-                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
-                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1"),
+                ManagedVersion::new("6.20-GE-1", TagKind::wine(), "Wine-6.20-GE-1"),
             ],
         );
 
@@ -632,36 +6479,51 @@ mod tests {
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
-        let config_paths = AppConfigPaths::new("test_resources/assets/config.vdf", "test_resources/assets/wine.yml");
         let mut stdout = AssertLines::new();
-        writer.list(&mut stdout, args, config_paths).unwrap();
-
-        stdout.assert_line(0, "Proton GE:");
-        stdout.assert_line(1, "* 6.20-GE-1");
-        stdout.assert_line(2, "");
-        stdout.assert_line(3, "Wine GE:");
-        stdout.assert_line(4, "* 6.20-GE-1");
-        stdout.assert_line(5, "");
-        stdout.assert_line(6, "Wine GE (LoL):");
-        stdout.assert_line(7, "* 6.16-GE-3-LoL");
-        stdout.assert_line(8, "");
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
+
+        let warned = stdout.lines[0].starts_with("Warning:");
+        if warned {
+            stdout.assert_line(
+                0,
+                "Warning: 6.20-GE-1 is managed as Proton GE, Wine GE. Using the default kind Proton GE, pass a kind \
+                 flag to choose another one.",
+            );
+            stdout.assert_line(1, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
+        }
+        tmp_dir.close().unwrap();
+        warned
     }
 
-    #[test]
-    fn list_newest_output_with_in_use_version() {
-        let args = ListArgs::new(None, true);
-        let fs_mng = MockFilesystemManager::new();
+    #[test_case(AppConfigUpdate::Modified, LUTRIS_MODIFIED_CONFIG_HINT; "Modified existing Lutris config")]
+    #[test_case(AppConfigUpdate::Created, LUTRIS_CREATED_CONFIG_HINT; "Created initial Lutris config")]
+    fn apply_to_app_config_for_wine_version(update: AppConfigUpdate, expected_hint: &str) {
+        let tag_arg = TagArg::new(Some(Tag::from("6.21-GE-1")), TagKind::wine());
+        let args = ApplyArgs::new(tag_arg);
+
         let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_to_app_config()
+            .once()
+            .returning(move |_, _| Ok(update));
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
         setup_managed_versions(
             &json_path,
-            vec![
-                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
-                ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64"),
-                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
-            ],
+            vec![ManagedVersion::new(
+                "6.21-GE-1",
+                TagKind::wine(),
+                "lutris-ge-6.21-1-x86_64",
+            )],
         );
 
         let mut path_cfg = MockPathConfiguration::new();
@@ -672,41 +6534,48 @@ mod tests {
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
-        let config_paths = AppConfigPaths::new("test_resources/assets/config.vdf", "test_resources/assets/wine.yml");
         let mut stdout = AssertLines::new();
-        writer.list(&mut stdout, args, config_paths).unwrap();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
 
-        stdout.assert_line(0, "Proton GE:");
-        stdout.assert_line(1, "* 6.21-GE-2 - In use by Steam");
-        stdout.assert_line(2, "");
-        stdout.assert_line(3, "Wine GE:");
-        stdout.assert_line(4, "* 6.21-GE-1 - In use by Lutris");
-        stdout.assert_line(5, "");
-        stdout.assert_line(6, "Wine GE (LoL):");
-        stdout.assert_line(7, "* 6.16-GE-3-LoL");
-        stdout.assert_line(8, "");
+        stdout.assert_line(
+            0,
+            &format!("Modifying Lutris configuration to use {}", Version::wine("6.21-GE-1")),
+        );
+        stdout.assert_line(1, expected_hint);
+        stdout.assert_line(
+            2,
+            "Lutris wine runner set to lutris-ge-6.21-1-x86_64 - you may need to select it per game in Lutris.",
+        );
     }
 
-    #[test]
-    fn list_all() {
-        let args = ListArgs::new(None, false);
-        let fs_mng = MockFilesystemManager::new();
+    #[test_case(ApplyStep::Next, "6.21-GE-2", "7.0-GE-1"; "Apply next version")]
+    #[test_case(ApplyStep::Prev, "6.21-GE-2", "6.20-GE-1"; "Apply previous version")]
+    fn apply_to_app_config_step(step: ApplyStep, current: &str, expected: &str) {
+        let mut args = ApplyArgs::new(TagArg::new(None, TagKind::Proton));
+        args.step = Some(step);
+
         let ge_downloader = MockDownloader::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_apply_to_app_config()
+            .once()
+            .returning(|_, _| Ok(AppConfigUpdate::Modified));
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
         setup_managed_versions(
             &json_path,
             vec![
-                ManagedVersion::new("6.20-GE-2", TagKind::Proton, ""),
-                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
-                ManagedVersion::new("6.19-GE-1", TagKind::Proton, ""),
-                ManagedVersion::new("6.20-GE-2", TagKind::wine(), ""),
-                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
-                ManagedVersion::new("6.19-GE-1", TagKind::wine(), ""),
-                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
-                ManagedVersion::new("6.16-2-GE-LoL", TagKind::lol(), ""),
-                ManagedVersion::new("6.16-1-GE-LoL", TagKind::lol(), ""),
+                ManagedVersion::new("7.0-GE-1", TagKind::Proton, "Proton-7.0-GE-1"),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1"),
+                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
             ],
         );
 
@@ -719,46 +6588,38 @@ mod tests {
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let config_paths = AppConfigPaths::new("test_resources/assets/config.vdf", "test_resources/assets/wine.yml");
-        writer.list(&mut stdout, args, config_paths).unwrap();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
 
-        stdout.assert_line(0, "Proton GE:");
-        stdout.assert_line(1, "* 6.20-GE-2");
-        stdout.assert_line(2, "* 6.20-GE-1");
-        stdout.assert_line(3, "* 6.19-GE-1");
-        stdout.assert_line(4, "");
-        stdout.assert_line(5, "Wine GE:");
-        stdout.assert_line(6, "* 6.20-GE-2");
-        stdout.assert_line(7, "* 6.20-GE-1");
-        stdout.assert_line(8, "* 6.19-GE-1");
-        stdout.assert_line(9, "");
-        stdout.assert_line(10, "Wine GE (LoL):");
-        stdout.assert_line(11, "* 6.16-GE-3-LoL");
-        stdout.assert_line(12, "* 6.16-2-GE-LoL");
-        stdout.assert_line(13, "* 6.16-1-GE-LoL");
-        stdout.assert_line(14, "");
+        stdout.assert_line(0, &format!("Switching from {} to {}", current, expected));
+        stdout.assert_line(
+            1,
+            &format!("Modifying Steam configuration to use {}", Version::proton(expected)),
+        );
+        stdout.assert_line(2, PROTON_APPLY_HINT);
     }
 
     #[test]
-    fn list_all_with_in_use_version() {
-        let args = ListArgs::new(None, false);
-        let fs_mng = MockFilesystemManager::new();
+    fn apply_to_app_config_next_at_the_end_of_the_range() {
+        let mut args = ApplyArgs::new(TagArg::new(None, TagKind::Proton));
+        args.step = Some(ApplyStep::Next);
+
         let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
         setup_managed_versions(
             &json_path,
             vec![
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1"),
                 ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
-                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
-                ManagedVersion::new("6.19-GE-1", TagKind::Proton, ""),
-                ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64"),
-                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
-                ManagedVersion::new("6.19-GE-1", TagKind::wine(), ""),
-                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
-                ManagedVersion::new("6.16-2-GE-LoL", TagKind::lol(), ""),
-                ManagedVersion::new("6.16-1-GE-LoL", TagKind::lol(), ""),
             ],
         );
 
@@ -771,124 +6632,92 @@ mod tests {
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let config_paths = AppConfigPaths::new("test_resources/assets/config.vdf", "test_resources/assets/wine.yml");
-        writer.list(&mut stdout, args, config_paths).unwrap();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer
+            .apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths)
+            .unwrap();
 
-        stdout.assert_line(0, "Proton GE:");
-        stdout.assert_line(1, "* 6.21-GE-2 - In use by Steam");
-        stdout.assert_line(2, "* 6.20-GE-1");
-        stdout.assert_line(3, "* 6.19-GE-1");
-        stdout.assert_line(4, "");
-        stdout.assert_line(5, "Wine GE:");
-        stdout.assert_line(6, "* 6.21-GE-1 - In use by Lutris");
-        stdout.assert_line(7, "* 6.20-GE-1");
-        stdout.assert_line(8, "* 6.19-GE-1");
-        stdout.assert_line(9, "");
-        stdout.assert_line(10, "Wine GE (LoL):");
-        stdout.assert_line(11, "* 6.16-GE-3-LoL");
-        stdout.assert_line(12, "* 6.16-2-GE-LoL");
-        stdout.assert_line(13, "* 6.16-1-GE-LoL");
-        stdout.assert_line(14, "");
+        stdout.assert_line(
+            0,
+            "The currently applied version is already the newest managed Proton GE version",
+        );
     }
 
     #[test]
-    fn add_successful_output() {
+    fn apply_to_app_config_fails_with_an_error() {
         let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = AddArgs::new(tag_arg, true, false);
-
-        let mut ge_downloader = MockDownloader::new();
-        ge_downloader.expect_download_release_assets().once().returning(|_| {
-            Ok(DownloadedAssets {
-                tag: "".to_string(),
-                compressed_archive: DownloadedArchive {
-                    compressed_content: vec![],
-                    file_name: "".to_string(),
-                },
-                checksum: None,
-            })
-        });
+        let args = ApplyArgs::new(tag_arg);
 
+        let ge_downloader = MockDownloader::new();
         let mut fs_mng = MockFilesystemManager::new();
         fs_mng
-            .expect_setup_version()
+            .expect_apply_to_app_config()
             .once()
-            .returning(|_, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+            .returning(|_, _| bail!("Mocked error"));
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![]);
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+        );
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
             .expect_managed_versions_config()
-            .times(2)
+            .once()
             .returning(move |_| json_path.clone());
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        writer.add(&mut stdout, args).unwrap();
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        let result = writer.apply_to_app_config(&mut stdout, &mut io::empty(), args, config_paths);
+        assert!(result.is_err());
 
-        stdout.assert_line(0, "Skipping checksum comparison");
-        stdout.assert_line(1, "Successfully added version");
+        stdout.assert_line(0, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
     }
 
     #[test]
-    fn add_with_checksum_comparison_successful_output() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = AddArgs::new(tag_arg, false, false);
-
-        let mut ge_downloader = MockDownloader::new();
-        ge_downloader.expect_download_release_assets().once().returning(|_| {
-            let tar = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
-            let checksum = fs::read_to_string("test_resources/assets/Proton-6.20-GE-1.sha512sum").unwrap();
-
-            Ok(DownloadedAssets {
-                tag: "6.20-GE-1".to_string(),
-                compressed_archive: DownloadedArchive {
-                    compressed_content: tar,
-                    file_name: "Proton-6.20-GE-1.tar.gz".to_string(),
-                },
-                checksum: Some(DownloadedChecksum {
-                    checksum,
-                    file_name: "Proton-6.20-GE-1.sha512sum".to_string(),
-                }),
-            })
-        });
-
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng
-            .expect_setup_version()
-            .once()
-            .returning(|_, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
+    fn copy_user_settings_where_source_tag_does_not_exist() {
+        let args = CopyUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+        let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![]);
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")]);
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
             .expect_managed_versions_config()
-            .times(2)
+            .once()
             .returning(move |_| json_path.clone());
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        writer.add(&mut stdout, args).unwrap();
+        let result = writer.copy_user_settings(&mut stdout, args);
+        assert!(result.is_err());
 
-        stdout.assert_line(0, "Performing checksum comparison: Checksums match");
-        stdout.assert_line(1, "Successfully added version");
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Given source Proton version does not exist");
+        stdout.assert_empty();
     }
 
     #[test]
-    fn add_specific_version_which_is_already_managed_again_expect_message_about_already_being_managed() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = AddArgs::new(tag_arg, false, false);
-
-        let ge_downloader = MockDownloader::new();
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng.expect_setup_version().never();
+    fn copy_user_settings_where_destination_tag_does_not_exist() {
+        let args = CopyUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+        let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
@@ -903,22 +6732,31 @@ mod tests {
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let result = writer.add(&mut stdout, args);
-        assert!(result.is_ok());
-        stdout.assert_line(0, "Version 6.20-GE-1 (Proton) is already managed");
+        let result = writer.copy_user_settings(&mut stdout, args);
+        assert!(result.is_err());
+
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Given destination Proton version does not exist");
+        stdout.assert_empty();
     }
 
     #[test]
-    fn add_latest_version_which_is_already_managed_again_expect_message_about_already_being_managed() {
-        let tag_arg = TagArg::new(None, TagKind::Proton);
-        let args = AddArgs::new(tag_arg, false, false);
+    fn copy_user_settings_for_present_versions() {
+        let args = CopyUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+        let ge_downloader = MockDownloader::new();
 
         let mut fs_mng = MockFilesystemManager::new();
-        fs_mng.expect_setup_version().never();
+        fs_mng.expect_copy_user_settings().once().returning(|_, _| Ok(()));
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")]);
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.21-GE-1", TagKind::Proton, ""),
+            ],
+        );
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
@@ -926,83 +6764,73 @@ mod tests {
             .once()
             .returning(move |_| json_path.clone());
 
-        let mut ge_downloader = MockDownloader::new();
-        ge_downloader
-            .expect_fetch_release()
-            .once()
-            .returning(move |_, _| Ok(GeRelease::new(String::from("6.20-GE-1"), Vec::new())));
-
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let result = writer.add(&mut stdout, args);
-        assert!(result.is_ok());
-        stdout.assert_line(0, "Version 6.20-GE-1 (Proton) is already managed");
+        writer.copy_user_settings(&mut stdout, args).unwrap();
+
+        stdout.assert_line(
+            0,
+            "Copied user_settings.py from 6.20-GE-1 (Proton) to 6.21-GE-1 (Proton)",
+        );
     }
 
     #[test]
-    fn add_with_apply_should_modify_app_config() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = AddArgs::new(tag_arg, false, true);
-
-        let mut ge_downloader = MockDownloader::new();
-        ge_downloader.expect_download_release_assets().once().returning(|_| {
-            let tar = fs::read("test_resources/assets/Proton-6.20-GE-1.tar.gz").unwrap();
-            let checksum = fs::read_to_string("test_resources/assets/Proton-6.20-GE-1.sha512sum").unwrap();
+    fn copy_user_settings_from_file_for_present_version() {
+        let tmp_dir = TempDir::new().unwrap();
+        let template = tmp_dir.join("user_settings.py");
+        fs::write(&template, "user_settings = {}\n").unwrap();
 
-            Ok(DownloadedAssets {
-                tag: "6.20-GE-1".to_string(),
-                compressed_archive: DownloadedArchive {
-                    compressed_content: tar,
-                    file_name: "Proton-6.20-GE-1.tar.gz".to_string(),
-                },
-                checksum: Some(DownloadedChecksum {
-                    checksum,
-                    file_name: "Proton-6.20-GE-1.sha512sum".to_string(),
-                }),
-            })
-        });
+        let args = CopyUserSettingsArgs::from_file(&template, "6.21-GE-1");
+        let ge_downloader = MockDownloader::new();
 
         let mut fs_mng = MockFilesystemManager::new();
+        let expected_template = template.clone();
+        let expected_version = ManagedVersion::new("6.21-GE-1", TagKind::Proton, "");
         fs_mng
-            .expect_setup_version()
+            .expect_copy_user_settings_from_file()
             .once()
-            .returning(|_, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")));
-        fs_mng.expect_apply_to_app_config().once().returning(|_| Ok(()));
+            .withf(move |src_path, version| {
+                src_path.to_path_buf() == expected_template && version.eq(&expected_version)
+            })
+            .returning(|_, _| Ok(()));
 
-        let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![]);
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")]);
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
             .expect_managed_versions_config()
-            .times(2)
+            .once()
             .returning(move |_| json_path.clone());
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        writer.add(&mut stdout, args).unwrap();
+        writer.copy_user_settings(&mut stdout, args).unwrap();
 
-        stdout.assert_line(0, "Performing checksum comparison: Checksums match");
-        stdout.assert_line(1, "Successfully added version");
-        stdout.assert_line(2, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
-        stdout.assert_line(3, PROTON_APPLY_HINT);
+        stdout.assert_line(
+            0,
+            &format!(
+                "Copied user_settings.py from {} to 6.21-GE-1 (Proton)",
+                template.display()
+            ),
+        );
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn remove_not_managed_version() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = RemoveArgs::new(tag_arg);
-        let ge_downloader = MockDownloader::new();
+    fn copy_user_settings_from_missing_file() {
+        let tmp_dir = TempDir::new().unwrap();
+        let template = tmp_dir.join("user_settings.py");
 
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng.expect_remove_version().never();
+        let args = CopyUserSettingsArgs::from_file(&template, "6.21-GE-1");
+        let ge_downloader = MockDownloader::new();
+        let fs_mng = MockFilesystemManager::new();
 
-        let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![]);
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")]);
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
@@ -1013,66 +6841,92 @@ mod tests {
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let config_paths = AppConfigPaths::new("invalid-path", "invalid-path");
-        let result = writer.remove(&mut stdout, args, config_paths);
-        assert!(result.is_err());
-
-        let err = result.unwrap_err();
-        assert_eq!(err.to_string(), "Given version is not managed");
+        let err = writer.copy_user_settings(&mut stdout, args).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Given source file {} does not exist or is not a file",
+                template.display()
+            )
+        );
         stdout.assert_empty();
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn remove_existing_version() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = RemoveArgs::new(tag_arg);
+    fn copy_user_settings_fails_on_filesystem_operation() {
+        let args = CopyUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
         let ge_downloader = MockDownloader::new();
 
         let mut fs_mng = MockFilesystemManager::new();
-        fs_mng.expect_remove_version().once().returning(|_| Ok(()));
+        fs_mng
+            .expect_copy_user_settings()
+            .once()
+            .returning(|_, _| bail!("Mocked error"));
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
         setup_managed_versions(
             &json_path,
-            vec![ManagedVersion::new(Tag::from("6.20-GE-1"), TagKind::Proton, "")],
+            vec![
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.21-GE-1", TagKind::Proton, ""),
+            ],
         );
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
             .expect_managed_versions_config()
-            .times(2)
+            .once()
             .returning(move |_| json_path.clone());
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
+
         let mut stdout = AssertLines::new();
+        let result = writer.copy_user_settings(&mut stdout, args);
+        assert!(result.is_err());
 
-        let config_path = PathBuf::from("test_resources/assets/config.vdf");
-        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"));
-        writer.remove(&mut stdout, args, config_paths).unwrap();
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Mocked error");
+        stdout.assert_empty();
+    }
 
-        stdout.assert_line(0, "Successfully removed version 6.20-GE-1 (Proton).")
+    fn setup_user_settings_diff(tmp_dir: &TempDir) -> (MockFilesystemManager, MockPathConfiguration) {
+        let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1"),
+                ManagedVersion::new("6.21-GE-1", TagKind::Proton, "Proton-6.21-GE-1"),
+            ],
+        );
+
+        let compat_dir = tmp_dir.join("compatibilitytools.d");
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_user_settings_path()
+            .times(2)
+            .returning(move |version| compat_dir.join(version.directory_name()).join("user_settings.py"));
+
+        let mut path_cfg = MockPathConfiguration::new();
+        path_cfg
+            .expect_managed_versions_config()
+            .once()
+            .returning(move |_| json_path.clone());
+
+        (fs_mng, path_cfg)
     }
 
     #[test]
-    fn remove_version_used_by_app_config() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.21-GE-2")), TagKind::Proton);
-        let args = RemoveArgs::new(tag_arg);
+    fn diff_user_settings_where_source_tag_does_not_exist() {
+        let args = DiffUserSettingsArgs::new("6.19-GE-1", "6.21-GE-1");
         let ge_downloader = MockDownloader::new();
-
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng.expect_remove_version().never();
+        let fs_mng = MockFilesystemManager::new();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(
-            &json_path,
-            vec![ManagedVersion::new(
-                Tag::from("6.21-GE-2"),
-                TagKind::Proton,
-                "Proton-6.21-GE-2",
-            )],
-        );
+        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")]);
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
@@ -1081,188 +6935,192 @@ mod tests {
             .returning(move |_| json_path.clone());
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
-        let mut stdout = AssertLines::new();
 
-        let config_path = PathBuf::from("test_resources/assets/config.vdf");
-        let config_paths = AppConfigPaths::new(config_path, PathBuf::from("ignored"));
-        let result = writer.remove(&mut stdout, args, config_paths);
-        assert!(result.is_err());
-
-        let err = result.unwrap_err();
-        assert_eq!(
-            err.to_string(),
-            "Proton version is in use by Steam. Select a different version to make removal possible."
-        );
+        let mut stdout = AssertLines::new();
+        let err = writer.diff_user_settings(&mut stdout, args).unwrap_err();
+        assert_eq!(err.to_string(), "Given source Proton version does not exist");
         stdout.assert_empty();
     }
 
     #[test]
-    fn check_with_successful_requests() {
-        let args = CheckArgs::new(None);
-
-        let mut ge_downloader = MockDownloader::new();
-        ge_downloader
-            .expect_fetch_release()
-            .once()
-            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::Proton))
-            .returning(|_, _| Ok(GeRelease::new(String::from("6.20-GE-1"), vec![])));
-        ge_downloader
-            .expect_fetch_release()
-            .once()
-            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::wine()))
-            .returning(|_, _| Ok(GeRelease::new(String::from("6.20-GE-1"), vec![])));
-        ge_downloader
-            .expect_fetch_release()
-            .once()
-            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::lol()))
-            .returning(|_, _| Ok(GeRelease::new(String::from("6.16-GE-3-LoL"), vec![])));
+    fn diff_user_settings_should_print_unified_diff() {
+        let args = DiffUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+        let ge_downloader = MockDownloader::new();
+        let tmp_dir = TempDir::new().unwrap();
+        let (fs_mng, path_cfg) = setup_user_settings_diff(&tmp_dir);
 
-        let path_cfg = MockPathConfiguration::new();
-        let fs_mng = MockFilesystemManager::new();
+        let src_path = tmp_dir.join("compatibilitytools.d/Proton-6.20-GE-1/user_settings.py");
+        let dst_path = tmp_dir.join("compatibilitytools.d/Proton-6.21-GE-1/user_settings.py");
+        fs::create_dir_all(src_path.parent().unwrap()).unwrap();
+        fs::create_dir_all(dst_path.parent().unwrap()).unwrap();
+        fs::write(&src_path, "user_settings = {\n    \"PROTON_LOG\": \"1\",\n}\n").unwrap();
+        fs::write(&dst_path, "user_settings = {\n    \"PROTON_LOG\": \"0\",\n}\n").unwrap();
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let mut stderr = AssertLines::new();
-        writer.check(&mut stdout, &mut stderr, args);
+        writer.diff_user_settings(&mut stdout, args).unwrap();
 
-        stdout.assert_line(0, "These are the latest releases.");
-        stdout.assert_line(1, "");
-        stdout.assert_line(2, "Proton GE: 6.20-GE-1");
-        stdout.assert_line(3, "Wine GE: 6.20-GE-1");
-        stdout.assert_line(4, "Wine GE - LoL: 6.16-GE-3-LoL");
+        stdout.assert_line(0, &format!("--- {}", src_path.display()));
+        stdout.assert_line(1, &format!("+++ {}", dst_path.display()));
+        stdout.assert_line(2, "@@ -1,3 +1,3 @@");
+        stdout.assert_line(3, "user_settings = {");
+        stdout.assert_line(4, "-    \"PROTON_LOG\": \"1\",");
+        stdout.assert_line(5, "+    \"PROTON_LOG\": \"0\",");
+        stdout.assert_line(6, "}");
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn check_with_only_errors() {
-        let args = CheckArgs::new(None);
-
-        let mut ge_downloader = MockDownloader::new();
-        ge_downloader
-            .expect_fetch_release()
-            .once()
-            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::Proton))
-            .returning(|_, _| Err(GithubError::NoTags));
-        ge_downloader
-            .expect_fetch_release()
-            .once()
-            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::wine()))
-            .returning(|_, _| Err(GithubError::NoTags));
-        ge_downloader
-            .expect_fetch_release()
-            .once()
-            .withf(|tag, kind| tag.is_none() && kind.eq(&TagKind::lol()))
-            .returning(|_, _| Err(GithubError::NoTags));
+    fn diff_user_settings_should_report_identical_files() {
+        let args = DiffUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+        let ge_downloader = MockDownloader::new();
+        let tmp_dir = TempDir::new().unwrap();
+        let (fs_mng, path_cfg) = setup_user_settings_diff(&tmp_dir);
 
-        let path_cfg = MockPathConfiguration::new();
-        let fs_mng = MockFilesystemManager::new();
+        for dir in ["Proton-6.20-GE-1", "Proton-6.21-GE-1"] {
+            let dir = tmp_dir.join("compatibilitytools.d").join(dir);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("user_settings.py"), "user_settings = {}\n").unwrap();
+        }
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let mut stderr = AssertLines::new();
-        writer.check(&mut stdout, &mut stderr, args);
+        writer.diff_user_settings(&mut stdout, args).unwrap();
 
-        stdout.assert_line(0, "These are the latest releases.");
-        stderr.assert_line(
+        stdout.assert_line(
             0,
-            "Proton GE: Could not fetch release information from GitHub: No tags could be found",
-        );
-        stderr.assert_line(
-            1,
-            "Wine GE: Could not fetch release information from GitHub: No tags could be found",
-        );
-        stderr.assert_line(
-            2,
-            "Wine GE - LoL: Could not fetch release information from GitHub: No tags could be found",
+            "user_settings.py of 6.20-GE-1 (Proton) and 6.21-GE-1 (Proton) are identical",
         );
-    }
-
-    #[test]
-    fn migrate_already_managed_version() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = MigrationArgs::new(tag_arg, "invalid-path");
-
-        let ge_downloader = MockDownloader::new();
-        let fs_mng = MockFilesystemManager::new();
 
-        let tmp_dir = TempDir::new().unwrap();
-        let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(
-            &json_path,
-            vec![ManagedVersion::new(
-                Tag::from("6.20-GE-1"),
-                TagKind::Proton,
-                "Proton-6.20-GE-1",
-            )],
-        );
+        tmp_dir.close().unwrap();
+    }
 
-        let mut path_cfg = MockPathConfiguration::new();
-        path_cfg
-            .expect_managed_versions_config()
-            .once()
-            .returning(move |_| json_path.clone());
+    #[test]
+    fn diff_user_settings_should_report_missing_files() {
+        let args = DiffUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+        let ge_downloader = MockDownloader::new();
+        let tmp_dir = TempDir::new().unwrap();
+        let (fs_mng, path_cfg) = setup_user_settings_diff(&tmp_dir);
+
+        let dst_dir = tmp_dir.join("compatibilitytools.d/Proton-6.21-GE-1");
+        fs::create_dir_all(&dst_dir).unwrap();
+        fs::write(dst_dir.join("user_settings.py"), "user_settings = {}\n").unwrap();
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let result = writer.migrate(&mut stdout, args);
-        assert!(result.is_err());
-
-        let err = result.unwrap_err();
+        let err = writer.diff_user_settings(&mut stdout, args).unwrap_err();
         assert_eq!(
             err.to_string(),
-            "Given version to migrate already exists as a managed version"
+            format!(
+                "6.20-GE-1 (Proton) has no user_settings.py at {}",
+                tmp_dir
+                    .join("compatibilitytools.d/Proton-6.20-GE-1/user_settings.py")
+                    .display()
+            )
         );
         stdout.assert_empty();
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn migrate_not_present_version() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = MigrationArgs::new(tag_arg, "migration-source");
-
+    fn rebuild_should_register_unmanaged_directories() {
         let ge_downloader = MockDownloader::new();
-
         let mut fs_mng = MockFilesystemManager::new();
         fs_mng
-            .expect_migrate_folder()
+            .expect_list_directory_names()
+            .with(eq(TagKind::Proton))
+            .once()
+            .returning(|_| {
+                Ok(vec![
+                    String::from("GE-Proton7-20"),
+                    String::from("Proton-6.20-GE-1"),
+                    String::from("Proton-6.21-GE-2_patched"),
+                    String::from("random-tool"),
+                ])
+            });
+        fs_mng
+            .expect_list_directory_names()
+            .with(eq(TagKind::wine()))
             .once()
-            .returning(|_, _| Ok(ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")));
+            .returning(|_| {
+                Ok(vec![
+                    String::from("lutris-GE-Proton7-20-x86_64"),
+                    String::from("wine-custom"),
+                ])
+            });
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![]);
+        setup_managed_versions(
+            &json_path,
+            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+        );
 
         let mut path_cfg = MockPathConfiguration::new();
+        let manifest_path = json_path.clone();
         path_cfg
             .expect_managed_versions_config()
             .times(2)
-            .returning(move |_| json_path.clone());
+            .returning(move |_| manifest_path.clone());
+        let steam_dir = tmp_dir.join("compatibilitytools.d");
+        path_cfg
+            .expect_steam_compatibility_tools_dir()
+            .once()
+            .returning(move |_| steam_dir.clone());
+        let lutris_dir = tmp_dir.join("runners/wine");
+        path_cfg
+            .expect_lutris_runners_dir()
+            .once()
+            .returning(move |_| lutris_dir.clone());
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        writer.migrate(&mut stdout, args).unwrap();
-        stdout.assert_line(0, "Successfully migrated directory as 6.20-GE-1 (Proton)");
+        writer.rebuild(&mut stdout).unwrap();
+
+        let mut labeled = ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2_patched");
+        labeled.set_label(Some(String::from("patched")));
+        let proton = ManagedVersion::new("GE-Proton7-20", TagKind::Proton, "GE-Proton7-20");
+        let wine = ManagedVersion::new("GE-Proton7-20", TagKind::wine(), "lutris-GE-Proton7-20-x86_64");
+        stdout.assert_line(0, &format!("Registered GE-Proton7-20 as {}", proton));
+        stdout.assert_line(1, &format!("Registered Proton-6.21-GE-2_patched as {}", labeled));
+        stdout.assert_line(
+            2,
+            "Skipping random-tool: Does not follow the naming scheme of Proton GE versions",
+        );
+        stdout.assert_line(3, &format!("Registered lutris-GE-Proton7-20-x86_64 as {}", wine));
+        stdout.assert_line(
+            4,
+            "Skipping wine-custom: Does not follow the naming scheme of Wine GE versions",
+        );
+        stdout.assert_line(5, "3 registered, 2 skipped");
+
+        let managed_versions = ManagedVersions::from_file(&json_path).unwrap().versions();
+        assert_eq!(managed_versions, vec![proton_6_20_1(), proton, labeled.clone(), wine]);
+        assert_eq!(managed_versions[2].directory_name(), labeled.directory_name());
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn migrate_fails_due_to_filesystem_error() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = MigrationArgs::new(tag_arg, "migration-source");
-
+    fn status_should_print_applied_managed_versions() {
         let ge_downloader = MockDownloader::new();
-
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng
-            .expect_migrate_folder()
-            .once()
-            .returning(|_, _| bail!("Mocked error"));
+        let fs_mng = MockFilesystemManager::new();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![]);
+        setup_managed_versions(
+            &json_path,
+            vec![
+                ManagedVersion::new("6.21-GE-2", TagKind::Proton, "Proton-6.21-GE-2"),
+                ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64"),
+            ],
+        );
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
@@ -1273,19 +7131,20 @@ mod tests {
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let result = writer.migrate(&mut stdout, args);
-        assert!(result.is_err());
+        let config_paths = AppConfigPaths::new(
+            "test_resources/assets/config.vdf",
+            "test_resources/assets/wine.yml",
+            "invalid-path",
+        );
+        writer.status(&mut stdout, config_paths).unwrap();
 
-        let err = result.unwrap_err();
-        assert_eq!(err.to_string(), "Could not migrate directory");
-        stdout.assert_empty();
+        let wine_version = ManagedVersion::new("6.21-GE-1", TagKind::wine(), "lutris-ge-6.21-1-x86_64");
+        stdout.assert_line(0, "Steam: 6.21-GE-2 (Proton)");
+        stdout.assert_line(1, &format!("Lutris: {}", wine_version));
     }
 
     #[test]
-    fn apply_to_app_config_for_non_existent_version() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = ApplyArgs::new(tag_arg);
-
+    fn status_should_print_unmanaged_directory_and_unreadable_config() {
         let ge_downloader = MockDownloader::new();
         let fs_mng = MockFilesystemManager::new();
 
@@ -1302,30 +7161,41 @@ mod tests {
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let result = writer.apply_to_app_config(&mut stdout, args);
-        assert!(result.is_err());
+        let config_paths = AppConfigPaths::new(
+            PathBuf::from("test_resources/assets/config.vdf"),
+            tmp_dir.join("missing.yml"),
+            tmp_dir.join("bottles"),
+        );
+        writer.status(&mut stdout, config_paths).unwrap();
 
-        let err = result.unwrap_err();
-        assert_eq!(err.to_string(), "Given version is not managed");
-        stdout.assert_empty();
+        stdout.assert_line(0, "Steam: Proton-6.21-GE-2 (not managed by GE-Man)");
+        stdout.assert_line(1, "Lutris: Could not read the configuration");
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn apply_to_app_config_for_latest_version() {
-        let tag_arg = TagArg::new(None, TagKind::Proton);
-        let args = ApplyArgs::new(tag_arg);
-
+    fn status_should_print_versions_linked_into_bottles() {
         let ge_downloader = MockDownloader::new();
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng.expect_apply_to_app_config().once().returning(|_| Ok(()));
+        let fs_mng = MockFilesystemManager::new();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
         setup_managed_versions(
             &json_path,
-            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+            vec![ManagedVersion::new(
+                "6.20-GE-1",
+                TagKind::wine(),
+                "lutris-ge-6.20-1-x86_64",
+            )],
         );
 
+        let bottles_runners = tmp_dir.join("bottles/runners");
+        fs::create_dir_all(&bottles_runners).unwrap();
+        for dir_name in ["lutris-ge-6.20-1-x86_64", "wine-custom"] {
+            std::os::unix::fs::symlink(tmp_dir.join(dir_name), bottles_runners.join(dir_name)).unwrap();
+        }
+
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
             .expect_managed_versions_config()
@@ -1335,60 +7205,114 @@ mod tests {
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        writer.apply_to_app_config(&mut stdout, args).unwrap();
+        let config_paths = AppConfigPaths::new(
+            PathBuf::from("test_resources/assets/config.vdf"),
+            PathBuf::from("test_resources/assets/wine.yml"),
+            bottles_runners,
+        );
+        writer.status(&mut stdout, config_paths).unwrap();
 
-        stdout.assert_line(0, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
-        stdout.assert_line(1, PROTON_APPLY_HINT);
+        let wine_version = ManagedVersion::new("6.20-GE-1", TagKind::wine(), "lutris-ge-6.20-1-x86_64");
+        stdout.assert_line(
+            2,
+            &format!("Bottles: {}, wine-custom (not managed by GE-Man)", wine_version),
+        );
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn apply_to_app_config_for_existent_version() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = ApplyArgs::new(tag_arg);
-
+    fn dirs_should_print_every_path() {
         let ge_downloader = MockDownloader::new();
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng.expect_apply_to_app_config().once().returning(|_| Ok(()));
-
-        let tmp_dir = TempDir::new().unwrap();
-        let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(
-            &json_path,
-            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
-        );
+        let fs_mng = MockFilesystemManager::new();
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
             .expect_managed_versions_config()
             .once()
-            .returning(move |_| json_path.clone());
+            .returning(|_| PathBuf::from("/data/ge_man/managed_versions.json"));
+        path_cfg
+            .expect_ge_man_config_dir()
+            .once()
+            .returning(|_| PathBuf::from("/config/ge_man"));
+        path_cfg
+            .expect_ge_man_config_file()
+            .once()
+            .returning(|_| PathBuf::from("/config/ge_man/config.json"));
+        path_cfg
+            .expect_ge_man_data_dir()
+            .once()
+            .returning(|_| PathBuf::from("/data/ge_man"));
+        path_cfg
+            .expect_ge_man_cache_dir()
+            .once()
+            .returning(|_| PathBuf::from("/cache/ge_man"));
+        path_cfg
+            .expect_app_config_backup_file()
+            .with(always(), eq(TagKind::Proton))
+            .once()
+            .returning(|_, _| PathBuf::from("/config/ge_man/steam-config-backup.vdf"));
+        path_cfg
+            .expect_app_config_backup_file()
+            .with(always(), eq(TagKind::wine()))
+            .once()
+            .returning(|_, _| PathBuf::from("/config/ge_man/lutris-wine-runner-config-backup.yml"));
+        path_cfg
+            .expect_steam_config()
+            .once()
+            .returning(|_| PathBuf::from("/steam/config/config.vdf"));
+        path_cfg
+            .expect_steam_compatibility_tools_dir()
+            .once()
+            .returning(|_| PathBuf::from("/steam/compatibilitytools.d"));
+        path_cfg
+            .expect_lutris_wine_runner_config()
+            .once()
+            .returning(|_| PathBuf::from("/config/lutris/runners/wine.yml"));
+        path_cfg
+            .expect_lutris_runners_dir()
+            .once()
+            .returning(|_| PathBuf::from("/data/lutris/runners/wine"));
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        writer.apply_to_app_config(&mut stdout, args).unwrap();
-
-        stdout.assert_line(0, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
-        stdout.assert_line(1, PROTON_APPLY_HINT);
+        writer.dirs(&mut stdout).unwrap();
+
+        stdout.assert_line(0, "GE-Man config directory: /config/ge_man");
+        stdout.assert_line(1, "GE-Man config file: /config/ge_man/config.json");
+        stdout.assert_line(2, "GE-Man data directory: /data/ge_man");
+        stdout.assert_line(3, "GE-Man cache directory: /cache/ge_man");
+        stdout.assert_line(4, "Managed versions file: /data/ge_man/managed_versions.json");
+        stdout.assert_line(5, "Steam config backup: /config/ge_man/steam-config-backup.vdf");
+        stdout.assert_line(
+            6,
+            "Lutris config backup: /config/ge_man/lutris-wine-runner-config-backup.yml",
+        );
+        stdout.assert_line(7, "Steam config: /steam/config/config.vdf");
+        stdout.assert_line(8, "Steam compatibility tools directory: /steam/compatibilitytools.d");
+        stdout.assert_line(9, "Lutris wine runner config: /config/lutris/runners/wine.yml");
+        stdout.assert_line(10, "Lutris wine runners directory: /data/lutris/runners/wine");
     }
 
     #[test]
-    fn apply_to_app_config_fails_with_an_error() {
-        let tag_arg = TagArg::new(Some(Tag::from("6.20-GE-1")), TagKind::Proton);
-        let args = ApplyArgs::new(tag_arg);
-
+    fn export_should_write_reinstall_script() {
         let ge_downloader = MockDownloader::new();
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng
-            .expect_apply_to_app_config()
-            .once()
-            .returning(|_| bail!("Mocked error"));
+        let fs_mng = MockFilesystemManager::new();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
+        let mut labeled = ManagedVersion::new("6.20-GE-1", TagKind::Proton, "");
+        labeled.set_label(Some(String::from("patched")));
         setup_managed_versions(
             &json_path,
-            vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "Proton-6.20-GE-1")],
+            vec![
+                ManagedVersion::new("6.16-GE-3-LoL", TagKind::lol(), ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
+                ManagedVersion::new("6.19-GE-2", TagKind::Proton, ""),
+                ManagedVersion::new("6.20-GE-1", TagKind::wine(), ""),
+                labeled,
+            ],
         );
 
         let mut path_cfg = MockPathConfiguration::new();
@@ -1399,22 +7323,39 @@ mod tests {
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
+        let script_path = tmp_dir.join("install.sh");
         let mut stdout = AssertLines::new();
-        let result = writer.apply_to_app_config(&mut stdout, args);
-        assert!(result.is_err());
+        writer
+            .export(&mut stdout, ExportArgs::new(Some(script_path.clone()), None))
+            .unwrap();
 
-        stdout.assert_line(0, "Modifying Steam configuration to use 6.20-GE-1 (Proton)");
+        stdout.assert_line(0, &format!("Exported 5 versions to {}", script_path.display()));
+        let script = fs::read_to_string(&script_path).unwrap();
+        assert_eq!(
+            script,
+            format!(
+                "{}geman add --skip-existing --proton '6.19-GE-2'\n\
+                 geman add --skip-existing --proton '6.20-GE-1'\n\
+                 geman add --skip-existing --proton '6.20-GE-1' --label 'patched'\n\
+                 geman add --skip-existing --wine '6.20-GE-1'\n\
+                 geman add --skip-existing --lol '6.16-GE-3-LoL'\n",
+                REINSTALL_SCRIPT_HEADER
+            )
+        );
+        assert_eq!(fs::metadata(&script_path).unwrap().permissions().mode() & 0o777, 0o755);
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn copy_user_settings_where_source_tag_does_not_exist() {
-        let args = CopyUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+    fn export_should_write_manifest() {
         let ge_downloader = MockDownloader::new();
         let fs_mng = MockFilesystemManager::new();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.21-GE-1", TagKind::Proton, "")]);
+        let versions = vec![proton_6_20_1(), ManagedVersion::new("6.20-GE-1", TagKind::wine(), "")];
+        setup_managed_versions(&json_path, versions.clone());
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
@@ -1424,112 +7365,160 @@ mod tests {
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
+        let manifest_path = tmp_dir.join("versions.json");
         let mut stdout = AssertLines::new();
-        let result = writer.copy_user_settings(&mut stdout, args);
-        assert!(result.is_err());
+        writer
+            .export(&mut stdout, ExportArgs::new(None, Some(manifest_path.clone())))
+            .unwrap();
 
-        let err = result.unwrap_err();
-        assert_eq!(err.to_string(), "Given source Proton version does not exist");
-        stdout.assert_empty();
+        stdout.assert_line(0, &format!("Exported 2 versions to {}", manifest_path.display()));
+        assert_eq!(ManagedVersions::from_file(&manifest_path).unwrap().versions(), versions);
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn copy_user_settings_where_destination_tag_does_not_exist() {
-        let args = CopyUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+    fn import_should_only_register_installed_versions() {
         let ge_downloader = MockDownloader::new();
-        let fs_mng = MockFilesystemManager::new();
+        let mut fs_mng = MockFilesystemManager::new();
+        fs_mng
+            .expect_version_exists()
+            .with(eq(ManagedVersion::new(
+                "6.21-GE-1",
+                TagKind::Proton,
+                "Proton-6.21-GE-1",
+            )))
+            .once()
+            .returning(|_| true);
+        fs_mng
+            .expect_version_exists()
+            .with(eq(ManagedVersion::new(
+                "6.19-GE-1",
+                TagKind::Proton,
+                "Proton-6.19-GE-1",
+            )))
+            .once()
+            .returning(|_| false);
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(&json_path, vec![ManagedVersion::new("6.20-GE-1", TagKind::Proton, "")]);
+        setup_managed_versions(&json_path, vec![proton_6_20_1()]);
+
+        let manifest_path = tmp_dir.join("versions.json");
+        let mut invalid_label = ManagedVersion::new("6.18-GE-1", TagKind::Proton, "");
+        invalid_label.set_label(Some(String::from("../x")));
+        ManagedVersions::new(vec![
+            proton_6_20_1(),
+            ManagedVersion::new("6.21-GE-1", TagKind::Proton, "Proton-6.21-GE-1"),
+            ManagedVersion::new("6.19-GE-1", TagKind::Proton, "Proton-6.19-GE-1"),
+            invalid_label,
+        ])
+        .write_to_file(&manifest_path)
+        .unwrap();
 
         let mut path_cfg = MockPathConfiguration::new();
+        let managed_versions_path = json_path.clone();
         path_cfg
             .expect_managed_versions_config()
-            .once()
-            .returning(move |_| json_path.clone());
+            .times(2)
+            .returning(move |_| managed_versions_path.clone());
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let result = writer.copy_user_settings(&mut stdout, args);
-        assert!(result.is_err());
+        writer
+            .import(&mut stdout, ImportArgs::new(&manifest_path, false))
+            .unwrap();
 
-        let err = result.unwrap_err();
-        assert_eq!(err.to_string(), "Given destination Proton version does not exist");
-        stdout.assert_empty();
+        stdout.assert_line(0, "Skipping 6.20-GE-1 (Proton): Already managed");
+        stdout.assert_line(1, "Imported 6.21-GE-1 (Proton)");
+        stdout.assert_line(
+            2,
+            "Skipping 6.19-GE-1 (Proton): Not installed. Use --download to download it.",
+        );
+        stdout.assert_line(3, "Skipping 6.18-GE-1: Invalid label '../x'");
+        let managed_versions = ManagedVersions::from_file(&json_path).unwrap();
+        assert!(managed_versions
+            .find_version(&Version::new("6.21-GE-1", TagKind::Proton))
+            .is_some());
+        assert!(managed_versions
+            .find_version(&Version::new("6.19-GE-1", TagKind::Proton))
+            .is_none());
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn copy_user_settings_for_present_versions() {
-        let args = CopyUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
-        let ge_downloader = MockDownloader::new();
-
+    fn import_with_download_should_try_every_missing_version() {
+        let mut ge_downloader = MockDownloader::new();
+        ge_downloader
+            .expect_fetch_release()
+            .times(2)
+            .withf(|tag, _| tag.is_none())
+            .returning(|_, _| Ok(GeRelease::new(String::from("6.21-GE-1"), Vec::new())));
+        ge_downloader
+            .expect_download_release_assets()
+            .times(2)
+            .returning(|_| Err(GithubError::NoTags));
         let mut fs_mng = MockFilesystemManager::new();
-        fs_mng.expect_copy_user_settings().once().returning(|_, _| Ok(()));
+        fs_mng.expect_version_exists().times(2).returning(|_| false);
+        fs_mng.expect_setup_version().never();
 
         let tmp_dir = TempDir::new().unwrap();
         let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(
-            &json_path,
-            vec![
-                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
-                ManagedVersion::new("6.21-GE-1", TagKind::Proton, ""),
-            ],
-        );
+        setup_managed_versions(&json_path, vec![]);
+
+        let manifest_path = tmp_dir.join("versions.json");
+        ManagedVersions::new(vec![
+            ManagedVersion::new("6.19-GE-1", TagKind::Proton, "Proton-6.19-GE-1"),
+            ManagedVersion::new("6.21-GE-1", TagKind::Proton, "Proton-6.21-GE-1"),
+        ])
+        .write_to_file(&manifest_path)
+        .unwrap();
 
         let mut path_cfg = MockPathConfiguration::new();
         path_cfg
             .expect_managed_versions_config()
-            .once()
+            .times(3)
             .returning(move |_| json_path.clone());
 
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        writer.copy_user_settings(&mut stdout, args).unwrap();
+        let err = writer
+            .import(&mut stdout, ImportArgs::new(&manifest_path, true))
+            .unwrap_err();
 
-        stdout.assert_line(
-            0,
-            "Copied user_settings.py from 6.20-GE-1 (Proton) to 6.21-GE-1 (Proton)",
+        stdout.assert_line(0, "Downloaded 0 of 2 missing versions");
+        assert_eq!(
+            err.to_string(),
+            "Failed to download 2 versions:\n\
+            6.19-GE-1 (Proton): Could not fetch release assets from Github: No tags could be found\n\
+            6.21-GE-1 (Proton): Could not fetch release assets from Github: No tags could be found"
         );
+
+        tmp_dir.close().unwrap();
     }
 
     #[test]
-    fn copy_user_settings_fails_on_filesystem_operation() {
-        let args = CopyUserSettingsArgs::new("6.20-GE-1", "6.21-GE-1");
+    fn import_without_manifest_should_fail() {
         let ge_downloader = MockDownloader::new();
-
-        let mut fs_mng = MockFilesystemManager::new();
-        fs_mng
-            .expect_copy_user_settings()
-            .once()
-            .returning(|_, _| bail!("Mocked error"));
-
-        let tmp_dir = TempDir::new().unwrap();
-        let json_path = tmp_dir.join("ge_man/managed_versions.json");
-        setup_managed_versions(
-            &json_path,
-            vec![
-                ManagedVersion::new("6.20-GE-1", TagKind::Proton, ""),
-                ManagedVersion::new("6.21-GE-1", TagKind::Proton, ""),
-            ],
-        );
-
-        let mut path_cfg = MockPathConfiguration::new();
-        path_cfg
-            .expect_managed_versions_config()
-            .once()
-            .returning(move |_| json_path.clone());
-
+        let fs_mng = MockFilesystemManager::new();
+        let path_cfg = MockPathConfiguration::new();
         let writer = TerminalWriter::new(&ge_downloader, &fs_mng, &path_cfg);
 
         let mut stdout = AssertLines::new();
-        let result = writer.copy_user_settings(&mut stdout, args);
-        assert!(result.is_err());
+        let result = writer.import(&mut stdout, ImportArgs::new("/tmp/does-not-exist.json", false));
 
-        let err = result.unwrap_err();
-        assert_eq!(err.to_string(), "Mocked error");
-        stdout.assert_empty();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Manifest /tmp/does-not-exist.json does not exist"
+        );
+    }
+
+    #[test]
+    fn shell_quote_should_escape_single_quotes() {
+        assert_eq!(shell_quote("GE-Proton7-8"), "'GE-Proton7-8'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
     }
 }
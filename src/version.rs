@@ -8,11 +8,13 @@ use crate::data::ManagedVersion;
 pub trait Versioned {
     fn tag(&self) -> &Tag;
     fn kind(&self) -> &TagKind;
+    /// Optional label which allows to manage the same tag more than once.
+    fn label(&self) -> Option<&String>;
 }
 
 impl<'a> PartialEq for dyn Versioned + 'a {
     fn eq(&self, other: &Self) -> bool {
-        self.tag().eq(other.tag()) && self.kind().eq(other.kind())
+        self.tag().eq(other.tag()) && self.kind().eq(other.kind()) && self.label().eq(&other.label())
     }
 }
 
@@ -24,16 +26,34 @@ impl<'a> PartialOrd for dyn Versioned + 'a {
 
 impl<'a> Ord for dyn Versioned + 'a {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.tag().cmp(other.tag()).then(self.kind().cmp(other.kind()))
+        cmp_tags(self.tag(), other.tag())
+            .then(self.kind().cmp(other.kind()))
+            .then(self.label().cmp(&other.label()))
     }
 }
 
+/// Order two tags by their semantic version, so `GE-Proton7-22` is newer than `GE-Proton7-8` and both are newer than
+/// the old `6.21-GE-2` scheme. Tags with the same version fall back to the tag name to stay consistent with equality.
+pub fn cmp_tags(tag: &Tag, other: &Tag) -> Ordering {
+    tag.cmp_semver(other).then_with(|| tag.cmp(other))
+}
+
 impl<'a> Eq for dyn Versioned + 'a {}
 
-#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+/// A label is appended to the directory name of a version, so it may only contain characters which are safe to use in a
+/// directory name.
+pub fn is_valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && label
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Version {
     tag: Tag,
     kind: TagKind,
+    label: Option<String>,
 }
 
 impl Version {
@@ -42,7 +62,11 @@ impl Version {
         T: Into<Tag>,
     {
         let tag = tag.into();
-        Version { tag, kind }
+        Version { tag, kind, label: None }
+    }
+
+    pub fn set_label(&mut self, label: Option<String>) {
+        self.label = label;
     }
 
     pub fn proton(tag: &str) -> Self {
@@ -72,26 +96,112 @@ impl Versioned for Version {
     fn kind(&self) -> &TagKind {
         &self.kind
     }
+
+    fn label(&self) -> Option<&String> {
+        self.label.as_ref()
+    }
 }
 
 impl<'a> PartialEq<dyn Versioned + 'a> for Version {
     fn eq(&self, other: &(dyn Versioned + 'a)) -> bool {
-        self.tag().eq(other.tag()) && self.kind().eq(other.kind())
+        self.tag().eq(other.tag()) && self.kind().eq(other.kind()) && self.label().eq(&other.label())
     }
 }
 
 impl<'a> PartialOrd<dyn Versioned + 'a> for Version {
     fn partial_cmp(&self, other: &(dyn Versioned + 'a)) -> Option<Ordering> {
-        Some(self.tag().cmp(other.tag()).then(self.kind().cmp(other.kind())))
+        Some(
+            cmp_tags(self.tag(), other.tag())
+                .then(self.kind().cmp(other.kind()))
+                .then(self.label().cmp(&other.label())),
+        )
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_tags(&self.tag, &other.tag)
+            .then(self.kind.cmp(&other.kind))
+            .then(self.label.cmp(&other.label))
     }
 }
 
 impl Display for Version {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} ({})", self.tag, self.kind.compatibility_tool_kind())
+        write_version(f, &self.tag, self.label.as_ref(), &self.kind)
     }
 }
 
+/// Write a version as `<tag> (<kind>)` or `<tag> [<label>] (<kind>)` if the version has a label.
+pub(crate) fn write_version(
+    f: &mut Formatter<'_>,
+    tag: &Tag,
+    label: Option<&String>,
+    kind: &TagKind,
+) -> std::fmt::Result {
+    match label {
+        Some(label) => write!(f, "{} [{}] ({})", tag, label, kind.compatibility_tool_kind()),
+        None => write!(f, "{} ({})", tag, kind.compatibility_tool_kind()),
+    }
+}
+
+/// Normalize a tag so spelling variants of the same release can be matched, e.g. `6.16-GE-3-LoL` and
+/// `6.16-3-ge-lol`. The case, the separators and the position of the "GE" and "LoL" tokens are ignored.
+pub fn normalize_tag(tag: &str) -> String {
+    let tag = tag.to_lowercase();
+    let mut is_ge = false;
+    let mut is_lol = false;
+    let mut tokens = Vec::new();
+
+    for token in tag.split(|c| c == '-' || c == '_').filter(|token| !token.is_empty()) {
+        match token {
+            "ge" => is_ge = true,
+            "lol" => is_lol = true,
+            _ => tokens.push(token),
+        }
+    }
+
+    let mut normalized = String::new();
+    if is_ge {
+        normalized.push_str("ge:");
+    }
+    if is_lol {
+        normalized.push_str("lol:");
+    }
+    normalized.push_str(&tokens.join("-"));
+    normalized
+}
+
+/// Infer the version of an extracted release from its directory name, e.g. `Proton-6.20-GE-1`, `GE-Proton7-8`,
+/// `lutris-GE-Proton7-20-x86_64` or `Wine-6.16-GE-3-LoL`. Returns `None` if the name does not follow a known scheme.
+pub fn version_from_directory_name(name: &str) -> Option<Version> {
+    let wine_version = |tag: &str| {
+        if tag.to_lowercase().ends_with("lol") {
+            Version::lol(tag)
+        } else {
+            Version::wine(tag)
+        }
+    };
+
+    let version = if name.starts_with("GE-Proton") {
+        Some(Version::proton(name))
+    } else if let Some(tag) = name.strip_prefix("Proton-") {
+        Some(Version::proton(tag))
+    } else if let Some(tag) = name.strip_prefix("lutris-") {
+        Some(wine_version(tag.strip_suffix("-x86_64").unwrap_or(tag)))
+    } else {
+        name.strip_prefix("Wine-").map(wine_version)
+    };
+
+    version.filter(|version| !version.tag().value().is_empty())
+}
+
 #[cfg(test)]
 mod version_tests {
     use test_case::test_case;
@@ -148,4 +258,60 @@ mod version_tests {
     fn cmp(version1: Version, version2: Version) -> bool {
         version1.cmp(&version2).eq(&Ordering::Equal)
     }
+
+    #[test_case("GE-Proton8-1", "GE-Proton7-22" => Ordering::Greater; "Newer major GE Proton version")]
+    #[test_case("GE-Proton7-22", "GE-Proton7-8" => Ordering::Greater; "Release numbers should not be compared as text")]
+    #[test_case("GE-Proton7-22", "6.21-GE-2" => Ordering::Greater; "New tag scheme should be newer than old scheme")]
+    #[test_case("6.21-GE-2", "GE-Proton7-1" => Ordering::Less; "Old tag scheme should be older than new scheme")]
+    #[test_case("6.21-GE-2", "6.21-GE-10" => Ordering::Less; "Old tag scheme release numbers")]
+    fn cmp_mixed_proton_tag_schemes(tag1: &str, tag2: &str) -> Ordering {
+        Version::proton(tag1).cmp(&Version::proton(tag2))
+    }
+
+    #[test]
+    fn sort_mixed_proton_tag_schemes() {
+        let mut versions = vec![
+            Version::proton("GE-Proton7-8"),
+            Version::proton("6.21-GE-2"),
+            Version::proton("GE-Proton8-1"),
+            Version::proton("GE-Proton7-22"),
+            Version::proton("6.19-GE-2"),
+        ];
+        versions.sort();
+
+        let tags: Vec<String> = versions.iter().map(|version| version.tag().to_string()).collect();
+        assert_eq!(
+            tags,
+            vec![
+                "6.19-GE-2",
+                "6.21-GE-2",
+                "GE-Proton7-8",
+                "GE-Proton7-22",
+                "GE-Proton8-1"
+            ]
+        );
+    }
+
+    #[test_case("6.16-GE-3-LoL", "6.16-3-GE-LoL" => true; "LoL token order should be ignored")]
+    #[test_case("6.16-GE-3-LoL", "6.16-ge-3-lol" => true; "Case should be ignored")]
+    #[test_case("GE-Proton7-8", "ge_proton7_8" => true; "Separators should be ignored")]
+    #[test_case("6.20-GE-1", "6.20--GE-1" => true; "Repeated separators should be ignored")]
+    #[test_case("6.16-GE-3-LoL", "6.16-2-GE-LoL" => false; "Different LoL releases should not match")]
+    #[test_case("6.16-GE-3-LoL", "6.16-GE-3" => false; "LoL token should not be ignored")]
+    #[test_case("GE-Proton7-8", "Proton7-8" => false; "GE token should not be ignored")]
+    fn normalize_tag_matches(tag1: &str, tag2: &str) -> bool {
+        normalize_tag(tag1).eq(&normalize_tag(tag2))
+    }
+
+    #[test_case("Proton-6.20-GE-1" => Some(Version::proton("6.20-GE-1")); "Proton directory")]
+    #[test_case("GE-Proton7-8" => Some(Version::proton("GE-Proton7-8")); "GE Proton directory")]
+    #[test_case("lutris-GE-Proton7-20-x86_64" => Some(Version::wine("GE-Proton7-20")); "Lutris directory")]
+    #[test_case("lutris-ge-6.21-1-x86_64" => Some(Version::wine("ge-6.21-1")); "Old Lutris directory")]
+    #[test_case("Wine-6.20-GE-1" => Some(Version::wine("6.20-GE-1")); "Wine directory")]
+    #[test_case("Wine-6.16-GE-3-LoL" => Some(Version::lol("6.16-GE-3-LoL")); "Wine LoL directory")]
+    #[test_case("Proton-" => None; "Prefix without tag")]
+    #[test_case("my-proton" => None; "Unknown directory")]
+    fn version_from_directory_name_infers_tag_and_kind(name: &str) -> Option<Version> {
+        version_from_directory_name(name)
+    }
 }